@@ -9,7 +9,6 @@ use kira::effect::reverb::ReverbBuilder;
 use kira::track::SpatialTrackBuilder;
 use nalgebra::UnitQuaternion;
 use rapier3d::parry::query::Ray;
-use rapier3d::prelude::QueryFilter;
 use std::error::Error;
 use syrillian::assets::scene_loader::SceneLoader;
 use syrillian::assets::{HMaterial, HSound, Sound, StoreType};
@@ -21,6 +20,7 @@ use syrillian::components::{
     SpringJoint, Text3D,
 };
 use syrillian::core::{GameObjectExt, GameObjectId, GameObjectRef};
+use syrillian::physics::QueryFilterBuilder;
 use syrillian::prefabs::{CubePrefab, FirstPersonPlayerPrefab, Prefab};
 #[cfg(debug_assertions)]
 use syrillian::rendering::DebugRenderer;
@@ -391,20 +391,14 @@ impl MyMain {
             || world.input.is_button_released(MouseButton::Left);
 
         if pick_up {
-            let Some(collider) = self.player.get_component::<Collider3D>() else {
-                return;
-            };
-            let player_collider = collider.phys_handle;
             let ray = Ray::new(
                 camera_obj.transform.position().into(),
                 camera_obj.transform.forward(),
             );
-            let intersect = world.physics.cast_ray(
-                &ray,
-                5.,
-                false,
-                QueryFilter::only_dynamic().exclude_collider(player_collider),
-            );
+            let filter = QueryFilterBuilder::new()
+                .only_dynamic()
+                .exclude(*self.player.as_ref());
+            let intersect = world.physics.cast_ray(&ray, 5., false, filter.build());
 
             #[cfg(debug_assertions)]
             {
@@ -205,6 +205,11 @@ impl<T: StoreType> Store<T> {
             .map(|i| i.into())
     }
 
+    /// Same as [`Store::try_get`], but without warning about a missing handle.
+    pub(crate) fn try_get_quiet(&self, h: H<T>) -> Option<Ref<'_, T>> {
+        self.data.get(&h.into()).map(|i| i.into())
+    }
+
     pub fn try_get_mut(&self, h: H<T>) -> Option<RefMut<'_, T>> {
         let reference = self.data.get_mut(&h.into()).or_else(|| {
             warn!(
@@ -0,0 +1,212 @@
+//! Reloading textures and meshes when their files change on disk.
+//!
+//! The [`AssetWatcher`] remembers which file every watched asset came from. Once a file
+//! changes, the asset is loaded again and replaces the old one behind the same handle, so
+//! every material and mesh renderer using it picks up the change on the next frame.
+
+use crate::assets::scene_loader::SceneLoader;
+use crate::engine::assets::{AssetStore, HMesh, HTexture, Texture};
+use crossbeam_channel::{Receiver, unbounded};
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use snafu::{ResultExt, Snafu};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+#[derive(Debug, Snafu)]
+#[snafu(context(suffix(Err)))]
+pub enum HotReloadError {
+    #[snafu(display("Failed to watch {}: {source}", path.display()))]
+    Watch {
+        path: PathBuf,
+        source: notify::Error,
+    },
+
+    #[snafu(display("Failed to load texture {}: {message}", path.display()))]
+    TextureLoad { path: PathBuf, message: String },
+
+    #[snafu(display("Failed to load mesh {}: {source}", path.display()))]
+    MeshLoad {
+        path: PathBuf,
+        source: crate::assets::scene_loader::Error,
+    },
+
+    #[snafu(display("{} doesn't contain a mesh", path.display()))]
+    NoMesh { path: PathBuf },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum WatchedAsset {
+    Texture(HTexture),
+    Mesh(HMesh),
+}
+
+/// Watches the files of loaded assets and reloads them when they change.
+///
+/// Enable it on a world with [`World::enable_hot_reload`](crate::World::enable_hot_reload),
+/// which polls it every frame. A file that fails to load, for example because it was only
+/// half written, is reported and the old asset stays in place until the next change.
+pub struct AssetWatcher {
+    assets: Arc<AssetStore>,
+    watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+    watched_dirs: HashSet<PathBuf>,
+    files: HashMap<PathBuf, Vec<WatchedAsset>>,
+}
+
+impl AssetWatcher {
+    pub fn new(assets: Arc<AssetStore>) -> Result<Self, HotReloadError> {
+        let (tx, events) = unbounded();
+        let watcher = notify::recommended_watcher(move |event| {
+            let _ = tx.send(event);
+        })
+        .context(WatchErr {
+            path: PathBuf::new(),
+        })?;
+
+        Ok(Self {
+            assets,
+            watcher,
+            events,
+            watched_dirs: HashSet::new(),
+            files: HashMap::new(),
+        })
+    }
+
+    /// Loads an image into the texture store, and keeps it up to date with the file
+    pub fn load_texture(&mut self, path: impl AsRef<Path>) -> Result<HTexture, HotReloadError> {
+        let path = path.as_ref();
+        let texture = load_texture(path)?;
+        let handle = self.assets.textures.add(texture);
+        self.watch(path, WatchedAsset::Texture(handle))?;
+        Ok(handle)
+    }
+
+    /// Loads the first mesh of a glTF file into the mesh store, and keeps it up to date with
+    /// the file
+    pub fn load_mesh(&mut self, path: impl AsRef<Path>) -> Result<HMesh, HotReloadError> {
+        let path = path.as_ref();
+        let mesh = load_mesh(path)?;
+        let handle = self.assets.meshes.add(mesh);
+        self.watch(path, WatchedAsset::Mesh(handle))?;
+        Ok(handle)
+    }
+
+    /// Reloads an already stored texture from this file whenever it changes
+    pub fn watch_texture(
+        &mut self,
+        path: impl AsRef<Path>,
+        handle: HTexture,
+    ) -> Result<(), HotReloadError> {
+        self.watch(path.as_ref(), WatchedAsset::Texture(handle))
+    }
+
+    /// Reloads an already stored mesh from the first mesh in this glTF file whenever it changes
+    pub fn watch_mesh(
+        &mut self,
+        path: impl AsRef<Path>,
+        handle: HMesh,
+    ) -> Result<(), HotReloadError> {
+        self.watch(path.as_ref(), WatchedAsset::Mesh(handle))
+    }
+
+    /// Reloads the assets of all files that changed since the last poll. Returns how many
+    /// assets were replaced.
+    pub fn poll(&mut self) -> usize {
+        let mut changed = HashSet::new();
+        for event in self.events.try_iter() {
+            match event {
+                Ok(event) if is_content_change(&event.kind) => changed.extend(event.paths),
+                Ok(_) => {}
+                Err(e) => warn!("[Hot Reload] File watcher error: {e}"),
+            }
+        }
+
+        let mut reloaded = 0;
+        for path in changed {
+            let Some(assets) = self.files.get(&path) else {
+                continue;
+            };
+            for asset in assets {
+                match self.reload(&path, *asset) {
+                    Ok(()) => reloaded += 1,
+                    Err(e) => warn!("[Hot Reload] Keeping the previous asset. {e}"),
+                }
+            }
+        }
+        reloaded
+    }
+
+    fn reload(&self, path: &Path, asset: WatchedAsset) -> Result<(), HotReloadError> {
+        match asset {
+            WatchedAsset::Texture(handle) => {
+                let texture = load_texture(path)?;
+                if let Some(mut old) = self.assets.textures.try_get_mut(handle) {
+                    *old = texture;
+                }
+            }
+            WatchedAsset::Mesh(handle) => {
+                let mesh = load_mesh(path)?;
+                if let Some(mut old) = self.assets.meshes.try_get_mut(handle) {
+                    *old = mesh;
+                }
+            }
+        }
+        info!("[Hot Reload] Reloaded {}", path.display());
+        Ok(())
+    }
+
+    fn watch(&mut self, path: &Path, asset: WatchedAsset) -> Result<(), HotReloadError> {
+        let path = path
+            .canonicalize()
+            .map_err(notify::Error::io)
+            .context(WatchErr { path })?;
+
+        // Editors often save by replacing the file, which ends a watch on the file itself.
+        // Watching the directory keeps working across that.
+        if let Some(dir) = path.parent()
+            && !self.watched_dirs.contains(dir)
+        {
+            self.watcher
+                .watch(dir, RecursiveMode::NonRecursive)
+                .context(WatchErr { path: dir })?;
+            self.watched_dirs.insert(dir.to_path_buf());
+        }
+
+        let assets = self.files.entry(path).or_default();
+        if !assets.contains(&asset) {
+            assets.push(asset);
+        }
+        Ok(())
+    }
+}
+
+fn is_content_change(kind: &EventKind) -> bool {
+    matches!(
+        kind,
+        EventKind::Create(_)
+            | EventKind::Modify(ModifyKind::Data(_) | ModifyKind::Name(_) | ModifyKind::Any)
+    )
+}
+
+fn load_texture(path: &Path) -> Result<Texture, HotReloadError> {
+    let bytes = std::fs::read(path).map_err(|e| HotReloadError::TextureLoad {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    Texture::load_image_from_memory(&bytes).map_err(|e| HotReloadError::TextureLoad {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })
+}
+
+fn load_mesh(path: &Path) -> Result<crate::assets::Mesh, HotReloadError> {
+    let (mesh, _) = SceneLoader::load_first_mesh(&path.to_string_lossy())
+        .context(MeshLoadErr { path })?
+        .ok_or_else(|| HotReloadError::NoMesh {
+            path: path.to_path_buf(),
+        })?;
+    Ok(mesh)
+}
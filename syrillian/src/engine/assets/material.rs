@@ -4,6 +4,16 @@ use crate::store_add_checked;
 use bon::Builder;
 use nalgebra::Vector3;
 
+/// Which of the renderer's passes draws a material, and in which order
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum RenderQueue {
+    /// Drawn first, front-to-back from the camera, writing depth
+    Opaque,
+    /// Drawn after all opaque materials, back-to-front from the camera so blending stacks up
+    /// correctly. Doesn't write depth by default.
+    AlphaBlend,
+}
+
 #[derive(Debug, Clone, Builder)]
 pub struct Material {
     #[builder(into)]
@@ -27,12 +37,33 @@ pub struct Material {
     pub has_transparency: bool,
     #[builder(default = HShader::DIM3)]
     pub shader: HShader,
+    /// The render queue, or `None` to pick it from the transparency of the material
+    pub queue: Option<RenderQueue>,
+    /// Shifts the draw order of objects using this material, higher draws later. Default: 0
+    #[builder(default = 0)]
+    pub queue_priority: i32,
+    /// Whether drawing writes depth, or `None` to only write it in the opaque queue
+    pub depth_write: Option<bool>,
 }
 
 impl Material {
     pub fn is_transparent(&self) -> bool {
         self.alpha < 1.0
     }
+
+    pub fn render_queue(&self) -> RenderQueue {
+        self.queue
+            .unwrap_or(if self.is_transparent() || self.has_transparency {
+                RenderQueue::AlphaBlend
+            } else {
+                RenderQueue::Opaque
+            })
+    }
+
+    pub fn writes_depth(&self) -> bool {
+        self.depth_write
+            .unwrap_or(self.render_queue() == RenderQueue::Opaque)
+    }
 }
 
 impl<S: material_builder::State> MaterialBuilder<S>
@@ -59,6 +90,9 @@ impl StoreDefaults for Material {
             lit: true,
             cast_shadows: true,
             has_transparency: false,
+            queue: None,
+            queue_priority: 0,
+            depth_write: None,
         };
 
         store_add_checked!(store, HMaterial::FALLBACK_ID, fallback);
@@ -76,6 +110,9 @@ impl StoreDefaults for Material {
             lit: true,
             cast_shadows: true,
             has_transparency: false,
+            queue: None,
+            queue_priority: 0,
+            depth_write: None,
         };
 
         store_add_checked!(store, HMaterial::DEFAULT_ID, default);
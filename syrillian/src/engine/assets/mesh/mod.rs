@@ -1,7 +1,8 @@
 mod builder;
+mod primitives;
 
 use crate::assets::mesh::builder::MeshBuilder;
-use crate::core::{Bones, BoundingSphere, Vertex3D};
+use crate::core::{Bones, BoundingSphere, UpAxis, Vertex3D};
 use crate::engine::assets::generic_store::{HandleName, Store, StoreDefaults, StoreType};
 use crate::engine::assets::{H, HMesh};
 use crate::store_add_checked;
@@ -73,6 +74,20 @@ impl Mesh {
         &self.data.vertices
     }
 
+    /// Mutable access to the vertices of this mesh. The vertex data is copied first if it's
+    /// still shared with another mesh.
+    ///
+    /// Call [`Mesh::recalculate_bounds`] when positions were changed.
+    #[inline]
+    pub fn vertices_mut(&mut self) -> &mut [Vertex3D] {
+        &mut Arc::make_mut(&mut self.data).vertices
+    }
+
+    /// Recalculates the bounding sphere from the current vertex positions
+    pub fn recalculate_bounds(&mut self) {
+        self.bounding_sphere = bounding_sphere_from_vertices(&self.data.vertices);
+    }
+
     #[inline]
     pub fn indices(&self) -> Option<&[u32]> {
         self.data.indices.as_deref()
@@ -83,7 +98,17 @@ impl Mesh {
         self.data.indices.is_some()
     }
 
+    /// Loads a triangulated OBJ as it's authored, which is Y-up by convention
     pub fn load_from_obj_slice(data: &[u8]) -> Result<Mesh, MeshError> {
+        Self::load_from_obj_slice_with_up_axis(data, UpAxis::Y)
+    }
+
+    /// Loads a triangulated Y-up OBJ and turns it upright for `up_axis`, usually the
+    /// [`World::up_axis`](crate::World::up_axis) of the world it's for
+    pub fn load_from_obj_slice_with_up_axis(
+        data: &[u8],
+        up_axis: UpAxis,
+    ) -> Result<Mesh, MeshError> {
         let data = obj::ObjData::load_buf(data)?;
         let mut vertices: Vec<Vector3<f32>> = Vec::new();
         let mut normals: Vec<Vector3<f32>> = Vec::new();
@@ -119,6 +144,13 @@ impl Mesh {
 
         debug_assert!(vertices.len() == uvs.len() && vertices.len() == normals.len());
 
+        if up_axis != UpAxis::Y {
+            let rotation = up_axis.from_y_up();
+            for v in vertices.iter_mut().chain(normals.iter_mut()) {
+                *v = rotation * *v;
+            }
+        }
+
         let vertices = izip!(vertices, uvs, normals)
             .map(|(v, u, n)| Vertex3D::basic(v, u, n))
             .collect::<Vec<_>>();
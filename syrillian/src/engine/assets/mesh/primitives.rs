@@ -0,0 +1,256 @@
+use crate::assets::{HMesh, Mesh, Store, StoreType};
+use crate::core::Vertex3D;
+use nalgebra::{Vector2, Vector3};
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+/// One latitude row of a surface of revolution around the Y axis.
+struct RevolveRow {
+    /// Polar angle measured from +Y
+    theta: f32,
+    /// Vertical offset applied to the whole row
+    offset: f32,
+    /// Texture v coordinate of the row
+    v: f32,
+}
+
+/// Meshes generated in code.
+///
+/// These return the [`Mesh`] itself rather than a handle, so it can still be edited before
+/// it's uploaded. The `*_handle` variants add it to the store right away, like the builtin
+/// meshes such as [`HMesh::UNIT_CUBE`]:
+///
+/// ```rust
+/// use syrillian::World;
+/// use syrillian::assets::{Mesh, StoreType};
+///
+/// let world = World::builder().build();
+/// let ball = Mesh::uv_sphere_handle(world.as_ref(), 32);
+///
+/// let mut ground = Mesh::plane(10.0, 10.0, 8);
+/// ground.vertices_mut()[0].position.y = 0.5;
+/// let ground = ground.store(world.as_ref());
+/// ```
+impl Mesh {
+    /// Generates a flat plane on the XZ axis facing +Y, centered at the origin.
+    ///
+    /// The plane is split into `subdivisions` x `subdivisions` quads, which is useful for
+    /// deforming it later on. UVs span the full plane from `0.0` to `1.0`.
+    pub fn plane(width: f32, depth: f32, subdivisions: u32) -> Mesh {
+        let n = subdivisions.max(1);
+        let row = n + 1;
+
+        let mut vertices = Vec::with_capacity((row * row) as usize);
+        for j in 0..=n {
+            for i in 0..=n {
+                let u = i as f32 / n as f32;
+                let v = j as f32 / n as f32;
+                let position = Vector3::new((u - 0.5) * width, 0.0, (v - 0.5) * depth);
+                vertices.push(Vertex3D::new(
+                    position,
+                    Vector2::new(u, v),
+                    Vector3::y(),
+                    Vector3::x(),
+                    &[],
+                    &[],
+                ));
+            }
+        }
+
+        let mut indices = Vec::with_capacity((n * n * 6) as usize);
+        for j in 0..n {
+            for i in 0..n {
+                let a = j * row + i;
+                let b = a + 1;
+                let c = a + row;
+                let d = c + 1;
+                indices.extend_from_slice(&[a, c, b, b, c, d]);
+            }
+        }
+
+        Mesh::builder(vertices).with_indices(indices).build()
+    }
+
+    /// Generates a cube with the given edge length, centered at the origin.
+    ///
+    /// `Mesh::cube(1.0)` matches the default cuboid of a [`Collider3D`](crate::components::Collider3D)
+    /// on an unscaled object.
+    pub fn cube(size: f32) -> Mesh {
+        Self::cuboid(Vector3::new(size, size, size))
+    }
+
+    /// Generates a box with the given extents along each axis, centered at the origin.
+    ///
+    /// Every face has its own vertices, so normals are flat and each face is mapped to the
+    /// full UV range.
+    pub fn cuboid(extents: Vector3<f32>) -> Mesh {
+        let half = extents * 0.5;
+        // (normal, tangent) per face, the bitangent follows from normal x tangent
+        let faces = [
+            (Vector3::x(), -Vector3::z()),
+            (-Vector3::x(), Vector3::z()),
+            (Vector3::y(), Vector3::x()),
+            (-Vector3::y(), Vector3::x()),
+            (Vector3::z(), Vector3::x()),
+            (-Vector3::z(), -Vector3::x()),
+        ];
+
+        let mut vertices = Vec::with_capacity(24);
+        let mut indices = Vec::with_capacity(36);
+        for (normal, tangent) in faces {
+            let bitangent = normal.cross(&tangent);
+            let base = vertices.len() as u32;
+
+            for (u, v) in [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)] {
+                let corner = normal + tangent * (u * 2.0 - 1.0) + bitangent * (v * 2.0 - 1.0);
+                vertices.push(Vertex3D::new(
+                    corner.component_mul(&half),
+                    Vector2::new(u, 1.0 - v),
+                    normal,
+                    tangent,
+                    &[],
+                    &[],
+                ));
+            }
+
+            indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+
+        Mesh::builder(vertices).with_indices(indices).build()
+    }
+
+    /// Generates a UV sphere with a radius of 1, centered at the origin, like
+    /// [`HMesh::SPHERE`](crate::assets::HMesh::SPHERE). Scale the object for other sizes.
+    ///
+    /// `segments` is the amount of subdivisions around the Y axis. Half as many rings are
+    /// generated from pole to pole.
+    pub fn uv_sphere(segments: u32) -> Mesh {
+        let segments = segments.max(3);
+        let rings = (segments / 2).max(2);
+
+        let rows: Vec<_> = (0..=rings)
+            .map(|r| {
+                let t = r as f32 / rings as f32;
+                RevolveRow {
+                    theta: t * PI,
+                    offset: 0.0,
+                    v: t,
+                }
+            })
+            .collect();
+
+        Self::revolve(&rows, 1.0, segments)
+    }
+
+    /// Generates a capsule aligned with the Y axis, centered at the origin.
+    ///
+    /// The parameters match [`Collider3D::use_capsule`](crate::components::Collider3D::use_capsule),
+    /// so the total height of the capsule is `2 * (half_height + radius)`.
+    pub fn capsule(half_height: f32, radius: f32, segments: u32) -> Mesh {
+        let segments = segments.max(3);
+        let hemisphere_rings = (segments / 4).max(1);
+
+        let total_length = PI * radius + 2.0 * half_height;
+        let arc_step = FRAC_PI_2 * radius / hemisphere_rings as f32;
+
+        let mut rows = Vec::with_capacity(hemisphere_rings as usize * 2 + 2);
+        for r in 0..=hemisphere_rings {
+            rows.push(RevolveRow {
+                theta: r as f32 / hemisphere_rings as f32 * FRAC_PI_2,
+                offset: half_height,
+                v: r as f32 * arc_step / total_length,
+            });
+        }
+        for r in 0..=hemisphere_rings {
+            let arc = FRAC_PI_2 * radius + 2.0 * half_height + r as f32 * arc_step;
+            rows.push(RevolveRow {
+                theta: FRAC_PI_2 + r as f32 / hemisphere_rings as f32 * FRAC_PI_2,
+                offset: -half_height,
+                v: arc / total_length,
+            });
+        }
+
+        Self::revolve(&rows, radius, segments)
+    }
+
+    fn revolve(rows: &[RevolveRow], radius: f32, segments: u32) -> Mesh {
+        let stride = segments + 1;
+
+        let mut vertices = Vec::with_capacity(rows.len() * stride as usize);
+        for row in rows {
+            let (sin_theta, cos_theta) = row.theta.sin_cos();
+            for s in 0..=segments {
+                let u = s as f32 / segments as f32;
+                let (sin_phi, cos_phi) = (u * TAU).sin_cos();
+
+                let normal = Vector3::new(sin_theta * cos_phi, cos_theta, sin_theta * sin_phi);
+                let tangent = Vector3::new(-sin_phi, 0.0, cos_phi);
+                let position = normal * radius + Vector3::new(0.0, row.offset, 0.0);
+
+                vertices.push(Vertex3D::new(
+                    position,
+                    Vector2::new(u, row.v),
+                    normal,
+                    tangent,
+                    &[],
+                    &[],
+                ));
+            }
+        }
+
+        let last_row = rows.len() as u32 - 1;
+        let mut indices = Vec::with_capacity((last_row * segments * 6) as usize);
+        for r in 0..last_row {
+            for s in 0..segments {
+                let a = r * stride + s;
+                let b = a + 1;
+                let c = a + stride;
+                let d = c + 1;
+
+                // the triangles touching a pole would be degenerate
+                if r != 0 {
+                    indices.extend_from_slice(&[a, b, c]);
+                }
+                if r + 1 != last_row {
+                    indices.extend_from_slice(&[b, d, c]);
+                }
+            }
+        }
+
+        Mesh::builder(vertices).with_indices(indices).build()
+    }
+
+    /// Stores a [`Mesh::plane`] and returns its handle
+    pub fn plane_handle<S: AsRef<Store<Mesh>>>(
+        store: &S,
+        width: f32,
+        depth: f32,
+        subdivisions: u32,
+    ) -> HMesh {
+        Self::plane(width, depth, subdivisions).store(store)
+    }
+
+    /// Stores a [`Mesh::cube`] and returns its handle
+    pub fn cube_handle<S: AsRef<Store<Mesh>>>(store: &S, size: f32) -> HMesh {
+        Self::cube(size).store(store)
+    }
+
+    /// Stores a [`Mesh::cuboid`] and returns its handle
+    pub fn cuboid_handle<S: AsRef<Store<Mesh>>>(store: &S, extents: Vector3<f32>) -> HMesh {
+        Self::cuboid(extents).store(store)
+    }
+
+    /// Stores a [`Mesh::uv_sphere`] and returns its handle
+    pub fn uv_sphere_handle<S: AsRef<Store<Mesh>>>(store: &S, segments: u32) -> HMesh {
+        Self::uv_sphere(segments).store(store)
+    }
+
+    /// Stores a [`Mesh::capsule`] and returns its handle
+    pub fn capsule_handle<S: AsRef<Store<Mesh>>>(
+        store: &S,
+        half_height: f32,
+        radius: f32,
+        segments: u32,
+    ) -> HMesh {
+        Self::capsule(half_height, radius, segments).store(store)
+    }
+}
@@ -43,6 +43,9 @@ mod handle;
 mod key;
 mod sound;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod hot_reload;
+
 pub use self::asset_store::*;
 pub use self::handle::*;
 
@@ -54,6 +57,9 @@ pub use self::shader::*;
 pub use self::sound::*;
 pub use self::texture::*;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::hot_reload::*;
+
 pub use generic_store::StoreType;
 
 pub(crate) use self::generic_store::*;
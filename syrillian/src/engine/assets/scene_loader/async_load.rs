@@ -0,0 +1,175 @@
+//! Loading glTF scenes on a background thread, so big files don't stall the frame.
+//!
+//! Reading the file and decoding its buffers and images happens on the thread. Once that's
+//! done, the objects are spawned under the root of the [`SceneHandle`] during the next
+//! [`World::update`].
+//!
+//! ```no_run
+//! use syrillian::World;
+//! use syrillian::core::WorldEvent;
+//!
+//! let mut world = World::builder().build();
+//! let level = world.load_gltf_async("levels/harbor.glb");
+//! world.add_child(level.root());
+//!
+//! world.on_world_event(|_world, event| {
+//!     if let WorldEvent::SceneLoaded(root) = event {
+//!         println!("{} is ready", root.name);
+//!     }
+//! });
+//!
+//! // the root is empty until the scene is in
+//! assert!(!level.is_ready());
+//! ```
+
+use crate::World;
+use crate::assets::scene_loader::{Error, GltfScene, SceneLoader};
+use crate::core::{GameObjectId, WorldEvent};
+use crossbeam_channel::{Receiver, TryRecvError, bounded};
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::warn;
+
+/// Where a scene started with [`World::load_gltf_async`] is at
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LoadStatus {
+    Loading,
+    /// The scene was spawned under the root
+    Ready,
+    /// The scene couldn't be loaded, or its root was deleted before it was done
+    Failed,
+}
+
+struct LoadState {
+    status: LoadStatus,
+    error: Option<Error>,
+}
+
+/// A glTF scene that's loading in the background, see [`World::load_gltf_async`].
+///
+/// Clones refer to the same load.
+#[derive(Clone)]
+pub struct SceneHandle {
+    root: GameObjectId,
+    state: Rc<RefCell<LoadState>>,
+}
+
+impl SceneHandle {
+    /// The object the scene is spawned under. It exists right away, but stays empty until the
+    /// scene is ready.
+    pub fn root(&self) -> GameObjectId {
+        self.root
+    }
+
+    pub fn status(&self) -> LoadStatus {
+        self.state.borrow().status
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.status() == LoadStatus::Ready
+    }
+
+    /// The reason the scene failed to load, if it did. Only returned once.
+    pub fn take_error(&self) -> Option<Error> {
+        self.state.borrow_mut().error.take()
+    }
+}
+
+pub(crate) struct PendingScene {
+    handle: SceneHandle,
+    result_rx: Receiver<Result<GltfScene, Error>>,
+}
+
+impl World {
+    /// Starts loading a glTF file on a background thread and returns right away.
+    ///
+    /// The returned handle has an empty `"glTF Scene"` root, which can be placed into the world
+    /// like the root from [`SceneLoader::load`]. The nodes of the scene are spawned under it
+    /// once loading finishes, followed by a [`WorldEvent::SceneLoaded`], or a
+    /// [`WorldEvent::SceneLoadFailed`] if it didn't work out.
+    pub fn load_gltf_async(&mut self, path: impl Into<String>) -> SceneHandle {
+        let path = path.into();
+        let handle = SceneHandle {
+            root: self.new_object("glTF Scene"),
+            state: Rc::new(RefCell::new(LoadState {
+                status: LoadStatus::Loading,
+                error: None,
+            })),
+        };
+
+        let (result_tx, result_rx) = bounded(1);
+        let spawned = std::thread::Builder::new()
+            .name(format!("glTF loader {path}"))
+            .spawn(move || {
+                let _ = result_tx.send(GltfScene::import(&path));
+            });
+        if let Err(e) = spawned {
+            warn!("Couldn't start a thread for loading a glTF scene: {e}");
+        }
+
+        self.pending_scenes.push(PendingScene {
+            handle: handle.clone(),
+            result_rx,
+        });
+        handle
+    }
+
+    /// Blocks until all scenes started with [`World::load_gltf_async`] are loaded and spawned
+    pub fn wait_for_scene_loads(&mut self) {
+        for pending in std::mem::take(&mut self.pending_scenes) {
+            let result = pending.result_rx.recv().ok();
+            self.finish_scene_load(pending.handle, result);
+        }
+    }
+
+    /// Spawns the scenes that finished loading since the last call
+    pub(crate) fn poll_scene_loads(&mut self) {
+        if self.pending_scenes.is_empty() {
+            return;
+        }
+
+        for pending in std::mem::take(&mut self.pending_scenes) {
+            match pending.result_rx.try_recv() {
+                Ok(result) => self.finish_scene_load(pending.handle, Some(result)),
+                Err(TryRecvError::Disconnected) => self.finish_scene_load(pending.handle, None),
+                Err(TryRecvError::Empty) => self.pending_scenes.push(pending),
+            }
+        }
+    }
+
+    /// `result` is `None` if the loading thread went away without a result
+    fn finish_scene_load(&mut self, handle: SceneHandle, result: Option<Result<GltfScene, Error>>) {
+        let root = handle.root;
+        if !root.exists() {
+            handle.state.borrow_mut().status = LoadStatus::Failed;
+            return;
+        }
+
+        let result = match result {
+            Some(Ok(scene)) => SceneLoader::spawn_into(self, &scene, root),
+            Some(Err(e)) => Err(e),
+            None => {
+                warn!("The thread loading a glTF scene stopped without a result");
+                handle.state.borrow_mut().status = LoadStatus::Failed;
+                self.emit_world_event(WorldEvent::SceneLoadFailed(root));
+                return;
+            }
+        };
+
+        let mut state = handle.state.borrow_mut();
+        match result {
+            Ok(()) => {
+                state.status = LoadStatus::Ready;
+                drop(state);
+                self.emit_world_event(WorldEvent::SceneLoaded(root));
+            }
+            Err(e) => {
+                warn!("Failed to load glTF scene: {e}");
+                state.status = LoadStatus::Failed;
+                state.error = Some(e);
+                drop(state);
+                self.emit_world_event(WorldEvent::SceneLoadFailed(root));
+            }
+        }
+    }
+}
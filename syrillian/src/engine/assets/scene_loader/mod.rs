@@ -14,14 +14,25 @@ use nalgebra::{Quaternion, UnitQuaternion, Vector3};
 use snafu::{OptionExt, ResultExt, Snafu};
 use std::collections::HashMap;
 use syrillian_utils::debug_panic;
-use tracing::trace;
+use tracing::{trace, warn};
+use uuid::Uuid;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod async_load;
 mod bones;
 mod meshes;
 mod textures;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use async_load::PendingScene;
+#[cfg(not(target_arch = "wasm32"))]
+pub use async_load::{LoadStatus, SceneHandle};
+
 pub use meshes::MeshData;
 
+/// Custom property holding the persistent id of a node, see [`GameObject::uuid`](crate::core::GameObject::uuid)
+pub const UUID_PROPERTY: &str = "uuid";
+
 #[derive(Debug, Snafu)]
 #[snafu(context(suffix(Err)))]
 pub enum Error {
@@ -65,6 +76,9 @@ pub struct SceneLoader;
 
 impl SceneLoader {
     /// Loads a glTF file from disk and spawns its root into the world.
+    ///
+    /// In a world that isn't [Y-up](crate::core::UpAxis), the root is turned to stand the
+    /// scene upright.
     pub fn load(world: &mut World, path: &str) -> Result<GameObjectId, Error> {
         let scene = GltfScene::import(path)?;
         Self::load_into_world(world, &scene)
@@ -100,24 +114,51 @@ impl SceneLoader {
 
     /// Spawns the glTF scene graph into the world and returns the created root object.
     fn load_into_world(world: &mut World, gltf_scene: &GltfScene) -> Result<GameObjectId, Error> {
+        let root_scene = Self::root_scene(gltf_scene)?;
+
+        let root = world.new_object("glTF Scene");
+        Self::spawn_scene(world, gltf_scene, root_scene, root);
+        Ok(root)
+    }
+
+    /// Spawns the glTF scene graph under an existing root object.
+    pub(crate) fn spawn_into(
+        world: &mut World,
+        gltf_scene: &GltfScene,
+        root: GameObjectId,
+    ) -> Result<(), Error> {
+        let root_scene = Self::root_scene(gltf_scene)?;
+
+        Self::spawn_scene(world, gltf_scene, root_scene, root);
+        Ok(())
+    }
+
+    fn root_scene(gltf_scene: &GltfScene) -> Result<gltf::Scene<'_>, Error> {
         let doc = &gltf_scene.doc;
-        let root_scene = doc
-            .default_scene()
+        doc.default_scene()
             .or_else(|| doc.scenes().next())
-            .context(GltfNoScenesErr)?;
+            .context(GltfNoScenesErr)
+    }
 
+    fn spawn_scene(
+        world: &mut World,
+        gltf_scene: &GltfScene,
+        root_scene: gltf::Scene,
+        mut root: GameObjectId,
+    ) {
         let materials = textures::load_materials(gltf_scene, world);
         trace!("Loaded materials");
 
-        let mut root = world.new_object("glTF Scene");
+        // glTF is always Y-up
+        let rotation = *root.transform.local_rotation() * world.up_axis().from_y_up();
+        root.transform.set_local_rotation(rotation);
+
         for node in root_scene.nodes() {
             let child = Self::spawn_node(world, gltf_scene, node, Some(&materials));
             root.add_child(child);
         }
 
         Self::load_animations(gltf_scene, root);
-
-        Ok(root)
     }
 
     /// Collects animations from the scene and attaches them to the spawned root.
@@ -143,7 +184,10 @@ impl SceneLoader {
 
         if let Some(extras) = node.extras() {
             match serde_json::de::from_str::<serde_json::Value>(extras.get()) {
-                Ok(serde_json::Value::Object(props)) => obj.add_properties(props),
+                Ok(serde_json::Value::Object(props)) => {
+                    Self::restore_uuid(world, obj, &props);
+                    obj.add_properties(props)
+                }
                 Ok(_) => trace!(
                     "Ignored custom property that was not a map when loading node into an object"
                 ),
@@ -171,6 +215,25 @@ impl SceneLoader {
         obj
     }
 
+    /// Gives the object the persistent id stored in its `uuid` custom property, if any.
+    fn restore_uuid(
+        world: &mut World,
+        obj: GameObjectId,
+        props: &serde_json::Map<String, serde_json::Value>,
+    ) {
+        let Some(uuid) = props.get(UUID_PROPERTY).and_then(|v| v.as_str()) else {
+            return;
+        };
+        match Uuid::parse_str(uuid) {
+            Ok(uuid) if world.set_object_uuid(obj, uuid) => {}
+            Ok(uuid) => warn!(
+                "Object {:?} has the uuid {uuid}, which is already used",
+                obj.name
+            ),
+            Err(e) => warn!("Invalid uuid {uuid:?} on object {:?}: {e}", obj.name),
+        }
+    }
+
     /// Attaches a mesh renderer (and skeletal component if required) to the node.
     fn attach_mesh(
         world: &mut World,
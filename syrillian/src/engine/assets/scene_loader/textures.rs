@@ -1,7 +1,8 @@
 use super::GltfScene;
 use crate::World;
-use crate::assets::{HMaterial, HShader, HTexture, Material, StoreType, Texture};
+use crate::assets::{HMaterial, HShader, HTexture, Material, RenderQueue, StoreType, Texture};
 use gltf::image::Format;
+use gltf::material::AlphaMode;
 use nalgebra::Vector3;
 use std::collections::HashMap;
 use syrillian_utils::debug_panic;
@@ -26,6 +27,7 @@ pub(super) fn load_materials(scene: &GltfScene, world: &mut World) -> HashMap<u3
         let roughness_texture = load_texture(scene, world, pbr.metallic_roughness_texture());
 
         let lit = !mat.unlit();
+        let queue = (mat.alpha_mode() == AlphaMode::Blend).then_some(RenderQueue::AlphaBlend);
 
         let material = Material {
             name,
@@ -40,6 +42,9 @@ pub(super) fn load_materials(scene: &GltfScene, world: &mut World) -> HashMap<u3
             cast_shadows: true,
             shader: HShader::DIM3,
             has_transparency: false,
+            queue,
+            queue_priority: 0,
+            depth_write: None,
         };
         map.insert(i as u32, world.assets.materials.add(material));
     }
@@ -0,0 +1,167 @@
+//! Named buses that group sounds, so whole categories like music or sound effects can be
+//! turned down or muted at once.
+//!
+//! All buses feed into [`MASTER_BUS`], which feeds into the speakers. The `"music"` and
+//! `"sfx"` buses exist from the start, more can be added with
+//! [`AudioScene::add_bus`](crate::audio::AudioScene::add_bus).
+//!
+//! ```rust
+//! use syrillian::World;
+//! use syrillian::components::audio::AudioEmitter;
+//!
+//! let mut world = World::builder().build();
+//! let mut radio = world.new_object("Radio");
+//! radio.add_component::<AudioEmitter>().set_bus("music");
+//!
+//! // the options menu changes every sound on the bus, including ones already playing
+//! world.audio.mixer_mut().set_volume("music", 0.3);
+//! world.audio.mixer_mut().set_muted("sfx", true);
+//! assert_eq!(world.audio.mixer().volume("music"), Some(0.3));
+//! ```
+
+use kira::track::{TrackBuilder, TrackHandle};
+use kira::{AudioManager, Decibels, DefaultBackend, Tween};
+use tracing::warn;
+
+/// The bus all other buses play through
+pub const MASTER_BUS: &str = "master";
+
+/// Buses that exist in every mixer, besides [`MASTER_BUS`]
+pub const DEFAULT_BUSES: [&str; 2] = ["music", "sfx"];
+
+struct Bus {
+    name: String,
+    volume: f32,
+    muted: bool,
+    track: Option<TrackHandle>,
+}
+
+impl Bus {
+    fn new(name: &str, track: Option<TrackHandle>) -> Self {
+        Self {
+            name: name.to_string(),
+            volume: 1.0,
+            muted: false,
+            track,
+        }
+    }
+
+    fn apply(&mut self) {
+        let decibels = if self.muted || self.volume <= 0.0 {
+            Decibels::SILENCE
+        } else {
+            Decibels((20.0 * self.volume.log10()).max(Decibels::SILENCE.0))
+        };
+        if let Some(track) = &mut self.track {
+            track.set_volume(decibels, Tween::default());
+        }
+    }
+}
+
+/// The volumes of all buses, found at [`AudioScene::mixer`](crate::audio::AudioScene::mixer).
+///
+/// Volumes are linear, 1 plays sounds unchanged and 0 is silent. The volume of a bus is
+/// multiplied with the master volume. Without an audio device, the volumes are still kept,
+/// they just don't have anything to change.
+pub struct AudioMixer {
+    buses: Vec<Bus>,
+}
+
+impl AudioMixer {
+    pub(crate) fn new(mut manager: Option<&mut AudioManager<DefaultBackend>>) -> Self {
+        let master = manager
+            .as_mut()
+            .and_then(|manager| add_track(MASTER_BUS, manager.add_sub_track(TrackBuilder::new())));
+        let mut mixer = Self {
+            buses: vec![Bus::new(MASTER_BUS, master)],
+        };
+        for name in DEFAULT_BUSES {
+            mixer.add_bus(name);
+        }
+        mixer
+    }
+
+    /// Adds a bus playing through the master bus, see
+    /// [`AudioScene::add_bus`](crate::audio::AudioScene::add_bus)
+    pub(crate) fn add_bus(&mut self, name: &str) -> bool {
+        if self.has_bus(name) {
+            return false;
+        }
+
+        let track = self.buses[0]
+            .track
+            .as_mut()
+            .and_then(|master| add_track(name, master.add_sub_track(TrackBuilder::new())));
+        self.buses.push(Bus::new(name, track));
+        true
+    }
+
+    pub fn has_bus(&self, name: &str) -> bool {
+        self.bus(name).is_some()
+    }
+
+    /// The names of all buses, starting with [`MASTER_BUS`]
+    pub fn bus_names(&self) -> impl Iterator<Item = &str> {
+        self.buses.iter().map(|bus| bus.name.as_str())
+    }
+
+    /// Changes the volume of a bus, fading over a few milliseconds. Returns `false` if
+    /// there's no such bus.
+    pub fn set_volume(&mut self, name: &str, volume: f32) -> bool {
+        let Some(bus) = self.bus_mut(name) else {
+            return false;
+        };
+        bus.volume = volume.max(0.0);
+        bus.apply();
+        true
+    }
+
+    pub fn volume(&self, name: &str) -> Option<f32> {
+        self.bus(name).map(|bus| bus.volume)
+    }
+
+    /// Silences a bus without forgetting its volume. Returns `false` if there's no such bus.
+    pub fn set_muted(&mut self, name: &str, muted: bool) -> bool {
+        let Some(bus) = self.bus_mut(name) else {
+            return false;
+        };
+        bus.muted = muted;
+        bus.apply();
+        true
+    }
+
+    pub fn is_muted(&self, name: &str) -> Option<bool> {
+        self.bus(name).map(|bus| bus.muted)
+    }
+
+    /// The volume sounds on a bus end up with, after muting and the master volume
+    pub fn effective_volume(&self, name: &str) -> Option<f32> {
+        let audible = |bus: &Bus| if bus.muted { 0.0 } else { bus.volume };
+        let bus = self.bus(name)?;
+        if bus.name == MASTER_BUS {
+            return Some(audible(bus));
+        }
+        Some(audible(bus) * audible(&self.buses[0]))
+    }
+
+    pub(crate) fn track_mut(&mut self, name: &str) -> Option<&mut TrackHandle> {
+        self.bus_mut(name)?.track.as_mut()
+    }
+
+    fn bus(&self, name: &str) -> Option<&Bus> {
+        self.buses.iter().find(|bus| bus.name == name)
+    }
+
+    fn bus_mut(&mut self, name: &str) -> Option<&mut Bus> {
+        self.buses.iter_mut().find(|bus| bus.name == name)
+    }
+}
+
+fn add_track<E: std::fmt::Display>(
+    name: &str,
+    track: Result<TrackHandle, E>,
+) -> Option<TrackHandle> {
+    track
+        .inspect_err(|e| warn!("Couldn't create the track of audio bus {name:?}: {e}"))
+        .ok()
+}
@@ -1,8 +1,12 @@
+mod mixer;
+
+pub use mixer::*;
+
 use kira::listener::ListenerHandle;
 use kira::track::{SpatialTrackBuilder, SpatialTrackHandle};
 use kira::{AudioManager, AudioManagerSettings, DefaultBackend, Tween};
 use nalgebra::{Quaternion, Vector3};
-use tracing::error;
+use tracing::{error, warn};
 
 struct AudioSceneInner {
     manager: AudioManager<DefaultBackend>,
@@ -37,13 +41,14 @@ impl AudioSceneInner {
 
 pub struct AudioScene {
     inner: Option<AudioSceneInner>,
+    mixer: AudioMixer,
 }
 
 impl Default for AudioScene {
     fn default() -> Self {
-        Self {
-            inner: AudioSceneInner::new(),
-        }
+        let mut inner = AudioSceneInner::new();
+        let mixer = AudioMixer::new(inner.as_mut().map(|this| &mut this.manager));
+        Self { inner, mixer }
     }
 }
 
@@ -62,16 +67,52 @@ impl AudioScene {
         }
     }
 
+    pub fn mixer(&self) -> &AudioMixer {
+        &self.mixer
+    }
+
+    pub fn mixer_mut(&mut self) -> &mut AudioMixer {
+        &mut self.mixer
+    }
+
+    /// Adds a bus to the mixer that plays through the master bus. Returns `false` if a bus
+    /// with this name already exists.
+    pub fn add_bus(&mut self, name: &str) -> bool {
+        self.mixer.add_bus(name)
+    }
+
     /// Returns none if the spatial track limit was reached
     pub fn add_spatial_track(
         &mut self,
         initial_position: Vector3<f32>,
         track: SpatialTrackBuilder,
     ) -> Option<SpatialTrackHandle> {
-        self.inner.as_mut().and_then(|this| {
-            this.manager
-                .add_spatial_sub_track(this.listener.id(), initial_position, track)
-                .ok()
-        })
+        self.add_spatial_track_on_bus(MASTER_BUS, initial_position, track)
+    }
+
+    /// Like [`AudioScene::add_spatial_track`], but plays through a bus of the [`AudioMixer`].
+    /// Unknown buses play through the master bus.
+    pub fn add_spatial_track_on_bus(
+        &mut self,
+        bus: &str,
+        initial_position: Vector3<f32>,
+        track: SpatialTrackBuilder,
+    ) -> Option<SpatialTrackHandle> {
+        let this = self.inner.as_mut()?;
+        let bus = if self.mixer.has_bus(bus) {
+            bus
+        } else {
+            warn!("Audio bus {bus:?} doesn't exist, playing through the master bus");
+            MASTER_BUS
+        };
+
+        let listener = this.listener.id();
+        match self.mixer.track_mut(bus) {
+            Some(parent) => parent.add_spatial_sub_track(listener, initial_position, track),
+            None => this
+                .manager
+                .add_spatial_sub_track(listener, initial_position, track),
+        }
+        .ok()
     }
 }
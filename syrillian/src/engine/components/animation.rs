@@ -22,6 +22,8 @@ pub struct AnimationComponent {
     current: Option<Playback>,
 
     bindings: Vec<Vec<ChannelBinding>>,
+
+    ignore_pause: bool,
 }
 
 /// Position, Rotation, Scale
@@ -35,6 +37,7 @@ impl NewComponent for AnimationComponent {
             clip_indices: Vec::new(),
             current: None,
             bindings: Vec::new(),
+            ignore_pause: false,
         }
     }
 }
@@ -48,8 +51,12 @@ impl Component for AnimationComponent {
             return;
         }
 
-        let dt = world.delta_time().as_secs_f32();
-        pb.time += dt * pb.speed;
+        let dt = if self.ignore_pause {
+            world.delta_time()
+        } else {
+            world.game_delta_time()
+        };
+        pb.time += dt.as_secs_f32() * pb.speed;
 
         let clip = &self.clips[pb.clip_index];
         if clip.duration > 0.0 {
@@ -241,6 +248,16 @@ impl AnimationComponent {
         }
     }
 
+    /// Keeps playing while the world is paused, like for animations in a pause menu. Off by
+    /// default, so animations freeze with the game. See [`World::set_paused`].
+    pub fn set_ignore_pause(&mut self, ignore: bool) {
+        self.ignore_pause = ignore;
+    }
+
+    pub fn ignores_pause(&self) -> bool {
+        self.ignore_pause
+    }
+
     pub fn clips(&self) -> &[AnimationClip] {
         &self.clips
     }
@@ -1,5 +1,6 @@
 use crate::World;
 use crate::assets::HSound;
+use crate::audio::MASTER_BUS;
 use crate::components::{Component, NewComponent};
 use crate::core::GameObjectId;
 use kira::Tween;
@@ -17,6 +18,7 @@ pub struct AudioEmitter {
     asset_handle: Option<HSound>,
     sound_handle: Option<StaticSoundHandle>,
     track_handle: Option<SpatialTrackHandle>,
+    bus: String,
     looping: bool,
     play_triggered: bool,
 }
@@ -28,6 +30,7 @@ impl NewComponent for AudioEmitter {
             asset_handle: None,
             sound_handle: None,
             track_handle: None,
+            bus: MASTER_BUS.to_string(),
             looping: false,
             play_triggered: false,
         }
@@ -37,9 +40,11 @@ impl NewComponent for AudioEmitter {
 impl Component for AudioEmitter {
     fn init(&mut self, world: &mut World) {
         trace!("Initializing new Spatial Track");
-        self.track_handle = world
-            .audio
-            .add_spatial_track(self.parent.transform.position(), SpatialTrackBuilder::new());
+        self.track_handle = world.audio.add_spatial_track_on_bus(
+            &self.bus,
+            self.parent.transform.position(),
+            SpatialTrackBuilder::new(),
+        );
     }
 
     fn update(&mut self, world: &mut World) {
@@ -132,9 +137,36 @@ impl AudioEmitter {
         self.asset_handle = Some(sound);
     }
 
+    /// Plays through a bus of the [`AudioMixer`](crate::audio::AudioMixer), so its volume
+    /// applies to this emitter. Emitters start on the master bus.
+    ///
+    /// A playing sound is stopped. This also replaces a track set with
+    /// [`AudioEmitter::set_track`], so pick the bus first.
+    pub fn set_bus(&mut self, bus: &str) {
+        if self.bus == bus {
+            return;
+        }
+
+        self.stop();
+        self.bus = bus.to_string();
+        if self.track_handle.is_some() {
+            let pos = self.parent.transform.position();
+            self.track_handle = World::instance().audio.add_spatial_track_on_bus(
+                &self.bus,
+                pos,
+                SpatialTrackBuilder::new(),
+            );
+        }
+    }
+
+    /// The name of the bus this emitter plays through
+    pub fn bus(&self) -> &str {
+        &self.bus
+    }
+
     pub fn set_track(&mut self, world: &mut World, track: SpatialTrackBuilder) -> &mut Self {
         let pos = self.parent.transform.position();
-        self.track_handle = world.audio.add_spatial_track(pos, track);
+        self.track_handle = world.audio.add_spatial_track_on_bus(&self.bus, pos, track);
         if self.track_handle.is_none() {
             warn!("Spatial track limit reached");
         }
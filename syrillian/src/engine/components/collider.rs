@@ -1,9 +1,11 @@
 use self::ColliderError::{DesyncedCollider, InvalidMesh, InvalidMeshRef, NoMeshRenderer};
 use crate::World;
-use crate::components::{Component, MeshRenderer, NewComponent, RigidBodyComponent};
+use crate::components::{CRef, Component, MeshRenderer, NewComponent, RigidBodyComponent};
 use crate::core::GameObjectId;
 use crate::engine::assets::{HMesh, Mesh};
-use nalgebra::{Point3, Vector3};
+use crate::physics::Contact;
+use crate::physics::validation::validated;
+use nalgebra::{Isometry3, Point3, Vector3};
 use rapier3d::prelude::*;
 use snafu::Snafu;
 use tracing::{trace, warn};
@@ -30,6 +32,11 @@ pub struct Collider3D {
     parent: GameObjectId,
     shape_kind: ColliderShapeKind,
     last_scale: Vector3<f32>,
+    scale: Vector3<f32>,
+    local_transform: Isometry3<f32>,
+    mass_mode: Option<MassMode>,
+    /// Whether the collider was enabled before its object was deactivated
+    enabled_while_active: bool,
 
     #[cfg(debug_assertions)]
     enable_debug_render: bool, // TODO: Sync with GPU
@@ -39,10 +46,22 @@ pub struct Collider3D {
     was_debug_enabled: bool,
 }
 
+const DEFAULT_DENSITY: f32 = 1.0;
+
+/// How the mass of a collider is decided, see [`Collider3D::set_mass`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MassMode {
+    /// Mass per unit of volume, so the mass changes with the size of the shape
+    Density(f32),
+    /// A fixed mass, whatever the size of the shape
+    Mass(f32),
+}
+
 #[derive(Debug, Clone)]
 enum ColliderShapeKind {
     Cuboid,
     Mesh(HMesh),
+    Custom(SharedShape),
 }
 
 #[derive(Debug, Snafu)]
@@ -78,6 +97,10 @@ impl NewComponent for Collider3D {
             parent,
             shape_kind: ColliderShapeKind::Cuboid,
             last_scale: scale,
+            scale: Vector3::new(1.0, 1.0, 1.0),
+            local_transform: Isometry3::identity(),
+            mass_mode: None,
+            enabled_while_active: true,
 
             #[cfg(debug_assertions)]
             enable_debug_render: true,
@@ -152,7 +175,20 @@ impl Component for Collider3D {
         }
     }
 
+    fn on_active_changed(&mut self, world: &mut World, active: bool) {
+        let Some(collider) = world.physics.collider_set.get_mut(self.phys_handle) else {
+            return;
+        };
+        if active {
+            collider.set_enabled(self.enabled_while_active);
+        } else {
+            self.enabled_while_active = collider.is_enabled();
+            collider.set_enabled(false);
+        }
+    }
+
     fn delete(&mut self, world: &mut World) {
+        world.physics.clear_collider_material(self.phys_handle);
         world.physics.collider_set.remove(
             self.phys_handle,
             &mut world.physics.island_manager,
@@ -186,11 +222,17 @@ impl Collider3D {
                 let mesh = world.assets.meshes.try_get(*handle)?;
                 SharedShape::mesh_with_scale(&mesh, scale)
             }
+            ColliderShapeKind::Custom(shape) => Some(scale_shape(shape, scale)),
         }
     }
 
+    /// The object's scale combined with the collider's own, see [`Collider3D::set_scale`]
+    fn effective_scale(&self) -> Vector3<f32> {
+        Self::sanitize_scale(self.parent.transform.scale().component_mul(&self.scale))
+    }
+
     fn sync_with_transform_world(&mut self, world: &mut World, force_pose: bool) {
-        let scale = self.parent.transform.scale();
+        let scale = self.effective_scale();
         let new_shape = ((scale - self.last_scale).norm() > f32::EPSILON)
             .then(|| self.build_shape_for_scale_world(world, scale));
 
@@ -208,28 +250,204 @@ impl Collider3D {
         }
 
         if force_pose || self.linked_to_body.is_none() {
-            collider.set_translation(self.parent.transform.position());
-            collider.set_rotation(self.parent.transform.rotation());
+            let object_pose = Isometry3::from_parts(
+                self.parent.transform.position().into(),
+                self.parent.transform.rotation(),
+            );
+            collider.set_position(object_pose * self.local_transform);
         }
 
         self.last_scale = scale;
     }
 
+    /// Builds the physics shape again from its source, e.g. after the vertices of the mesh
+    /// backing this collider were changed.
+    pub fn rebuild_shape(&mut self) {
+        let world = World::instance();
+        let scale = self.effective_scale();
+
+        let Some(shape) = self.build_shape_for_scale_world(world, scale) else {
+            warn!("[Collider] Couldn't rebuild the collider shape");
+            return;
+        };
+        let Some(collider) = self.collider_mut() else {
+            debug_panic!("[Collider] No collider found when trying to rebuild its shape");
+            return;
+        };
+
+        collider.set_shape(shape);
+        self.last_scale = scale;
+
+        #[cfg(debug_assertions)]
+        {
+            self.debug_collider_mesh = None;
+        }
+    }
+
+    /// Offsets the collider relative to its object, or to the rigid body it's attached to.
+    ///
+    /// This only moves the physics shape; the object, its body and any visual mesh stay where
+    /// they are. A character capsule can for example be lifted so it rests on its feet.
+    pub fn set_local_transform(&mut self, local_transform: Isometry3<f32>) {
+        self.local_transform = local_transform;
+
+        let world = World::instance();
+        if let Some(collider) = self.collider_mut() {
+            collider.set_position_wrt_parent(local_transform);
+        }
+        self.sync_with_transform_world(world, true);
+    }
+
+    /// The offset of the collider relative to its object. See [`Collider3D::set_local_transform`].
+    pub fn local_transform(&self) -> Isometry3<f32> {
+        self.local_transform
+    }
+
+    /// Switches the collider off or back on, like for an enemy phasing through walls.
+    ///
+    /// A disabled collider keeps its handle, shape and settings, but it doesn't collide,
+    /// trigger, add mass to its body or show up in raycasts and other queries. Enabling it
+    /// again brings all of that back from the next physics step on. While its object is
+    /// [inactive](World::set_active), the collider stays off either way, and comes back in the
+    /// state set here.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if !World::instance().is_active(self.parent) {
+            self.enabled_while_active = enabled;
+            return;
+        }
+        if let Some(collider) = self.collider_mut() {
+            collider.set_enabled(enabled);
+        }
+    }
+
+    /// Whether the collider is switched on, see [`Collider3D::set_enabled`]
+    pub fn is_enabled(&self) -> bool {
+        if !World::instance().is_active(self.parent) {
+            return self.enabled_while_active;
+        }
+        self.collider()
+            .is_some_and(|collider| collider.is_enabled())
+    }
+
+    /// Everything this collider touched during the last physics step, with contact points
+    /// and normals in world space. See [`PhysicsManager::contacts`](crate::physics::PhysicsManager::contacts).
+    pub fn contacts(&self) -> Vec<Contact> {
+        World::instance().physics.contacts(self.phys_handle)
+    }
+
+    /// Objects overlapping this collider after the last physics step, if either is a sensor.
+    /// See [`PhysicsManager::currently_overlapping`](crate::physics::PhysicsManager::currently_overlapping).
+    pub fn currently_overlapping(&self) -> impl Iterator<Item = GameObjectId> + 'static {
+        World::instance()
+            .physics
+            .currently_overlapping(self.phys_handle)
+    }
+
+    /// Uses the friction and restitution of a registered preset. Returns `false` if there's
+    /// no preset with this name. See [`PhysicsManager::apply_material`](crate::physics::PhysicsManager::apply_material).
+    pub fn set_material(&mut self, name: &str) -> bool {
+        World::instance()
+            .physics
+            .apply_material(self.phys_handle, name)
+    }
+
+    /// The name of the preset this collider uses, if any
+    pub fn material(&self) -> Option<&str> {
+        World::instance()
+            .physics
+            .collider_material(self.phys_handle)
+    }
+
+    /// Moves the collider into a named layer, see [`PhysicsManager::set_collider_layer`](crate::physics::PhysicsManager::set_collider_layer).
+    pub fn set_layer(&mut self, name: &str) -> bool {
+        World::instance()
+            .physics
+            .set_collider_layer(self.phys_handle, name)
+    }
+
+    /// The name of the layer this collider is in
+    pub fn layer(&self) -> Option<&str> {
+        World::instance().physics.collider_layer(self.phys_handle)
+    }
+
+    /// Derives the mass from the volume of the shape, which is the default with a density of
+    /// 1. Scaling the object up makes it heavier.
+    ///
+    /// Replaces a mass set with [`Collider3D::set_mass`], the last call wins.
+    pub fn set_density(&mut self, density: f32) {
+        self.set_mass_mode(MassMode::Density(density));
+    }
+
+    /// Gives the collider an exact mass, and lets its density follow from the volume of the
+    /// shape. The mass stays the same when the object is scaled, only the inertia changes.
+    ///
+    /// Replaces a density set with [`Collider3D::set_density`], the last call wins. The mass
+    /// properties of the rigid body are recomputed right away.
+    pub fn set_mass(&mut self, mass: f32) {
+        self.set_mass_mode(MassMode::Mass(mass));
+    }
+
+    /// Whether the mass comes from a density or is set directly
+    pub fn mass_mode(&self) -> MassMode {
+        self.mass_mode.unwrap_or(MassMode::Density(DEFAULT_DENSITY))
+    }
+
+    /// The mass of the collider, however it was set
+    pub fn mass(&self) -> f32 {
+        self.collider().map_or(0.0, Collider::mass)
+    }
+
+    fn set_mass_mode(&mut self, mode: MassMode) {
+        match (self.mass_mode, mode) {
+            (Some(MassMode::Density(_)), MassMode::Mass(_)) => warn!(
+                "[Collider] {}: Setting a mass replaces the density set before",
+                self.parent.name
+            ),
+            (Some(MassMode::Mass(_)), MassMode::Density(_)) => warn!(
+                "[Collider] {}: Setting a density replaces the mass set before",
+                self.parent.name
+            ),
+            _ => {}
+        }
+        self.mass_mode = Some(mode);
+
+        let physics = &mut World::instance().physics;
+        let Some(collider) = physics.collider_set.get_mut(self.phys_handle) else {
+            debug_panic!("[Collider] No collider found when trying to set its mass");
+            return;
+        };
+        match mode {
+            MassMode::Density(density) => collider.set_density(density),
+            MassMode::Mass(mass) => collider.set_mass(mass),
+        }
+
+        if let Some(body) = collider
+            .parent()
+            .and_then(|body| physics.rigid_body_set.get_mut(body))
+        {
+            body.recompute_mass_properties_from_colliders(&physics.collider_set);
+        }
+    }
+
     pub fn collider(&self) -> Option<&Collider> {
-        World::instance().physics.collider_set.get(self.phys_handle)
+        let collider = World::instance().physics.collider_set.get(self.phys_handle);
+        validated(collider, self.phys_handle, self.parent, "Collider3D")
     }
 
     pub fn collider_mut(&self) -> Option<&mut Collider> {
-        World::instance()
+        let collider = World::instance()
             .physics
             .collider_set
-            .get_mut(self.phys_handle)
+            .get_mut(self.phys_handle);
+        validated(collider, self.phys_handle, self.parent, "Collider3D")
     }
 
     fn default_collider(parent: GameObjectId, shape: SharedShape) -> Collider {
         ColliderBuilder::new(shape)
-            .density(1.0)
+            .density(DEFAULT_DENSITY)
             .friction(0.999)
+            // the first group is the default layer
+            .collision_groups(InteractionGroups::all().with_memberships(Group::GROUP_1))
             .user_data(parent.as_ffi() as u128)
             .build()
     }
@@ -242,11 +460,103 @@ impl Collider3D {
         );
 
         self.linked_to_body = h_body;
+        if let Some(collider) = world.physics.collider_set.get_mut(self.phys_handle) {
+            collider.set_position_wrt_parent(self.local_transform);
+        }
 
         let force_pose = self.linked_to_body.is_none();
         self.sync_with_transform_world(world, force_pose);
     }
 
+    /// Replaces the collider shape with any rapier shape, like a capsule or a ball.
+    ///
+    /// The shape is given at a scale of 1, and scaled along with the object like the default
+    /// cuboid. See [`Collider3D::set_scale`] for how each shape scales.
+    pub fn set_shape(&mut self, shape: SharedShape) {
+        let scale = self.effective_scale();
+        let Some(collider) = self.collider_mut() else {
+            debug_panic!("[Collider] No collider found when trying to set its shape");
+            return;
+        };
+
+        collider.set_shape(scale_shape(&shape, scale));
+        self.shape_kind = ColliderShapeKind::Custom(shape);
+        self.last_scale = scale;
+
+        #[cfg(debug_assertions)]
+        {
+            self.debug_collider_mesh = None;
+        }
+    }
+
+    /// Adds a collider to `object` that's a [capsule](Collider3D::use_capsule) right away,
+    /// with the same parameters as [`Mesh::capsule`](crate::assets::Mesh::capsule)
+    pub fn capsule(mut object: GameObjectId, half_height: f32, radius: f32) -> CRef<Collider3D> {
+        let mut collider = object.add_component::<Collider3D>();
+        collider.use_capsule(half_height, radius);
+        collider
+    }
+
+    /// Adds a collider to `object` that's a [cylinder](Collider3D::use_cylinder) right away
+    pub fn cylinder(mut object: GameObjectId, half_height: f32, radius: f32) -> CRef<Collider3D> {
+        let mut collider = object.add_component::<Collider3D>();
+        collider.use_cylinder(half_height, radius);
+        collider
+    }
+
+    /// Uses a capsule standing upright along the Y axis, centered on the object.
+    ///
+    /// The total height is `2 * (half_height + radius)` at a scale of 1. Tilt it with
+    /// [`Collider3D::set_local_transform`] if needed.
+    pub fn use_capsule(&mut self, half_height: f32, radius: f32) {
+        self.set_shape(SharedShape::capsule_y(half_height, radius));
+    }
+
+    /// Uses a cylinder standing upright along the Y axis, centered on the object, with a
+    /// total height of `2 * half_height`. See [`Collider3D::use_capsule`].
+    pub fn use_cylinder(&mut self, half_height: f32, radius: f32) {
+        self.set_shape(SharedShape::cylinder(half_height, radius));
+    }
+
+    /// Scales the collider on top of the object's own scale, for example to make a pickup
+    /// easier to hit than it looks. Defaults to 1 on all axes.
+    ///
+    /// Whenever the combined scale changes, the shape is rebuilt from its unscaled source.
+    /// How well that works depends on the shape:
+    ///
+    /// | Shape                                  | Uniform scale | Non-uniform scale    |
+    /// |----------------------------------------|---------------|----------------------|
+    /// | Cuboid (default), mesh, convex hull    | exact         | exact                |
+    /// | Triangle, segment, heightfield, voxels | exact         | exact                |
+    /// | Ball, capsule, cylinder, cone          | exact         | convex approximation |
+    /// | Rounded shapes                         | exact         | convex approximation |
+    /// | Compound, half space                   | not scaled    | not scaled           |
+    ///
+    /// The approximations are convex hulls, which are slower to collide with than the round
+    /// shapes they replace, and a warning is logged whenever one is built. Shapes that can't
+    /// be scaled keep their size, with a warning too.
+    pub fn set_scale(&mut self, scale: Vector3<f32>) {
+        self.scale = scale;
+        let world = World::instance();
+        self.sync_with_transform_world(world, false);
+
+        #[cfg(debug_assertions)]
+        {
+            self.debug_collider_mesh = None;
+        }
+    }
+
+    /// The collider's own scale, see [`Collider3D::set_scale`]
+    pub fn scale(&self) -> Vector3<f32> {
+        self.scale
+    }
+
+    /// The world space bounds of the collider at its current pose, computed from the exact
+    /// shape
+    pub fn aabb(&self) -> Option<Aabb> {
+        self.collider().map(Collider::compute_aabb)
+    }
+
     pub fn use_mesh(&mut self) {
         if let Err(e) = self.try_use_mesh() {
             warn!("{e}");
@@ -267,7 +577,7 @@ impl Collider3D {
             .ok_or(NoMeshRenderer)?;
 
         let handle = mesh_renderer.mesh();
-        let scale = Self::sanitize_scale(self.parent.transform.scale());
+        let scale = self.effective_scale();
         let shape = {
             let mesh = world.assets.meshes.try_get(handle).ok_or(InvalidMeshRef)?;
             SharedShape::mesh_with_scale(&mesh, scale).ok_or(InvalidMesh)?
@@ -342,6 +652,53 @@ impl Collider3D {
     }
 }
 
+/// Round shapes become convex hulls with this many subdivisions when scaled non-uniformly
+const APPROXIMATION_SUBDIVISIONS: u32 = 16;
+
+/// Scales a shape given at a scale of 1, see [`Collider3D::set_scale`]
+fn scale_shape(shape: &SharedShape, scale: Vector3<f32>) -> SharedShape {
+    if scale == Vector3::new(1.0, 1.0, 1.0) {
+        return shape.clone();
+    }
+
+    let uniform = scale.x == scale.y && scale.y == scale.z;
+    match shape.as_typed_shape() {
+        TypedShape::Cuboid(cuboid) => {
+            return SharedShape::new(Cuboid::new(cuboid.half_extents.component_mul(&scale)));
+        }
+        TypedShape::TriMesh(mesh) => return SharedShape::new(mesh.clone().scaled(&scale)),
+        TypedShape::Compound(_) | TypedShape::HalfSpace(_) => {
+            warn!("[Collider] {:?} shapes can't be scaled", shape.shape_type());
+            return shape.clone();
+        }
+        TypedShape::Ball(_)
+        | TypedShape::Capsule(_)
+        | TypedShape::Cylinder(_)
+        | TypedShape::Cone(_)
+        | TypedShape::RoundCuboid(_)
+        | TypedShape::RoundTriangle(_)
+        | TypedShape::RoundCylinder(_)
+        | TypedShape::RoundCone(_)
+        | TypedShape::RoundConvexPolyhedron(_)
+            if !uniform =>
+        {
+            warn!(
+                "[Collider] {:?} can't be scaled non-uniformly, approximating it with a convex hull",
+                shape.shape_type()
+            );
+        }
+        _ => {}
+    }
+
+    match shape.scale_dyn(&scale, APPROXIMATION_SUBDIVISIONS) {
+        Some(scaled) => SharedShape(scaled.into()),
+        None => {
+            warn!("[Collider] Couldn't scale {:?}", shape.shape_type());
+            shape.clone()
+        }
+    }
+}
+
 pub trait MeshShapeExtra<T> {
     fn mesh(mesh: &Mesh) -> Option<T>;
     fn mesh_with_scale(mesh: &Mesh, scale: Vector3<f32>) -> Option<T>;
@@ -0,0 +1,262 @@
+//! A third-person camera that trails behind a target.
+//!
+//! ```rust
+//! use nalgebra::Vector3;
+//! use syrillian::World;
+//! use syrillian::components::FollowCameraComponent;
+//!
+//! let mut world = World::builder().build();
+//! let player = world.new_object("Player");
+//! let mut camera = world.new_camera().parent();
+//!
+//! let mut follow = camera.add_component::<FollowCameraComponent>();
+//! follow.set_target(Some(player));
+//! follow.offset = Vector3::new(0.0, 3.0, 6.0);
+//! ```
+
+use crate::World;
+use crate::components::{
+    CameraComponent, Component, Field, FieldValue, NewComponent, Reflect, ReflectError, priority,
+};
+use crate::core::{GameObjectId, UpAxis};
+use crate::physics::{LayerMask, QueryFilterBuilder};
+use nalgebra::{Point3, UnitQuaternion, Vector3};
+use rapier3d::prelude::Ray;
+use tracing::warn;
+
+/// Moves its object, usually a camera, toward a point behind a target and keeps it looking
+/// at the target.
+///
+/// The position follows a critically damped spring, so it catches up without overshooting.
+/// `position_smoothing` is roughly the time in seconds it lags behind. Rotation eases toward
+/// the target with `rotation_smoothing` seconds of lag the same way. Set either to zero to
+/// follow rigidly.
+///
+/// With `avoid_walls`, a ray is cast from the target to the camera every frame, like
+/// [`World::raycast`]. If something is in the way, the camera is pulled in in front of it
+/// right away, so it never clips through walls, while moving back out is smoothed.
+#[derive(Debug)]
+pub struct FollowCameraComponent {
+    parent: GameObjectId,
+    target: Option<GameObjectId>,
+    /// Where the camera sits relative to the target. Default: 2 up and 5 behind
+    pub offset: Vector3<f32>,
+    /// Rotate the offset with the target, so the camera stays behind it when it turns.
+    /// Default: true
+    pub rotate_offset: bool,
+    /// The point of the target that's looked at, relative to its position. Default: 1 up
+    pub look_offset: Vector3<f32>,
+    /// Seconds the position lags behind. Default: 0.3
+    pub position_smoothing: f32,
+    /// Seconds the rotation lags behind. Default: 0.1
+    pub rotation_smoothing: f32,
+    /// Keep walls between the target and the camera from blocking the view. Default: true
+    pub avoid_walls: bool,
+    /// How far the camera stays in front of walls. Default: 0.2
+    pub wall_margin: f32,
+    /// The layers that count as walls. Default: all layers
+    pub wall_layers: LayerMask,
+    position: Vector3<f32>,
+    velocity: Vector3<f32>,
+    snapped: bool,
+    up_axis: UpAxis,
+}
+
+impl NewComponent for FollowCameraComponent {
+    fn new(parent: GameObjectId) -> Self {
+        let up_axis = World::instance().up_axis();
+        FollowCameraComponent {
+            parent,
+            target: None,
+            offset: up_axis.from_y_up() * Vector3::new(0.0, 2.0, 5.0),
+            rotate_offset: true,
+            look_offset: up_axis.up(),
+            position_smoothing: 0.3,
+            rotation_smoothing: 0.1,
+            avoid_walls: true,
+            wall_margin: 0.2,
+            wall_layers: LayerMask::all(),
+            position: Vector3::zeros(),
+            velocity: Vector3::zeros(),
+            snapped: false,
+            up_axis,
+        }
+    }
+}
+
+impl FollowCameraComponent {
+    pub fn target(&self) -> Option<GameObjectId> {
+        self.target
+    }
+
+    /// Starts following another object. The camera jumps to its spot behind the new target
+    /// on the next frame instead of flying over.
+    pub fn set_target(&mut self, target: Option<GameObjectId>) {
+        self.target = target;
+        self.snap();
+    }
+
+    /// Jumps to the target on the next frame without smoothing, for example after the target
+    /// was teleported
+    pub fn snap(&mut self) {
+        self.snapped = false;
+    }
+
+    /// Moves the camera by `delta_time` seconds toward the target. This runs every frame on
+    /// its own, and is only needed to step the camera by hand.
+    pub fn advance(&mut self, world: &World, delta_time: f32) {
+        let Some(target) = self.target.filter(|t| t.exists()) else {
+            return;
+        };
+
+        let pivot = target.transform.position() + self.look_offset;
+        let offset = if self.rotate_offset {
+            target.transform.rotation() * self.offset
+        } else {
+            self.offset
+        };
+        let desired = target.transform.position() + offset;
+
+        if self.snapped {
+            self.position = smooth_damp(
+                self.position,
+                desired,
+                &mut self.velocity,
+                self.position_smoothing,
+                delta_time,
+            );
+        } else {
+            self.position = desired;
+            self.velocity = Vector3::zeros();
+        }
+
+        let position = if self.avoid_walls {
+            self.clip_to_walls(world, target, pivot)
+        } else {
+            self.position
+        };
+
+        let mut parent = self.parent;
+        let transform = &mut parent.transform;
+        transform.set_position_vec(position);
+
+        let look = position - pivot;
+        if look.norm_squared() > f32::EPSILON {
+            let rotation = UnitQuaternion::face_towards(&look, &self.up_axis.up());
+            let rotation = if self.snapped && self.rotation_smoothing > 0.0 {
+                let t = 1.0 - (-delta_time / self.rotation_smoothing).exp();
+                transform.rotation().slerp(&rotation, t)
+            } else {
+                rotation
+            };
+            transform.set_rotation(rotation);
+        }
+
+        self.snapped = true;
+    }
+
+    /// The smoothed position, pulled in front of the first wall between it and the pivot
+    fn clip_to_walls(
+        &self,
+        world: &World,
+        target: GameObjectId,
+        pivot: Vector3<f32>,
+    ) -> Vector3<f32> {
+        let offset = self.position - pivot;
+        let distance = offset.norm();
+        if distance <= f32::EPSILON {
+            return self.position;
+        }
+
+        let dir = offset / distance;
+        let ray = Ray::new(Point3::from(pivot), dir);
+        let filter = QueryFilterBuilder::new()
+            .exclude(target)
+            .exclude(self.parent)
+            .exclude_sensors();
+        match world.raycast(&ray, distance + self.wall_margin, &self.wall_layers, filter) {
+            Some((hit, _)) => pivot + dir * (hit - self.wall_margin).clamp(0.0, distance),
+            None => self.position,
+        }
+    }
+}
+
+/// Moves `current` toward `target` like a critically damped spring that settles in about
+/// `smooth_time` seconds
+fn smooth_damp(
+    current: Vector3<f32>,
+    target: Vector3<f32>,
+    velocity: &mut Vector3<f32>,
+    smooth_time: f32,
+    delta_time: f32,
+) -> Vector3<f32> {
+    if smooth_time <= 0.0 {
+        *velocity = Vector3::zeros();
+        return target;
+    }
+
+    let omega = 2.0 / smooth_time;
+    let x = omega * delta_time;
+    // approximates e^-x
+    let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+    let change = current - target;
+    let temp = (*velocity + change * omega) * delta_time;
+    *velocity = (*velocity - temp * omega) * decay;
+    target + (change + temp) * decay
+}
+
+impl Component for FollowCameraComponent {
+    fn on_start(&mut self, _world: &mut World) {
+        if self.parent.get_component::<CameraComponent>().is_none() {
+            warn!(
+                "[FollowCamera] {} has no camera, it will follow the target without one",
+                self.parent.name
+            );
+        }
+    }
+
+    fn update_priority(&self) -> i32 {
+        priority::CAMERA
+    }
+
+    fn late_update(&mut self, world: &mut World) {
+        let delta_time = world.delta_time().as_secs_f32();
+        self.advance(world, delta_time);
+    }
+
+    fn reflect(&mut self) -> Option<&mut dyn Reflect> {
+        Some(self)
+    }
+}
+
+impl Reflect for FollowCameraComponent {
+    fn type_name(&self) -> &'static str {
+        "FollowCamera"
+    }
+
+    fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("offset", self.offset),
+            Field::new("rotate_offset", self.rotate_offset),
+            Field::new("look_offset", self.look_offset),
+            Field::new("position_smoothing", self.position_smoothing),
+            Field::new("rotation_smoothing", self.rotation_smoothing),
+            Field::new("avoid_walls", self.avoid_walls),
+            Field::new("wall_margin", self.wall_margin),
+        ]
+    }
+
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), ReflectError> {
+        match name {
+            "offset" => self.offset = value.as_vec3(name)?,
+            "rotate_offset" => self.rotate_offset = value.as_bool(name)?,
+            "look_offset" => self.look_offset = value.as_vec3(name)?,
+            "position_smoothing" => self.position_smoothing = value.as_f32(name)?,
+            "rotation_smoothing" => self.rotation_smoothing = value.as_f32(name)?,
+            "avoid_walls" => self.avoid_walls = value.as_bool(name)?,
+            "wall_margin" => self.wall_margin = value.as_f32(name)?,
+            _ => return Err(ReflectError::unknown(name)),
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use crate::World;
 use crate::components::{CameraComponent, Component, NewComponent};
-use crate::core::{GameObjectId, Transform};
+use crate::core::{GameObjectId, Transform, UpAxis};
 use crate::input::InputManager;
 use crate::utils::FloatMathExt;
 use crate::windowing::RenderTargetId;
@@ -53,6 +53,7 @@ pub struct FPSCameraConfig {
 pub struct FirstPersonCameraController {
     parent: GameObjectId,
     pub config: FPSCameraConfig,
+    up_axis: UpAxis,
 
     yaw: f32,
     pitch: f32,
@@ -107,6 +108,7 @@ impl NewComponent for FirstPersonCameraController {
         FirstPersonCameraController {
             parent,
             config: FPSCameraConfig::default(),
+            up_axis: World::instance().up_axis(),
             yaw: 0.0,
             pitch: 0.0,
             smooth_roll: 0.0,
@@ -237,8 +239,10 @@ impl FirstPersonCameraController {
         delta_time: f32,
         mouse_delta: &Vector2<f32>,
     ) {
-        if self.vel.xz().magnitude() < 0.01
-            || self.vel.xz().normalize().dot(&transform.forward().xz()) > 0.9
+        let horizontal_vel = self.up_axis.horizontal(&self.vel);
+        let horizontal_forward = self.up_axis.horizontal(&transform.forward());
+        if horizontal_vel.magnitude() < 0.01
+            || horizontal_vel.normalize().dot(&horizontal_forward) > 0.9
         {
             self.update_roll(mouse_delta.x, self.config.max_roll);
         }
@@ -267,7 +271,9 @@ impl FirstPersonCameraController {
             .lerp(&Vector3::zeros(), self.config.smoothing_speed * delta_time);
 
         if let Some(mut parent) = self.parent.parent {
-            parent.transform.set_local_rotation(yaw_rot);
+            parent
+                .transform
+                .set_local_rotation(self.up_axis.from_y_up() * yaw_rot);
         }
     }
 
@@ -295,7 +301,8 @@ impl FirstPersonCameraController {
 
     fn calculate_jump_bob(&mut self, delta_time: f32) {
         if !self.is_grounded {
-            self.jump_offset = self.vel.y.clamp(-3.5, 3.5) / 3.5 * self.config.jump_bob_height;
+            let vertical_vel = self.up_axis.height(&self.vel);
+            self.jump_offset = vertical_vel.clamp(-3.5, 3.5) / 3.5 * self.config.jump_bob_height;
         }
 
         self.jump_bob_interp_t = self
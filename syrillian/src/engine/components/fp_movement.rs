@@ -3,12 +3,13 @@ use crate::components::{
     CRef, CWeak, CameraComponent, Component, FirstPersonCameraController, NewComponent,
     RigidBodyComponent,
 };
-use crate::core::GameObjectId;
+use crate::core::{GameObjectId, UpAxis};
+use crate::physics::QueryFilterBuilder;
 use crate::windowing::RenderTargetId;
 use gilrs::Axis;
 use nalgebra::Vector3;
 use num_traits::Zero;
-use rapier3d::prelude::{LockedAxes, QueryFilter, RigidBody, vector};
+use rapier3d::prelude::{LockedAxes, RigidBody};
 use tracing::warn;
 use winit::keyboard::KeyCode;
 
@@ -24,6 +25,7 @@ pub struct FirstPersonMovementController {
     is_grounded: bool,
     pub air_control: f32,
     feet_height: f32,
+    up_axis: UpAxis,
 }
 
 impl NewComponent for FirstPersonMovementController {
@@ -40,6 +42,7 @@ impl NewComponent for FirstPersonMovementController {
             is_grounded: false,
             air_control: 0.1,
             feet_height: -1.0,
+            up_axis: World::instance().up_axis(),
         }
     }
 }
@@ -98,7 +101,7 @@ impl Component for FirstPersonMovementController {
 
         let jumping = world.input.is_jump_down();
         if jumping && self.is_grounded {
-            body.apply_impulse(vector![0.0, 0.2 * self.jump_factor, 0.0], true);
+            body.apply_impulse(self.up_axis.up() * 0.2 * self.jump_factor, true);
         }
 
         let (lr_movement, fb_movement, speed_factor, max_speed) =
@@ -127,9 +130,9 @@ impl Component for FirstPersonMovementController {
             }
         }
 
-        let mut linvel = *body.linvel();
-        linvel.x = self.velocity.x;
-        linvel.z = self.velocity.z;
+        let up = self.up_axis.up();
+        let linvel =
+            up * self.up_axis.height(body.linvel()) + self.up_axis.horizontal(&self.velocity);
 
         body.set_linvel(linvel, true);
     }
@@ -146,13 +149,13 @@ impl FirstPersonMovementController {
         };
 
         let mut position = *body.position();
-        position.translation.y += self.feet_height + 0.05;
-        const DIR: Vector3<f32> = Vector3::new(0.0, -1.0, 0.0);
-        let filter = QueryFilter::new().exclude_rigid_body(rigid_body.body_handle);
+        let up = self.up_axis.up();
+        position.translation.vector += up * (self.feet_height + 0.05);
+        let filter = QueryFilterBuilder::new().exclude(rigid_body.parent());
 
         self.is_grounded = world
             .physics
-            .cast_sphere(0.25, 0.15, &position, &DIR, filter)
+            .cast_sphere(0.25, 0.15, &position, &-up, filter.build())
             .is_some();
     }
 
@@ -212,11 +215,12 @@ impl FirstPersonMovementController {
         if !self.is_grounded {
             interp_speed *= self.air_control;
         }
-        if self.is_grounded || body.linvel().xz().norm() > 0.05 {
+        let horizontal_linvel = self.up_axis.horizontal(body.linvel());
+        if self.is_grounded || horizontal_linvel.norm() > 0.05 {
             self.velocity = self.velocity.lerp(&target_velocity, interp_speed);
         } else {
-            self.velocity.x = body.linvel().x;
-            self.velocity.z = body.linvel().z;
+            let up = self.up_axis.up();
+            self.velocity = horizontal_linvel + up * self.up_axis.height(&self.velocity);
         }
 
         (lr_movement, fb_movement, speed_factor, max_speed)
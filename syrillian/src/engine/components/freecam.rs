@@ -1,6 +1,6 @@
 use crate::World;
 use crate::components::{CameraComponent, Component, NewComponent};
-use crate::core::GameObjectId;
+use crate::core::{GameObjectId, UpAxis};
 use crate::input::InputManager;
 use crate::windowing::RenderTargetId;
 use gilrs::{Axis, Button};
@@ -14,6 +14,7 @@ pub struct FreecamController {
     parent: GameObjectId,
     pub yaw: f32,
     pub pitch: f32,
+    up_axis: UpAxis,
 }
 
 impl NewComponent for FreecamController {
@@ -24,6 +25,7 @@ impl NewComponent for FreecamController {
             parent,
             yaw: 0.0,
             pitch: 0.0,
+            up_axis: World::instance().up_axis(),
         }
     }
 }
@@ -135,7 +137,7 @@ impl FreecamController {
             UnitQuaternion::from_axis_angle(&Vector3::y_axis(), self.yaw.to_radians());
         let pitch_rotation =
             UnitQuaternion::from_axis_angle(&Vector3::x_axis(), self.pitch.to_radians());
-        let rotation = yaw_rotation * pitch_rotation;
+        let rotation = self.up_axis.from_y_up() * yaw_rotation * pitch_rotation;
 
         transform.set_local_rotation(rotation);
     }
@@ -1,7 +1,7 @@
 use nalgebra::Vector3;
 
 use crate::World;
-use crate::components::{Component, NewComponent};
+use crate::components::{Component, Field, FieldValue, NewComponent, Reflect, ReflectError};
 use crate::core::GameObjectId;
 
 pub struct GravityComponent {
@@ -31,4 +31,32 @@ impl Component for GravityComponent {
         let transform = &mut self.parent.transform;
         transform.translate(Vector3::new(0.0, self.velocity, 0.0));
     }
+
+    fn reflect(&mut self) -> Option<&mut dyn Reflect> {
+        Some(self)
+    }
+}
+
+impl Reflect for GravityComponent {
+    fn type_name(&self) -> &'static str {
+        "Gravity"
+    }
+
+    fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("acceleration_per_sec", self.acceleration_per_sec),
+            Field::new("velocity", self.velocity),
+            Field::new("max_acceleration", self.max_acceleration),
+        ]
+    }
+
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), ReflectError> {
+        match name {
+            "acceleration_per_sec" => self.acceleration_per_sec = value.as_f32(name)?,
+            "velocity" => self.velocity = value.as_f32(name)?,
+            "max_acceleration" => self.max_acceleration = value.as_f32(name)?,
+            _ => return Err(ReflectError::unknown(name)),
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,190 @@
+//! Volumes with their own gravity, like the artificial gravity sections of a space station.
+//!
+//! ```rust
+//! use nalgebra::Vector3;
+//! use syrillian::World;
+//! use syrillian::components::{Collider3D, GravityZoneComponent};
+//!
+//! let mut world = World::builder().build();
+//! let mut hangar = world.new_object("Hangar");
+//! hangar.transform.set_scale(30.0);
+//! hangar.add_component::<Collider3D>();
+//!
+//! let mut zone = hangar.add_component::<GravityZoneComponent>();
+//! zone.gravity = Vector3::zeros();
+//! ```
+
+use crate::World;
+use crate::components::{
+    Collider3D, Component, Field, FieldValue, NewComponent, Reflect, ReflectError,
+};
+use crate::core::GameObjectId;
+use nalgebra::Vector3;
+use rapier3d::prelude::{ColliderHandle, RigidBodyHandle};
+use std::collections::HashMap;
+use tracing::warn;
+
+/// Replaces the world's gravity for dynamic bodies inside the [`Collider3D`] of the same
+/// object, which is turned into a sensor.
+///
+/// Each physics tick, bodies overlapping the zone get an impulse that cancels the world's
+/// gravity and applies the zone's instead, scaled by their gravity scale. A body counts as
+/// inside if any of its colliders is, including colliders of child objects
+/// [linked](Collider3D::link_to_rigid_body) to it. Where zones overlap, the one with the
+/// highest `priority` wins, and between equal priorities the one on the object with the lower
+/// id. Overlaps are the ones of the previous step, so a body entering a zone feels it from the
+/// next tick on.
+#[derive(Debug)]
+pub struct GravityZoneComponent {
+    parent: GameObjectId,
+    /// Gravity acceleration inside the zone. Default: the world's gravity
+    pub gravity: Vector3<f32>,
+    /// Decides which zone applies where zones overlap, higher wins. Default: 0
+    pub priority: i32,
+    collider: Option<ColliderHandle>,
+}
+
+impl NewComponent for GravityZoneComponent {
+    fn new(parent: GameObjectId) -> Self {
+        GravityZoneComponent {
+            parent,
+            gravity: World::instance().physics.gravity,
+            priority: 0,
+            collider: None,
+        }
+    }
+}
+
+/// The dynamic bodies each zone won after the last physics step, keyed by the zone's object.
+/// Worked out once per tick and shared by all zones.
+#[derive(Debug, Default)]
+pub(crate) struct GravityZoneClaims {
+    tick: Option<u64>,
+    bodies: HashMap<GameObjectId, Vec<RigidBodyHandle>>,
+}
+
+/// Decides which zone every dynamic body inside one or more zones belongs to
+fn claim_bodies(world: &World) -> HashMap<GameObjectId, Vec<RigidBodyHandle>> {
+    let zones: HashMap<ColliderHandle, (i32, GameObjectId)> = world
+        .components
+        .values_of_type::<GravityZoneComponent>()
+        .into_iter()
+        .flatten()
+        .filter_map(|zone| Some((zone.collider?, (zone.priority, zone.parent))))
+        .collect();
+    if zones.is_empty() {
+        return HashMap::new();
+    }
+
+    let physics = &world.physics;
+    let mut winners: HashMap<RigidBodyHandle, (i32, GameObjectId)> = HashMap::new();
+    for (a, b) in physics.overlapping_pairs() {
+        for (zone, other) in [(a, b), (b, a)] {
+            let Some(&(priority, owner)) = zones.get(&zone) else {
+                continue;
+            };
+            let Some(body) = physics.collider_set.get(other).and_then(|c| c.parent()) else {
+                continue;
+            };
+            if !physics
+                .rigid_body_set
+                .get(body)
+                .is_some_and(|rb| rb.is_dynamic())
+            {
+                continue;
+            }
+
+            let claim = winners.entry(body).or_insert((priority, owner));
+            if priority > claim.0 || (priority == claim.0 && owner < claim.1) {
+                *claim = (priority, owner);
+            }
+        }
+    }
+
+    let mut bodies: HashMap<GameObjectId, Vec<RigidBodyHandle>> = HashMap::new();
+    for (body, (_, owner)) in winners {
+        bodies.entry(owner).or_default().push(body);
+    }
+    bodies
+}
+
+impl GravityZoneComponent {
+    /// Objects whose bodies get this zone's gravity after the last physics step, by id
+    pub fn affected_objects(&self, world: &World) -> Vec<GameObjectId> {
+        let mut objects: Vec<GameObjectId> = claim_bodies(world)
+            .remove(&self.parent)
+            .into_iter()
+            .flatten()
+            .filter_map(|body| world.physics.rigid_body_set.get(body))
+            .map(|rb| GameObjectId::from_ffi(rb.user_data as u64))
+            .collect();
+        objects.sort();
+        objects
+    }
+}
+
+impl Component for GravityZoneComponent {
+    fn on_start(&mut self, _world: &mut World) {
+        let Some(collider) = self.parent.get_component::<Collider3D>() else {
+            warn!(
+                "[GravityZone] {} has no collider, it won't affect anything",
+                self.parent.name
+            );
+            return;
+        };
+        if let Some(c) = collider.collider_mut() {
+            c.set_sensor(true);
+        }
+        self.collider = Some(collider.phys_handle);
+    }
+
+    fn pre_fixed_update(&mut self, world: &mut World) {
+        let tick = world.physics_tick_count();
+        if world.gravity_zones.tick != Some(tick) {
+            world.gravity_zones = GravityZoneClaims {
+                tick: Some(tick),
+                bodies: claim_bodies(world),
+            };
+        }
+        let Some(bodies) = world.gravity_zones.bodies.remove(&self.parent) else {
+            return;
+        };
+
+        let dt = world.physics.integration_parameters.dt;
+        let correction = self.gravity - world.physics.gravity;
+        for handle in bodies {
+            let Some(body) = world.physics.rigid_body_set.get_mut(handle) else {
+                continue;
+            };
+
+            let impulse = correction * body.mass() * body.gravity_scale() * dt;
+            body.apply_impulse(impulse, true);
+        }
+    }
+
+    fn reflect(&mut self) -> Option<&mut dyn Reflect> {
+        Some(self)
+    }
+}
+
+impl Reflect for GravityZoneComponent {
+    fn type_name(&self) -> &'static str {
+        "GravityZone"
+    }
+
+    fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("gravity", self.gravity),
+            Field::new("priority", self.priority as f32),
+        ]
+    }
+
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), ReflectError> {
+        match name {
+            "gravity" => self.gravity = value.as_vec3(name)?,
+            "priority" => self.priority = value.as_f32(name)? as i32,
+            _ => return Err(ReflectError::unknown(name)),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use crate::World;
+use crate::components::joints::JointError;
+use crate::components::{Collider3D, RigidBodyComponent, SphericalJoint, SpringJoint};
+use crate::core::GameObjectId;
+use nalgebra::{Point3, Vector3};
+use rapier3d::prelude::{ImpulseJointHandle, SharedShape};
+
+/// The joint used between two links of a [`JointChain`]
+#[derive(Debug, Copy, Clone)]
+pub enum ChainJointKind {
+    /// Links pivot freely around the point halfway between them, like a chain
+    Spherical,
+    /// Links are pulled back to their initial distance, like an elastic rope
+    Spring { stiffness: f32, damping: f32 },
+}
+
+/// Builds chains and ropes out of rigid bodies
+pub struct JointChain;
+
+impl JointChain {
+    /// Spawns `segments` bodies evenly spaced on the line between `start` and `end`, and links
+    /// `start`, all segments and `end` with joints of the given kind.
+    ///
+    /// Both ends need a [`RigidBodyComponent`]. Make them kinematic to hang the chain between
+    /// them. Each segment gets a small ball collider and the joints to its neighbours; anchors
+    /// are placed automatically. Returns the segments in order from `start` to `end`.
+    pub fn build(
+        world: &mut World,
+        start: GameObjectId,
+        end: GameObjectId,
+        segments: usize,
+        joint_kind: ChainJointKind,
+    ) -> Result<Vec<GameObjectId>, JointError> {
+        if !start.exists() || !end.exists() {
+            return Err(JointError::InvalidConnector);
+        }
+        if start.get_component::<RigidBodyComponent>().is_none()
+            || end.get_component::<RigidBodyComponent>().is_none()
+        {
+            return Err(JointError::NoConnectorRigidBody);
+        }
+
+        let segments = segments.max(1);
+        let from = start.transform.position();
+        let to = end.transform.position();
+        let step = (to - from) / (segments + 1) as f32;
+        let link_length = step.norm();
+
+        let mut links = Vec::with_capacity(segments);
+        for i in 1..=segments {
+            let mut link = world.new_object(format!("Chain Link {i}"));
+            link.transform.set_position_vec(from + step * i as f32);
+
+            let mut collider = link.add_component::<Collider3D>();
+            collider.set_shape(SharedShape::ball((link_length * 0.25).max(0.01)));
+            link.add_component::<RigidBodyComponent>();
+
+            world.add_child(link);
+            links.push(link);
+        }
+
+        for (i, &link) in links.iter().enumerate() {
+            let previous = if i == 0 { start } else { links[i - 1] };
+            Self::connect(link, previous, joint_kind, link_length)?;
+        }
+        if let Some(&last) = links.last() {
+            Self::connect(last, end, joint_kind, link_length)?;
+        }
+
+        Ok(links)
+    }
+
+    fn connect(
+        mut link: GameObjectId,
+        other: GameObjectId,
+        joint_kind: ChainJointKind,
+        link_length: f32,
+    ) -> Result<ImpulseJointHandle, JointError> {
+        match joint_kind {
+            ChainJointKind::Spherical => {
+                let link_pos = link.transform.position();
+                let other_pos = other.transform.position();
+                let pivot = (link_pos + other_pos) * 0.5;
+
+                let mut joint = link.add_component::<SphericalJoint>();
+                joint.set_contacts_enabled(false);
+                joint.set_anchor1(Point3::from(local_offset(link, pivot - link_pos)));
+                joint.set_anchor2(Point3::from(local_offset(other, pivot - other_pos)));
+                joint.try_connect_to(other)
+            }
+            ChainJointKind::Spring { stiffness, damping } => {
+                let mut joint = link.add_component::<SpringJoint>();
+                joint.configure(link_length, stiffness, damping);
+                joint.try_connect_to(other)
+            }
+        }
+    }
+}
+
+fn local_offset(obj: GameObjectId, offset: Vector3<f32>) -> Vector3<f32> {
+    obj.transform.rotation().inverse() * offset
+}
@@ -0,0 +1,162 @@
+//! Swapping meshes for cheaper ones the further away they're seen from.
+//!
+//! ```rust
+//! use syrillian::World;
+//! use syrillian::assets::HMesh;
+//! use syrillian::components::{LodComponent, MeshRenderer};
+//!
+//! let mut world = World::builder().build();
+//! let mut rock = world.new_object("Rock");
+//! rock.add_component::<MeshRenderer>();
+//!
+//! let mut lod = rock.add_component::<LodComponent>();
+//! lod.add_level(HMesh::SPHERE, 20.0);
+//! lod.add_level(HMesh::UNIT_CUBE, 80.0);
+//! // further away than 80, the rock isn't drawn at all
+//! ```
+
+use crate::World;
+use crate::assets::HMesh;
+use crate::components::{Component, MeshRenderer, NewComponent, priority};
+use crate::core::GameObjectId;
+use tracing::warn;
+
+/// Picks the mesh of the [`MeshRenderer`] on the same object by its distance to the active
+/// camera.
+///
+/// Each level is used up to its `max_distance`, and the first level that covers the distance
+/// wins. Beyond the last level, the renderer is hidden. Levels are kept sorted by distance.
+///
+/// To keep the mesh from flickering when the camera hovers around a boundary, a level is only
+/// left once the distance is `hysteresis` past its boundary, in either direction.
+#[derive(Debug)]
+pub struct LodComponent {
+    parent: GameObjectId,
+    levels: Vec<(HMesh, f32)>,
+    /// How far past a boundary the camera has to be before the level changes. Default: 1
+    pub hysteresis: f32,
+    current: Option<usize>,
+    selected: bool,
+}
+
+impl NewComponent for LodComponent {
+    fn new(parent: GameObjectId) -> Self {
+        LodComponent {
+            parent,
+            levels: Vec::new(),
+            hysteresis: 1.0,
+            current: None,
+            selected: false,
+        }
+    }
+}
+
+impl LodComponent {
+    /// Adds a level that's used up to `max_distance` from the camera
+    pub fn add_level(&mut self, mesh: HMesh, max_distance: f32) {
+        let index = self.levels.partition_point(|(_, d)| *d <= max_distance);
+        self.levels.insert(index, (mesh, max_distance));
+        self.selected = false;
+    }
+
+    /// Replaces all levels with `(mesh, max_distance)` entries, in any order
+    pub fn set_levels(&mut self, levels: Vec<(HMesh, f32)>) {
+        self.levels = levels;
+        self.levels.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        self.selected = false;
+    }
+
+    /// The `(mesh, max_distance)` entries, from nearest to furthest
+    pub fn levels(&self) -> &[(HMesh, f32)] {
+        &self.levels
+    }
+
+    /// The index of the level in use, or `None` if the mesh is culled or no level was picked
+    /// yet
+    pub fn current_level(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Picks the level for a camera at `distance` and applies it to the [`MeshRenderer`].
+    /// This runs every frame on its own with the distance to the active camera.
+    pub fn select(&mut self, distance: f32) {
+        if self.levels.is_empty() {
+            return;
+        }
+
+        let level = self.level_for(distance);
+        if self.selected && level == self.current {
+            return;
+        }
+
+        self.current = level;
+        self.selected = true;
+
+        let Some(mut renderer) = self.parent.get_component::<MeshRenderer>() else {
+            return;
+        };
+        match level {
+            Some(index) => {
+                let mesh = self.levels[index].0;
+                if renderer.mesh() != mesh {
+                    renderer.set_mesh(mesh);
+                }
+                renderer.set_visible(true);
+            }
+            None => renderer.set_visible(false),
+        }
+    }
+
+    fn level_for(&self, distance: f32) -> Option<usize> {
+        let level_at = |distance: f32| {
+            let index = self.levels.partition_point(|(_, d)| *d < distance);
+            (index < self.levels.len()).then_some(index)
+        };
+        // `None` is the furthest level
+        let rank = |level: Option<usize>| level.unwrap_or(self.levels.len());
+
+        let raw = level_at(distance);
+        if !self.selected {
+            return raw;
+        }
+
+        let current = rank(self.current);
+        if rank(raw) > current {
+            let further = level_at(distance - self.hysteresis);
+            if rank(further) > current {
+                return further;
+            }
+        } else if rank(raw) < current {
+            let nearer = level_at(distance + self.hysteresis);
+            if rank(nearer) < current {
+                return nearer;
+            }
+        }
+        self.current
+    }
+}
+
+impl Component for LodComponent {
+    fn on_start(&mut self, _world: &mut World) {
+        if self.parent.get_component::<MeshRenderer>().is_none() {
+            warn!(
+                "[LOD] {} has no mesh renderer, its levels won't do anything",
+                self.parent.name
+            );
+        }
+    }
+
+    // after cameras moved
+    fn update_priority(&self) -> i32 {
+        priority::CAMERA + 1
+    }
+
+    fn late_update(&mut self, world: &mut World) {
+        let Some(camera) = world.active_camera().upgrade(world) else {
+            return;
+        };
+        let eye = camera.parent().transform.position();
+        let distance = (self.parent.transform.position() - eye).norm();
+        self.select(distance);
+    }
+}
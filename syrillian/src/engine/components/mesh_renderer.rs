@@ -1,5 +1,5 @@
-use crate::assets::HMaterial;
-use crate::components::{Component, NewComponent, SkeletalComponent};
+use crate::assets::{HMaterial, Mesh, RefMut};
+use crate::components::{Collider3D, Component, NewComponent, SkeletalComponent};
 use crate::core::{Bone, GameObjectId, Vertex3D};
 use crate::engine::assets::HMesh;
 use crate::engine::rendering::CPUDrawCtx;
@@ -7,6 +7,7 @@ use crate::rendering::proxies::SceneProxy;
 use crate::rendering::proxies::mesh_proxy::MeshSceneProxy;
 use crate::{MAX_BONES, World, proxy_data_mut};
 use nalgebra::{Matrix4, Vector3};
+use std::ops::{Deref, DerefMut};
 use tracing::warn;
 
 #[derive(Debug, Default, Clone)]
@@ -58,13 +59,34 @@ pub struct DebugVertexNormal {
     normal: Vector3<f32>,
 }
 
+/// Mutable view into the vertices of a [`MeshRenderer`]s mesh.
+///
+/// The mesh asset stays locked while this is alive, so don't hold onto it.
+pub struct MeshVerticesMut<'a>(RefMut<'a, Mesh>);
+
+impl Deref for MeshVerticesMut<'_> {
+    type Target = [Vertex3D];
+
+    fn deref(&self) -> &Self::Target {
+        self.0.vertices()
+    }
+}
+
+impl DerefMut for MeshVerticesMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0.vertices_mut()
+    }
+}
+
 #[derive(Debug)]
 pub struct MeshRenderer {
     parent: GameObjectId,
     mesh: HMesh,
     materials: Vec<HMaterial>,
+    visible: bool,
     dirty_mesh: bool,
     dirty_materials: bool,
+    dirty_visibility: bool,
 }
 
 impl NewComponent for MeshRenderer {
@@ -74,8 +96,10 @@ impl NewComponent for MeshRenderer {
             parent,
             mesh: HMesh::UNIT_CUBE,
             materials: vec![],
+            visible: true,
             dirty_mesh: false,
             dirty_materials: false,
+            dirty_visibility: false,
         }
     }
 }
@@ -113,6 +137,15 @@ impl Component for MeshRenderer {
             });
         }
 
+        if self.dirty_visibility {
+            if self.visible {
+                ctx.enable_proxy();
+            } else {
+                ctx.disable_proxy();
+            }
+            self.dirty_visibility = false;
+        }
+
         if !self.dirty_mesh && !self.dirty_materials {
             return;
         }
@@ -175,6 +208,49 @@ impl MeshRenderer {
     pub fn mesh(&self) -> HMesh {
         self.mesh
     }
+
+    /// Stops drawing the mesh without removing the renderer, for example to cull it
+    pub fn set_visible(&mut self, visible: bool) {
+        if self.visible != visible {
+            self.visible = visible;
+            self.dirty_visibility = true;
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Mutable access to the vertices of the rendered mesh, for deforming it at runtime.
+    ///
+    /// This edits the mesh asset itself, so every renderer using the same handle will change.
+    /// Call [`MeshRenderer::mark_dirty`] after you're done editing.
+    pub fn vertices_mut(&mut self) -> Option<MeshVerticesMut<'_>> {
+        let world = self.parent.world();
+        world
+            .assets
+            .meshes
+            .try_get_mut(self.mesh)
+            .map(MeshVerticesMut)
+    }
+
+    /// Signals that the vertices of the mesh were changed, which updates the bounds and
+    /// re-uploads the vertex buffer next frame. The GPU buffers are only reallocated if the
+    /// vertex or index count changed.
+    ///
+    /// If `rebuild_collider` is set, an attached [`Collider3D`] will also rebuild its shape
+    /// from the new vertices.
+    pub fn mark_dirty(&mut self, rebuild_collider: bool) {
+        let world = self.parent.world();
+        if let Some(mut mesh) = world.assets.meshes.try_get_mut(self.mesh) {
+            mesh.recalculate_bounds();
+        }
+        self.dirty_mesh = true;
+
+        if rebuild_collider && let Some(mut collider) = self.parent.get_component::<Collider3D>() {
+            collider.rebuild_shape();
+        }
+    }
 }
 
 impl From<&Vertex3D> for DebugVertexNormal {
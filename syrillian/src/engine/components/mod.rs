@@ -41,15 +41,21 @@ pub mod audio;
 pub mod button;
 pub mod camera;
 pub mod collider;
+pub mod follow_camera;
 pub mod fp_camera;
 pub mod fp_movement;
 pub mod freecam;
 pub mod gravity;
+pub mod gravity_zone;
 pub mod image;
+pub mod joint_chain;
 pub mod joints;
 pub mod light;
+pub mod lod;
 pub mod mesh_renderer;
 pub mod panel;
+pub mod ragdoll;
+pub mod reflect;
 pub mod rigid_body;
 pub mod rotate;
 pub mod skeletal;
@@ -62,18 +68,24 @@ pub mod camera_debug;
 pub use animation::AnimationComponent;
 pub use button::Button;
 pub use camera::CameraComponent;
-pub use collider::Collider3D;
+pub use collider::{Collider3D, MassMode};
+pub use follow_camera::FollowCameraComponent;
 pub use fp_camera::FirstPersonCameraController;
 pub use fp_movement::FirstPersonMovementController;
 pub use freecam::FreecamController;
 pub use gravity::GravityComponent;
+pub use gravity_zone::GravityZoneComponent;
 pub use image::Image;
+pub use joint_chain::{ChainJointKind, JointChain};
 pub use joints::{
     FixedJoint, PrismaticJoint, RevoluteJoint, RopeJoint, SphericalJoint, SpringJoint,
 };
 pub use light::{PointLightComponent, SpotLightComponent, Sun, SunLightComponent};
+pub use lod::LodComponent;
 pub use mesh_renderer::MeshRenderer;
 pub use panel::Panel;
+pub use ragdoll::{Ragdoll, RagdollComponent, RagdollConfig};
+pub use reflect::{Field, FieldValue, Reflect, ReflectError};
 pub use rigid_body::RigidBodyComponent;
 pub use rotate::RotateComponent;
 pub use skeletal::SkeletalComponent;
@@ -377,22 +389,27 @@ impl TypedComponentId {
 ///```
 #[allow(unused)]
 pub trait Component: Any {
-    // Gets called when the game object is created directly after new
+    /// Gets called when the game object is created directly after new
     fn init(&mut self, world: &mut World) {}
 
-    // Gets called when the component should update anything state-related
+    /// Gets called once before the first update or fixed update of the component. Unlike init,
+    /// this runs after the object is fully spawned with all its components, so siblings can be
+    /// looked up and physics handles created here.
+    fn on_start(&mut self, world: &mut World) {}
+
+    /// Gets called when the component should update anything state-related
     fn update(&mut self, world: &mut World) {}
 
-    // Gets called when the component should update any state that's necessary for physics
+    /// Gets called when the component should update any state that's necessary for physics
     fn late_update(&mut self, world: &mut World) {}
 
-    // Gets called before physics are evolved
+    /// Gets called before physics are evolved
     fn pre_fixed_update(&mut self, world: &mut World) {}
 
-    // Gets called after physics have evolved
+    /// Gets called after physics have evolved
     fn fixed_update(&mut self, world: &mut World) {}
 
-    // Gets called after all other updates are done
+    /// Gets called after all other updates are done
     fn post_update(&mut self, world: &mut World) {}
 
     fn create_render_proxy(&mut self, world: &World) -> Option<Box<dyn SceneProxy>> {
@@ -409,8 +426,39 @@ pub trait Component: Any {
 
     fn on_gui(&mut self, world: &mut World, ctx: UiContext) {}
 
-    // Gets called when the component is about to be deleted
+    /// Gets called when the component is about to be deleted
     fn delete(&mut self, world: &mut World) {}
+
+    /// Gets called when the object is deactivated or activated again with
+    /// [`World::set_active`], and when the component is added to an inactive object.
+    ///
+    /// Updates are skipped for inactive objects on their own. This is for pausing what keeps
+    /// running outside of them, like a physics body.
+    fn on_active_changed(&mut self, world: &mut World, active: bool) {}
+
+    /// Where this component runs in each update pass, lower runs first. Components with the
+    /// same priority run in the order they were added. Defaults to [`priority::DEFAULT`].
+    ///
+    /// The priority is read once when the component is added.
+    fn update_priority(&self) -> i32 {
+        priority::DEFAULT
+    }
+
+    /// Exposes the editable fields of this component to editors and inspectors.
+    /// See [`Reflect`](reflect::Reflect).
+    fn reflect(&mut self) -> Option<&mut dyn Reflect> {
+        None
+    }
+}
+
+/// Common values for [`Component::update_priority`]
+pub mod priority {
+    /// Reading input, before anything acts on it
+    pub const INPUT: i32 = -100;
+    /// Gameplay and movement
+    pub const DEFAULT: i32 = 0;
+    /// Cameras, after whatever they follow has moved
+    pub const CAMERA: i32 = 100;
 }
 
 /// Either you'll have to implement this, or Default
@@ -0,0 +1,227 @@
+use crate::World;
+use crate::components::{
+    CRef, Collider3D, Component, NewComponent, RigidBodyComponent, SkeletalComponent,
+    SphericalJoint,
+};
+use crate::core::GameObjectId;
+use crate::utils::ExtraMatrixMath;
+use nalgebra::{Isometry3, Matrix4, Point3, Translation3, Unit, UnitQuaternion, Vector3};
+use rapier3d::prelude::SharedShape;
+use std::f32::consts::PI;
+
+/// Shape and joint settings used by [`Ragdoll::from_skeleton`]
+#[derive(Debug, Clone)]
+pub struct RagdollConfig {
+    /// Radius of the capsule around each bone
+    pub radius: f32,
+    /// Length of bones without children, which have no tail to measure against
+    pub leaf_length: f32,
+    /// Max swing of a bone relative to its parent, in radians
+    pub swing_limit: f32,
+    /// Max twist of a bone relative to its parent, in radians
+    pub twist_limit: f32,
+}
+
+impl Default for RagdollConfig {
+    fn default() -> Self {
+        Self {
+            radius: 0.05,
+            leaf_length: 0.1,
+            swing_limit: 45f32.to_radians(),
+            twist_limit: 30f32.to_radians(),
+        }
+    }
+}
+
+struct RagdollBone {
+    body: GameObjectId,
+    /// Pose of the bone relative to its body
+    offset: Isometry3<f32>,
+    parent: Option<usize>,
+}
+
+/// Drives the bones of a [`SkeletalComponent`] from the rigid bodies of a ragdoll.
+///
+/// This is added by [`Ragdoll::from_skeleton`]. The pose is written in `late_update`, so it
+/// overrides whatever an [`AnimationComponent`](crate::components::AnimationComponent) played.
+/// Deleting this component also deletes the ragdoll bodies.
+pub struct RagdollComponent {
+    parent: GameObjectId,
+    bones: Vec<RagdollBone>,
+}
+
+impl NewComponent for RagdollComponent {
+    fn new(parent: GameObjectId) -> Self {
+        Self {
+            parent,
+            bones: Vec::new(),
+        }
+    }
+}
+
+impl Component for RagdollComponent {
+    fn late_update(&mut self, _world: &mut World) {
+        let Some(mut skeleton) = self.parent.get_component::<SkeletalComponent>() else {
+            return;
+        };
+        if skeleton.bone_count() != self.bones.len() {
+            return;
+        }
+
+        let model_inv = self
+            .parent
+            .transform
+            .global_transform_matrix()
+            .to_homogeneous()
+            .try_inverse()
+            .unwrap_or_else(Matrix4::identity);
+
+        let model_space: Vec<Matrix4<f32>> = self
+            .bones
+            .iter()
+            .zip(skeleton.global_pose())
+            .map(|(bone, current)| {
+                if !bone.body.exists() {
+                    return *current;
+                }
+                let transform = &bone.body.transform;
+                let body = Isometry3::from_parts(transform.position().into(), transform.rotation());
+                model_inv * (body * bone.offset).to_homogeneous()
+            })
+            .collect();
+
+        for (i, bone) in self.bones.iter().enumerate() {
+            let local = match bone.parent {
+                Some(p) => {
+                    model_space[p]
+                        .try_inverse()
+                        .unwrap_or_else(Matrix4::identity)
+                        * model_space[i]
+                }
+                None => model_space[i],
+            };
+            let (t, r, _) = local.decompose();
+            skeleton.set_local_transform(i, Translation3::from(t));
+            skeleton.set_local_rotation(i, r.to_rotation_matrix());
+        }
+    }
+
+    fn delete(&mut self, world: &mut World) {
+        for bone in self.bones.drain(..) {
+            if bone.body.exists() {
+                world.delete_object(bone.body);
+            }
+        }
+    }
+}
+
+impl RagdollComponent {
+    /// The object holding the rigid body of each bone, index-aligned with the skeleton bones
+    pub fn bodies(&self) -> impl Iterator<Item = GameObjectId> + '_ {
+        self.bones.iter().map(|b| b.body)
+    }
+
+    pub fn body_of(&self, bone: usize) -> Option<GameObjectId> {
+        self.bones.get(bone).map(|b| b.body)
+    }
+}
+
+/// Builds physics ragdolls from skinned meshes
+pub struct Ragdoll;
+
+impl Ragdoll {
+    /// Creates a rigid body with a capsule collider for every bone of the skeleton in its
+    /// current pose, and connects each bone to its parent with a cone-limited
+    /// [`SphericalJoint`].
+    ///
+    /// Each capsule reaches from the bone to its children. A [`RagdollComponent`] is added to
+    /// the skeleton's object, so the mesh follows the bodies from then on. Returns the body of
+    /// the first root bone, or `None` if the skeleton has no bones.
+    ///
+    /// Bone scale is not simulated; bodies only carry position and rotation.
+    pub fn from_skeleton(
+        skeleton: &mut CRef<SkeletalComponent>,
+        config: &RagdollConfig,
+    ) -> Option<GameObjectId> {
+        let mut skinned = skeleton.parent();
+        let world = skinned.world();
+
+        skeleton.update_palette();
+        let bones = skeleton.bones().clone();
+        let globals = skeleton.global_pose();
+        if bones.is_empty() || globals.len() != bones.len() {
+            return None;
+        }
+
+        let model = skinned.transform.global_transform_matrix().to_homogeneous();
+        let bone_world: Vec<Isometry3<f32>> = globals
+            .iter()
+            .map(|g| {
+                let (t, r, _) = (model * g).decompose();
+                Isometry3::from_parts(t.into(), r)
+            })
+            .collect();
+
+        let mut bodies = Vec::with_capacity(bones.len());
+        for (i, pose) in bone_world.iter().enumerate() {
+            let head = pose.translation.vector;
+            let children = &bones.children[i];
+            let tail = (!children.is_empty()).then(|| {
+                children
+                    .iter()
+                    .map(|&c| bone_world[c].translation.vector)
+                    .sum::<Vector3<f32>>()
+                    / children.len() as f32
+            });
+
+            let (dir, length) = match tail.and_then(|t| Unit::try_new_and_get(t - head, 1e-4)) {
+                Some((dir, length)) => (dir, length.max(config.leaf_length)),
+                None => (pose.rotation * Vector3::y_axis(), config.leaf_length),
+            };
+            let rotation = UnitQuaternion::rotation_between_axis(&Vector3::y_axis(), &dir)
+                .unwrap_or_else(|| UnitQuaternion::from_axis_angle(&Vector3::x_axis(), PI));
+
+            let mut body = world.new_object(format!("{} Ragdoll", bones.names[i]));
+            body.transform.set_position_vec(head);
+
+            let mut collider = body.add_component::<Collider3D>();
+            let half_height = (length * 0.5 - config.radius).max(0.0);
+            collider.set_shape(SharedShape::capsule_y(half_height, config.radius));
+            collider.set_local_transform(Isometry3::from_parts(
+                (dir.into_inner() * length * 0.5).into(),
+                rotation,
+            ));
+            body.add_component::<RigidBodyComponent>();
+
+            world.add_child(body);
+            bodies.push(body);
+        }
+
+        for (i, parent) in bones.parents.iter().enumerate() {
+            let Some(p) = *parent else {
+                continue;
+            };
+
+            let pivot = bone_world[i].translation.vector - bone_world[p].translation.vector;
+            let mut joint = bodies[i].add_component::<SphericalJoint>();
+            joint.set_contacts_enabled(false);
+            joint.set_cone_limits(config.swing_limit, config.twist_limit);
+            joint.set_anchor2(Point3::from(pivot));
+            joint.connect_to(bodies[p]);
+        }
+
+        let mut ragdoll = skinned.add_component::<RagdollComponent>();
+        ragdoll.bones = bodies
+            .iter()
+            .zip(&bone_world)
+            .zip(&bones.parents)
+            .map(|((&body, pose), &parent)| RagdollBone {
+                body,
+                offset: Translation3::from(-pose.translation.vector) * pose,
+                parent,
+            })
+            .collect();
+
+        bones.roots.first().map(|&root| bodies[root])
+    }
+}
@@ -0,0 +1,177 @@
+//! Generic access to the editable fields of components, for inspectors and editor tooling.
+//!
+//! A component opts in by implementing [`Reflect`] and returning itself from
+//! [`Component::reflect`](crate::components::Component::reflect):
+//! ```rust
+//! use syrillian::components::{Component, Field, FieldValue, Reflect, ReflectError};
+//!
+//! #[derive(Default)]
+//! struct Health {
+//!     hit_points: f32,
+//!     invincible: bool,
+//! }
+//!
+//! impl Component for Health {
+//!     fn reflect(&mut self) -> Option<&mut dyn Reflect> {
+//!         Some(self)
+//!     }
+//! }
+//!
+//! impl Reflect for Health {
+//!     fn type_name(&self) -> &'static str {
+//!         "Health"
+//!     }
+//!
+//!     fn fields(&self) -> Vec<Field> {
+//!         vec![
+//!             Field::new("hit_points", self.hit_points),
+//!             Field::new("invincible", self.invincible),
+//!         ]
+//!     }
+//!
+//!     fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), ReflectError> {
+//!         match name {
+//!             "hit_points" => self.hit_points = value.as_f32(name)?,
+//!             "invincible" => self.invincible = value.as_bool(name)?,
+//!             _ => return Err(ReflectError::unknown(name)),
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut health = Health::default();
+//! health.set_field("hit_points", 100.0.into()).unwrap();
+//! assert_eq!(health.field("hit_points"), Some(FieldValue::F32(100.0)));
+//! ```
+
+use nalgebra::Vector3;
+use snafu::Snafu;
+
+/// The value of a reflected field
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FieldValue {
+    F32(f32),
+    Vec3(Vector3<f32>),
+    Bool(bool),
+}
+
+impl FieldValue {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            FieldValue::F32(_) => "f32",
+            FieldValue::Vec3(_) => "Vec3",
+            FieldValue::Bool(_) => "bool",
+        }
+    }
+
+    /// Returns the number, or a [`ReflectError::WrongType`] for the field `name`
+    pub fn as_f32(&self, name: &str) -> Result<f32, ReflectError> {
+        match *self {
+            FieldValue::F32(v) => Ok(v),
+            _ => Err(self.wrong_type(name, "f32")),
+        }
+    }
+
+    /// Returns the vector, or a [`ReflectError::WrongType`] for the field `name`
+    pub fn as_vec3(&self, name: &str) -> Result<Vector3<f32>, ReflectError> {
+        match *self {
+            FieldValue::Vec3(v) => Ok(v),
+            _ => Err(self.wrong_type(name, "Vec3")),
+        }
+    }
+
+    /// Returns the flag, or a [`ReflectError::WrongType`] for the field `name`
+    pub fn as_bool(&self, name: &str) -> Result<bool, ReflectError> {
+        match *self {
+            FieldValue::Bool(v) => Ok(v),
+            _ => Err(self.wrong_type(name, "bool")),
+        }
+    }
+
+    fn wrong_type(&self, name: &str, expected: &'static str) -> ReflectError {
+        ReflectError::WrongType {
+            name: name.to_string(),
+            expected,
+            found: self.kind(),
+        }
+    }
+}
+
+impl From<f32> for FieldValue {
+    fn from(value: f32) -> Self {
+        FieldValue::F32(value)
+    }
+}
+
+impl From<Vector3<f32>> for FieldValue {
+    fn from(value: Vector3<f32>) -> Self {
+        FieldValue::Vec3(value)
+    }
+}
+
+impl From<bool> for FieldValue {
+    fn from(value: bool) -> Self {
+        FieldValue::Bool(value)
+    }
+}
+
+/// A named field and its current value
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: &'static str,
+    pub value: FieldValue,
+}
+
+impl Field {
+    pub fn new(name: &'static str, value: impl Into<FieldValue>) -> Self {
+        Self {
+            name,
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Debug, Snafu)]
+#[snafu(context(suffix(Err)))]
+pub enum ReflectError {
+    #[snafu(display("No field named {name:?}"))]
+    UnknownField { name: String },
+
+    #[snafu(display("Field {name:?} is a {expected}, but got a {found}"))]
+    WrongType {
+        name: String,
+        expected: &'static str,
+        found: &'static str,
+    },
+}
+
+impl ReflectError {
+    pub fn unknown(name: &str) -> Self {
+        ReflectError::UnknownField {
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Lists and changes the editable fields of a component by name.
+///
+/// Setting a field goes through the same setters as code would, so changes like a new joint
+/// anchor are applied to the physics world right away.
+pub trait Reflect {
+    /// Display name of the type, for example in an inspector header
+    fn type_name(&self) -> &'static str;
+
+    /// All editable fields with their current values, in display order
+    fn fields(&self) -> Vec<Field>;
+
+    /// Changes the field `name`. Fails if there's no such field, or the value has a
+    /// different type.
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), ReflectError>;
+
+    fn field(&self, name: &str) -> Option<FieldValue> {
+        self.fields()
+            .into_iter()
+            .find(|f| f.name == name)
+            .map(|f| f.value)
+    }
+}
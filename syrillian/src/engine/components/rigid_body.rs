@@ -1,8 +1,10 @@
 use crate::World;
-use crate::components::{Component, NewComponent};
+use crate::components::{Component, Field, FieldValue, NewComponent, Reflect, ReflectError};
 use crate::core::GameObjectId;
+use crate::physics::BodyState;
+use crate::physics::validation::validated;
 use crate::utils::math::QuaternionEuler;
-use nalgebra::{Isometry3, Translation3};
+use nalgebra::{Isometry3, Point3, Translation3, Vector3};
 use rapier3d::prelude::*;
 use syrillian_utils::debug_panic;
 
@@ -10,8 +12,13 @@ pub struct RigidBodyComponent {
     parent: GameObjectId,
     pub body_handle: RigidBodyHandle,
     kinematic: bool,
+    interpolated: bool,
+    max_linvel: f32,
+    max_angvel: f32,
     prev_iso: Isometry3<f32>,
     curr_iso: Isometry3<f32>,
+    /// Whether the body was enabled before its object was deactivated
+    enabled_while_active: bool,
 }
 
 impl NewComponent for RigidBodyComponent {
@@ -24,14 +31,20 @@ impl NewComponent for RigidBodyComponent {
             .rotation(initial_rotation)
             .build();
 
+        let pose = *rigid_body.position();
         let body_handle = World::instance().physics.rigid_body_set.insert(rigid_body);
 
         RigidBodyComponent {
             parent,
             body_handle,
             kinematic: false,
-            prev_iso: Isometry3::default(),
-            curr_iso: Isometry3::default(),
+            interpolated: true,
+            max_linvel: f32::INFINITY,
+            max_angvel: f32::INFINITY,
+            // interpolating from the origin would flash the object there on its first frames
+            prev_iso: pose,
+            curr_iso: pose,
+            enabled_while_active: true,
         }
     }
 }
@@ -61,6 +74,11 @@ impl Component for RigidBodyComponent {
             self.prev_iso = self.curr_iso;
             self.curr_iso =
                 Isometry3::from_parts(Translation3::from(*rb.translation()), *rb.rotation());
+            if !self.interpolated {
+                self.prev_iso = self.curr_iso;
+            }
+            rb.reset_torques(false);
+            clamp_velocities(rb, self.max_linvel, self.max_angvel);
             if rb.is_dynamic() {
                 self.parent.transform.set_position_vec(*rb.translation());
                 if rb.is_rotation_locked().iter().all(|l| !l) {
@@ -70,6 +88,28 @@ impl Component for RigidBodyComponent {
         }
     }
 
+    fn on_active_changed(&mut self, world: &mut World, active: bool) {
+        let Some(rb) = world.physics.rigid_body_set.get_mut(self.body_handle) else {
+            return;
+        };
+        if !active {
+            self.enabled_while_active = rb.is_enabled();
+            rb.set_enabled(false);
+            return;
+        }
+
+        // pick up where the object was moved to in the meantime, without interpolating there
+        let iso = Isometry3::from_parts(
+            Translation3::from(self.parent.transform.position()),
+            self.parent.transform.rotation(),
+        );
+        rb.set_position(iso, false);
+        rb.set_enabled(self.enabled_while_active);
+        rb.wake_up(true);
+        self.prev_iso = iso;
+        self.curr_iso = iso;
+    }
+
     fn delete(&mut self, world: &mut World) {
         world.physics.rigid_body_set.remove(
             self.body_handle,
@@ -80,29 +120,80 @@ impl Component for RigidBodyComponent {
             false,
         );
     }
+
+    fn reflect(&mut self) -> Option<&mut dyn Reflect> {
+        Some(self)
+    }
+}
+
+impl Reflect for RigidBodyComponent {
+    fn type_name(&self) -> &'static str {
+        "RigidBody"
+    }
+
+    fn fields(&self) -> Vec<Field> {
+        let mut fields = vec![
+            Field::new("kinematic", self.kinematic),
+            Field::new("interpolated", self.interpolated),
+            Field::new("max_linvel", self.max_linvel),
+            Field::new("max_angvel", self.max_angvel),
+        ];
+        if let Some(body) = self.body() {
+            fields.push(Field::new("linear_damping", body.linear_damping()));
+            fields.push(Field::new("angular_damping", body.angular_damping()));
+            fields.push(Field::new("gravity_scale", body.gravity_scale()));
+            fields.push(Field::new("ccd", body.is_ccd_enabled()));
+        }
+        fields
+    }
+
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), ReflectError> {
+        match name {
+            "kinematic" => self.set_kinematic(value.as_bool(name)?),
+            "interpolated" => self.set_interpolated(value.as_bool(name)?),
+            "max_linvel" => self.set_max_linvel(value.as_f32(name)?),
+            "max_angvel" => self.set_max_angvel(value.as_f32(name)?),
+            "linear_damping" | "angular_damping" | "gravity_scale" | "ccd" => {
+                let Some(body) = self.body_mut() else {
+                    return Err(ReflectError::unknown(name));
+                };
+                match name {
+                    "linear_damping" => body.set_linear_damping(value.as_f32(name)?),
+                    "angular_damping" => body.set_angular_damping(value.as_f32(name)?),
+                    "gravity_scale" => body.set_gravity_scale(value.as_f32(name)?, true),
+                    _ => body.enable_ccd(value.as_bool(name)?),
+                }
+            }
+            _ => return Err(ReflectError::unknown(name)),
+        }
+        Ok(())
+    }
 }
 
 impl RigidBodyComponent {
     pub fn body(&self) -> Option<&RigidBody> {
-        World::instance()
+        let body = World::instance()
             .physics
             .rigid_body_set
-            .get(self.body_handle)
+            .get(self.body_handle);
+        validated(body, self.body_handle, self.parent, "RigidBodyComponent")
     }
 
     pub fn body_mut(&mut self) -> Option<&mut RigidBody> {
-        World::instance()
+        let body = World::instance()
             .physics
             .rigid_body_set
-            .get_mut(self.body_handle)
+            .get_mut(self.body_handle);
+        validated(body, self.body_handle, self.parent, "RigidBodyComponent")
     }
 
     pub fn set_kinematic(&mut self, kinematic: bool) {
-        let rb = self.body_mut().expect("Rigid body de-synced");
-        if kinematic {
-            rb.set_body_type(RigidBodyType::KinematicPositionBased, false);
-        } else {
-            rb.set_body_type(RigidBodyType::Dynamic, false);
+        if let Some(rb) = self.body_mut() {
+            if kinematic {
+                rb.set_body_type(RigidBodyType::KinematicPositionBased, false);
+            } else {
+                rb.set_body_type(RigidBodyType::Dynamic, false);
+            }
         }
         self.kinematic = kinematic;
     }
@@ -111,7 +202,116 @@ impl RigidBodyComponent {
         self.kinematic
     }
 
+    /// Applies a continuous torque in world space during the next physics step, and wakes
+    /// the body up.
+    ///
+    /// Torques add up until the step ran, and are cleared afterward. To keep something
+    /// spinning, like a turret, apply the torque again every tick from `pre_fixed_update`.
+    pub fn apply_torque(&mut self, torque: Vector3<f32>) {
+        if let Some(rb) = self.body_mut() {
+            rb.add_torque(torque, true);
+        }
+    }
+
+    /// Applies an instant impulse in world space at `point`, also in world space, and wakes
+    /// the body up.
+    ///
+    /// Unlike an impulse at the center of mass, an off-center hit also spins the body, by the
+    /// torque of the impulse around the lever arm from the center of mass to `point`. This is
+    /// what bullets or explosions hitting the edge of a crate should use.
+    pub fn apply_impulse_at_point(&mut self, impulse: Vector3<f32>, point: Point3<f32>) {
+        if let Some(rb) = self.body_mut() {
+            rb.apply_impulse_at_point(impulse, point, true);
+        }
+    }
+
+    /// Limits the speed of this body in m/s. Unlimited by default.
+    ///
+    /// The velocity is clamped after each physics tick, once integration and the solver ran.
+    /// It's not a constraint: the step that received a large impulse still moves the body at
+    /// full speed, and solver impulses aren't changed. It just keeps a runaway velocity from
+    /// carrying over into the following steps, where it would tunnel or blow up the scene.
+    pub fn set_max_linvel(&mut self, max: f32) {
+        self.max_linvel = max.max(0.0);
+    }
+
+    pub fn max_linvel(&self) -> f32 {
+        self.max_linvel
+    }
+
+    /// Limits the angular speed of this body in rad/s. Unlimited by default. Like
+    /// [`RigidBodyComponent::set_max_linvel`], this is applied after each physics tick.
+    pub fn set_max_angvel(&mut self, max: f32) {
+        self.max_angvel = max.max(0.0);
+    }
+
+    pub fn max_angvel(&self) -> f32 {
+        self.max_angvel
+    }
+
+    /// Enables or disables render interpolation between the last two physics steps.
+    ///
+    /// Interpolation smooths out dynamic bodies, but lags one step behind objects that are
+    /// moved directly every frame, like a cursor or a held tool. With interpolation disabled,
+    /// [`RigidBodyComponent::render_isometry`] follows the object's [`Transform`](crate::core::Transform)
+    /// exactly, so setting its position or rotation shows up in the same frame.
+    pub fn set_interpolated(&mut self, interpolated: bool) {
+        self.interpolated = interpolated;
+        if !interpolated {
+            self.prev_iso = self.curr_iso;
+        }
+    }
+
+    /// Puts the body at `pose` right away, without interpolating from where it was
+    pub(crate) fn teleport(&mut self, pose: Isometry3<f32>) {
+        if let Some(rb) = self.body_mut() {
+            rb.set_position(pose, true);
+        }
+        self.prev_iso = pose;
+        self.curr_iso = pose;
+    }
+
+    /// Captures the pose and velocities of this body, to go back to later with
+    /// [`RigidBodyComponent::restore_state`]. Returns `None` if the body is gone.
+    pub fn capture_state(&self) -> Option<BodyState> {
+        self.body().map(|rb| BodyState {
+            position: *rb.translation(),
+            rotation: *rb.rotation(),
+            linvel: *rb.linvel(),
+            angvel: *rb.angvel(),
+        })
+    }
+
+    /// Moves this body back to a captured state, for example to undo an edit or reset one
+    /// object to a checkpoint. The rest of the world is left alone.
+    ///
+    /// The body is teleported without interpolating, and woken up. Forces and torques added
+    /// since the capture are dropped, so it continues exactly from the captured velocities.
+    pub fn restore_state(&mut self, state: &BodyState) {
+        let pose = Isometry3::from_parts(state.position.into(), state.rotation);
+        self.teleport(pose);
+        if let Some(rb) = self.body_mut() {
+            rb.set_linvel(state.linvel, true);
+            rb.set_angvel(state.angvel, true);
+            rb.reset_forces(true);
+            rb.reset_torques(true);
+        }
+        self.parent.transform.set_position_vec(state.position);
+        self.parent.transform.set_rotation(state.rotation);
+    }
+
+    pub fn is_interpolated(&self) -> bool {
+        self.interpolated
+    }
+
+    /// The pose this body should be rendered at, `alpha` of the way from the previous to the
+    /// current physics step. See [`RigidBodyComponent::set_interpolated`].
     pub fn render_isometry(&self, alpha: f32) -> Isometry3<f32> {
+        if !self.interpolated {
+            let transform = &self.parent.transform;
+            return Isometry3::from_parts(transform.position().into(), transform.rotation());
+        }
+
         let p0 = self.prev_iso.translation.vector;
         let p1 = self.curr_iso.translation.vector;
         let p = p0 + (p1 - p0) * alpha;
@@ -119,3 +319,14 @@ impl RigidBodyComponent {
         Isometry3::from_parts(Translation3::from(p), r)
     }
 }
+
+fn clamp_velocities(rb: &mut RigidBody, max_linvel: f32, max_angvel: f32) {
+    let linvel = *rb.linvel();
+    if linvel.norm() > max_linvel {
+        rb.set_linvel(linvel.normalize() * max_linvel, false);
+    }
+    let angvel = *rb.angvel();
+    if angvel.norm() > max_angvel {
+        rb.set_angvel(angvel.normalize() * max_angvel, false);
+    }
+}
@@ -1,7 +1,7 @@
 use nalgebra::{UnitQuaternion, Vector3};
 
 use crate::World;
-use crate::components::{Component, NewComponent};
+use crate::components::{Component, Field, FieldValue, NewComponent, Reflect, ReflectError};
 use crate::core::GameObjectId;
 
 pub struct RotateComponent {
@@ -47,4 +47,30 @@ impl Component for RotateComponent {
         }
         self.iteration += delta_time;
     }
+
+    fn reflect(&mut self) -> Option<&mut dyn Reflect> {
+        Some(self)
+    }
+}
+
+impl Reflect for RotateComponent {
+    fn type_name(&self) -> &'static str {
+        "Rotate"
+    }
+
+    fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("rotate_speed", self.rotate_speed),
+            Field::new("scale_coefficient", self.scale_coefficient),
+        ]
+    }
+
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), ReflectError> {
+        match name {
+            "rotate_speed" => self.rotate_speed = value.as_f32(name)?,
+            "scale_coefficient" => self.scale_coefficient = value.as_f32(name)?,
+            _ => return Err(ReflectError::unknown(name)),
+        }
+        Ok(())
+    }
 }
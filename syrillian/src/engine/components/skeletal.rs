@@ -123,6 +123,11 @@ impl SkeletalComponent {
         self.dirty = true;
     }
 
+    /// Model-space transforms of all bones, as of the last [`SkeletalComponent::update_palette`]
+    pub fn global_pose(&self) -> &[Matrix4<f32>] {
+        &self.globals
+    }
+
     pub fn palette(&self) -> &[Matrix4<f32>] {
         &self.palette
     }
@@ -4,7 +4,7 @@ use slotmap::SlotMap;
 use slotmap::basic::Values;
 use std::any::{Any, TypeId};
 use std::borrow::Borrow;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 use tracing::trace;
 
@@ -79,7 +79,14 @@ pub struct ComponentStorage {
     inner: HashMap<TypeId, Box<dyn SlotMapUntyped<ComponentId>>>,
     len: usize,
     pub(crate) fresh: Vec<TypedComponentId>,
+    pub(crate) unstarted: Vec<TypedComponentId>,
     pub(crate) removed: Vec<TypedComponentId>,
+    /// The components that update, keyed by [`Component::update_priority`] and then by the
+    /// order they were added in. Components of inactive objects are taken out.
+    order: BTreeMap<(i32, u64), TypedComponentId>,
+    /// Where each component goes in the update order, parked or not
+    order_keys: HashMap<TypedComponentId, (i32, u64)>,
+    next_sequence: u64,
 }
 
 impl ComponentStorage {
@@ -160,6 +167,12 @@ impl ComponentStorage {
         })
     }
 
+    /// The ids of all components in the order they update, see
+    /// [`Component::update_priority`]
+    pub fn update_order(&self) -> impl Iterator<Item = TypedComponentId> + '_ {
+        self.order.values().copied()
+    }
+
     pub fn iter_refs(&self) -> impl Iterator<Item = CRef<dyn Component>> {
         self.inner.values().flat_map(|store| store.iter_refs())
     }
@@ -175,6 +188,7 @@ impl ComponentStorage {
     }
 
     pub(crate) fn add<C: Component>(&mut self, component: C, parent: GameObjectId) -> CRef<C> {
+        let priority = component.update_priority();
         let comp = Rc::new(component);
 
         let store = self._get_or_insert_mut();
@@ -188,9 +202,29 @@ impl ComponentStorage {
 
         self.len += 1;
         self.fresh.push(tid);
+        self.unstarted.push(tid);
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        self.order.insert((priority, sequence), tid);
+        self.order_keys.insert(tid, (priority, sequence));
         cref
     }
 
+    /// Takes a component out of the update order until it's unparked, see
+    /// [`World::set_active`](crate::World::set_active)
+    pub(crate) fn park(&mut self, ctid: TypedComponentId) {
+        if let Some(key) = self.order_keys.get(&ctid) {
+            self.order.remove(key);
+        }
+    }
+
+    /// Puts a parked component back where it was in the update order
+    pub(crate) fn unpark(&mut self, ctid: TypedComponentId) {
+        if let Some(key) = self.order_keys.get(&ctid) {
+            self.order.insert(*key, ctid);
+        }
+    }
+
     pub(crate) fn remove(&mut self, ctid: impl Borrow<TypedComponentId>) {
         trace!("Removed component");
 
@@ -207,6 +241,9 @@ impl ComponentStorage {
             "Component wasn't found despite still being owned by a game object."
         );
         self.removed.push(ctid);
+        if let Some(key) = self.order_keys.remove(&ctid) {
+            self.order.remove(&key);
+        }
 
         debug_assert_ne!(self.len, 0);
 
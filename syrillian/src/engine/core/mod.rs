@@ -7,13 +7,21 @@ pub mod bone;
 pub mod bounds;
 pub mod component_storage;
 pub mod object;
+pub mod object_builder;
 pub mod object_extensions;
+pub mod spatial_grid;
 pub mod transform;
+pub mod up_axis;
 pub mod vertex;
+pub mod world_event;
 
 pub use bone::*;
 pub use bounds::*;
 pub use object::*;
+pub use object_builder::*;
 pub use object_extensions::*;
+pub use spatial_grid::SpatialGrid;
 pub use transform::*;
+pub use up_axis::UpAxis;
 pub use vertex::*;
+pub use world_event::WorldEvent;
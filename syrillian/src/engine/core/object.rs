@@ -3,7 +3,7 @@ use crate::core::Transform;
 use crate::ensure_aligned;
 use crate::world::World;
 use itertools::Itertools;
-use nalgebra::{Matrix4, Translation3, Vector3};
+use nalgebra::{Matrix4, Point3, Translation3, Vector3};
 use slotmap::{Key, KeyData, new_key_type};
 use std::borrow::Borrow;
 use std::cell::Cell;
@@ -13,6 +13,7 @@ use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 use std::ptr::null_mut;
 use syrillian_utils::debug_panic;
+use uuid::Uuid;
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
 pub struct EventType(u32);
@@ -126,6 +127,14 @@ impl GameObjectId {
         self
     }
 
+    /// Turns this object to face another one, keeping its up axis toward world up. See
+    /// [`Transform::look_at`](crate::core::Transform::look_at).
+    pub fn look_at(&mut self, target: GameObjectId) {
+        let target = Point3::from(target.transform.position());
+        let up = World::instance().up_axis().up();
+        self.transform.look_at(target, up);
+    }
+
     /// Creates a strong reference to this object, keeping it alive until the reference is dropped.
     pub fn upgrade(&self) -> Option<GameObjectRef> {
         GameObjectRef::new(*self)
@@ -313,6 +322,8 @@ pub struct GameObject {
     pub(crate) event_mask: Cell<EventType>,
     /// Unique hash used for picking and lookup.
     pub(crate) hash: ObjectHash,
+    /// Persistent id that stays the same across save and load.
+    pub(crate) uuid: Uuid,
 }
 
 impl GameObject {
@@ -325,6 +336,15 @@ impl GameObject {
         self.hash
     }
 
+    /// A persistent id for this object, assigned on spawn.
+    ///
+    /// Unlike the [`GameObjectId`], which is only valid while the world is running, this id
+    /// is meant to be saved and later resolved again with [`World::find_by_uuid`]. Restore a
+    /// saved id with [`World::set_object_uuid`].
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
     pub fn event_mask(&self) -> EventType {
         self.event_mask.get()
     }
@@ -424,6 +444,10 @@ impl GameObject {
 
         let new_comp2 = new_comp.clone();
         self.components.push(new_comp.as_dyn());
+        if !world.is_active(self.id) {
+            world.components.park(new_comp.ctx.tid);
+            new_comp.as_dyn().on_active_changed(world, false);
+        }
         new_comp2
     }
 
@@ -503,6 +527,16 @@ impl GameObject {
         self.components.iter()
     }
 
+    /// Collects all [`Component`]s of type `C` attached to this game object, in the order
+    /// they were added.
+    ///
+    /// An object can hold any number of components of the same type, like two
+    /// [`FixedJoint`](crate::components::FixedJoint)s welding it to different objects.
+    /// [`GameObject::get_component`] only returns the first one of them.
+    pub fn get_components<C: Component + 'static>(&self) -> Vec<CRef<C>> {
+        self.iter_components().collect()
+    }
+
     /// Returns an iterator over all [`Component`] of type `C` attached to this game object.
     pub fn iter_components<C: Component + 'static>(&self) -> impl Iterator<Item = CRef<C>> {
         self.components.iter().filter_map(|c| c.clone().as_a())
@@ -522,17 +556,49 @@ impl GameObject {
         None
     }
 
-    /// Removes a [`Component`] by id from this game object and the world.
+    /// Removes the first [`Component`] of type `C` from this game object and the world,
+    /// running its [`Component::delete`] first. Returns whether such a component existed.
+    ///
+    /// This is safe to call while components are updating, even from the removed component
+    /// itself. The component is detached from the object right away, but deleted only once
+    /// the current update pass is done.
+    pub fn remove_component_of<C: Component + 'static>(&mut self) -> bool {
+        let Some(index) = self.components.iter().position(|c| c.as_a::<C>().is_some()) else {
+            return false;
+        };
+
+        let comp = self.components.remove(index);
+        self.world().destroy_component(comp);
+        true
+    }
+
+    /// Removes a [`Component`] by id from this game object and the world. Same as
+    /// [`GameObject::remove_component_of`], but for a specific instance.
     pub fn remove_component(&mut self, comp: impl Borrow<TypedComponentId>, world: &mut World) {
-        let comp = *comp.borrow();
-        let removed = self
+        if let Some(comp) = self.detach_component(*comp.borrow()) {
+            world.destroy_component(comp);
+        }
+    }
+
+    /// Same as [`GameObject::remove_component`], but without passing the world. Returns
+    /// whether the component was attached to this object.
+    pub fn remove_component_by_id(&mut self, comp: impl Borrow<TypedComponentId>) -> bool {
+        let Some(comp) = self.detach_component(*comp.borrow()) else {
+            return false;
+        };
+        self.world().destroy_component(comp);
+        true
+    }
+
+    fn detach_component(&mut self, comp: TypedComponentId) -> Option<CRef<dyn Component>> {
+        let mut removed: Vec<_> = self
             .components
             .extract_if(.., |c| c.ctx.tid == comp)
-            .count();
-        if removed > 1 {
+            .collect();
+        if removed.len() > 1 {
             debug_panic!("Removed more than one component by TID (which should be unique)");
         }
-        world.components.remove(comp);
+        removed.pop()
     }
 
     /// Returns an immutable reference to this game object's parent ID.
@@ -566,11 +632,13 @@ impl GameObject {
             return;
         }
 
+        let world = self.world();
+        world.announce_destroyed(self.id);
+
         for mut child in self.children.iter().copied() {
             child.delete();
         }
 
-        let world = self.world();
         for mut comp in self.components.drain(..) {
             comp.delete(world);
             world.components.remove(&comp);
@@ -578,6 +646,7 @@ impl GameObject {
 
         self.children.clear();
         self.unlink();
+        world.inactive_objects.remove(&self.id);
         world.schedule_object_removal(self.id);
     }
 
@@ -0,0 +1,115 @@
+use crate::World;
+use crate::components::NewComponent;
+use crate::core::GameObjectId;
+use nalgebra::{Isometry3, UnitQuaternion, Vector3};
+
+type Setup = Box<dyn FnOnce(GameObjectId)>;
+
+/// Describes a game object before it exists, so it can be spawned later, for example in bulk
+/// with [`World::spawn_batch`].
+///
+/// The transform is applied first, then the components are added in the order they were
+/// listed, so colliders and rigid bodies start out at the right pose and scale.
+/// ```rust
+/// use syrillian::World;
+/// use syrillian::components::{Collider3D, RigidBodyComponent};
+/// use syrillian::core::GameObjectBuilder;
+///
+/// let mut world = World::builder().build();
+/// let crates = world.spawn_batch(100, |i| {
+///     GameObjectBuilder::new(format!("Crate {i}"))
+///         .at(i as f32 * 2.0, 1.0, 0.0)
+///         .with::<Collider3D>()
+///         .with::<RigidBodyComponent>()
+/// });
+/// assert_eq!(crates.len(), 100);
+/// ```
+pub struct GameObjectBuilder {
+    name: String,
+    position: Vector3<f32>,
+    rotation: UnitQuaternion<f32>,
+    scale: Vector3<f32>,
+    setup: Vec<Setup>,
+}
+
+impl GameObjectBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            position: Vector3::zeros(),
+            rotation: UnitQuaternion::identity(),
+            scale: Vector3::new(1.0, 1.0, 1.0),
+            setup: Vec::new(),
+        }
+    }
+
+    pub fn at(self, x: f32, y: f32, z: f32) -> Self {
+        self.at_vec(Vector3::new(x, y, z))
+    }
+
+    pub fn at_vec(mut self, position: Vector3<f32>) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn rotation(mut self, rotation: UnitQuaternion<f32>) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets position and rotation at once, see [`World::spawn_at`]
+    pub fn pose(self, pose: Isometry3<f32>) -> Self {
+        self.at_vec(pose.translation.vector).rotation(pose.rotation)
+    }
+
+    pub fn scale(self, scale: f32) -> Self {
+        self.non_uniform_scale(scale, scale, scale)
+    }
+
+    pub fn non_uniform_scale(mut self, x: f32, y: f32, z: f32) -> Self {
+        self.scale = Vector3::new(x, y, z);
+        self
+    }
+
+    /// Adds a component with its default setup
+    pub fn with<C: NewComponent + 'static>(self) -> Self {
+        self.setup(|mut obj| {
+            obj.add_component::<C>();
+        })
+    }
+
+    /// Adds a component and configures it
+    pub fn with_then<C: NewComponent + 'static>(self, f: impl FnOnce(&mut C) + 'static) -> Self {
+        self.setup(move |mut obj| {
+            let mut comp = obj.add_component::<C>();
+            f(&mut comp);
+        })
+    }
+
+    /// Runs any other setup on the freshly spawned object, like adding children or properties
+    pub fn setup(mut self, f: impl FnOnce(GameObjectId) + 'static) -> Self {
+        self.setup.push(Box::new(f));
+        self
+    }
+
+    /// Spawns the object at the root of the world
+    pub fn build(self, world: &mut World) -> GameObjectId {
+        let obj = self.spawn_detached(world);
+        world.add_child(obj);
+        obj
+    }
+
+    /// Creates the object without adding it to the scene graph
+    pub(crate) fn spawn_detached(self, world: &mut World) -> GameObjectId {
+        let mut obj = world.new_object(self.name);
+        obj.transform.set_position_vec(self.position);
+        obj.transform.set_rotation(self.rotation);
+        obj.transform
+            .set_nonuniform_scale(self.scale.x, self.scale.y, self.scale.z);
+
+        for setup in self.setup {
+            setup(obj);
+        }
+        obj
+    }
+}
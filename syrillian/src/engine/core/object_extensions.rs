@@ -1,5 +1,5 @@
 use crate::components::joints::{
-    Fixed, JointComponent, JointTypeTrait, Prismatic, Revolute, Rope, Spring,
+    Fixed, JointComponent, JointTypeTrait, Prismatic, Revolute, Rope, Spherical, Spring,
 };
 use crate::components::light::{LightComponent, LightTypeTrait};
 use crate::components::{Collider3D, NewComponent, RigidBodyComponent, RotateComponent};
@@ -369,6 +369,26 @@ impl GOJointExt<'_, Prismatic> {
     }
 }
 
+impl GOJointExt<'_, Spherical> {
+    #[inline]
+    pub fn cone_limits(self, swing: f32, twist: f32) -> Self {
+        self.0.set_cone_limits(swing, twist);
+        self
+    }
+
+    #[inline]
+    pub fn cone_limits_deg(self, swing: f32, twist: f32) -> Self {
+        self.0.set_cone_limits_deg(swing, twist);
+        self
+    }
+
+    #[inline]
+    pub fn contacts_enabled(self, enabled: bool) -> Self {
+        self.0.set_contacts_enabled(enabled);
+        self
+    }
+}
+
 impl GOJointExt<'_, Rope> {
     #[inline]
     pub fn max_distance(self, distance: f32) -> Self {
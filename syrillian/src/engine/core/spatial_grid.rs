@@ -0,0 +1,265 @@
+//! Proximity queries for gameplay, without going through physics.
+//!
+//! Questions like "the nearest pickup within 5m" over hundreds of markers don't need colliders.
+//! The [`SpatialGrid`] of the [`World`](crate::World) buckets tagged objects into cubic cells
+//! by position, so a query only looks at the cells its sphere touches.
+//!
+//! ```rust
+//! use nalgebra::Vector3;
+//! use syrillian::World;
+//!
+//! let mut world = World::builder().build();
+//! let mut coin = world.new_object("Coin");
+//! coin.transform.set_position(3.0, 0.0, 0.0);
+//! world.spatial.insert(coin, "pickup");
+//!
+//! let player = Vector3::zeros();
+//! assert_eq!(world.spatial.nearest_tagged(player, 5.0, "pickup"), Some(coin));
+//! assert!(world.spatial.within(player, 2.0).is_empty());
+//! ```
+
+use crate::core::{GameObject, GameObjectId};
+use nalgebra::Vector3;
+use slotmap::SlotMap;
+use std::collections::HashMap;
+
+/// Edge length of the cells of a new grid
+pub const DEFAULT_CELL_SIZE: f32 = 10.0;
+
+type Cell = (i32, i32, i32);
+
+#[derive(Debug, Clone)]
+struct SpatialEntry {
+    tag: String,
+    position: Vector3<f32>,
+    cell: Cell,
+}
+
+/// Tagged objects, indexed by their global position.
+///
+/// Positions are taken when an object is inserted, and follow the object at the end of every
+/// frame in which its transform changed, see
+/// [`World::query_changed`](crate::World::query_changed). Deleted objects drop out on their own.
+/// Queries in the middle of a frame see objects where they were at its start.
+///
+/// Cells should be about as large as the typical query radius. Much smaller cells make queries
+/// visit many of them, much larger ones make them check many objects that are too far away.
+#[derive(Debug, Clone)]
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<Cell, Vec<GameObjectId>>,
+    entries: HashMap<GameObjectId, SpatialEntry>,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        SpatialGrid::new(DEFAULT_CELL_SIZE)
+    }
+}
+
+impl SpatialGrid {
+    pub fn new(cell_size: f32) -> Self {
+        SpatialGrid {
+            cell_size: cell_size.max(f32::EPSILON),
+            cells: HashMap::new(),
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Changes the edge length of the cells, and sorts all objects into the new ones
+    pub fn set_cell_size(&mut self, cell_size: f32) {
+        let entries = std::mem::take(&mut self.entries);
+        *self = SpatialGrid::new(cell_size);
+        for (object, entry) in entries {
+            self.place(object, entry.tag, entry.position);
+        }
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Indexes `object` at its current position, or changes its tag if it's indexed already
+    pub fn insert(&mut self, object: GameObjectId, tag: impl Into<String>) {
+        if !object.exists() {
+            return;
+        }
+        let position = object.transform.position();
+        self.remove(object);
+        self.place(object, tag.into(), position);
+    }
+
+    /// Stops indexing `object`. Returns whether it was indexed.
+    pub fn remove(&mut self, object: GameObjectId) -> bool {
+        let Some(entry) = self.entries.remove(&object) else {
+            return false;
+        };
+        self.unlink(object, entry.cell);
+        true
+    }
+
+    pub fn contains(&self, object: GameObjectId) -> bool {
+        self.entries.contains_key(&object)
+    }
+
+    pub fn tag(&self, object: GameObjectId) -> Option<&str> {
+        self.entries.get(&object).map(|entry| entry.tag.as_str())
+    }
+
+    /// The indexed position of `object`
+    pub fn position(&self, object: GameObjectId) -> Option<Vector3<f32>> {
+        self.entries.get(&object).map(|entry| entry.position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.entries.clear();
+    }
+
+    /// The closest object within `radius` of `point`
+    pub fn nearest(&self, point: Vector3<f32>, radius: f32) -> Option<GameObjectId> {
+        self.find_nearest(point, radius, None)
+    }
+
+    /// The closest object with `tag` within `radius` of `point`
+    pub fn nearest_tagged(
+        &self,
+        point: Vector3<f32>,
+        radius: f32,
+        tag: &str,
+    ) -> Option<GameObjectId> {
+        self.find_nearest(point, radius, Some(tag))
+    }
+
+    /// All objects within `radius` of `point`, closest first
+    pub fn within(&self, point: Vector3<f32>, radius: f32) -> Vec<GameObjectId> {
+        self.find_within(point, radius, None)
+    }
+
+    /// All objects with `tag` within `radius` of `point`, closest first
+    pub fn within_tagged(&self, point: Vector3<f32>, radius: f32, tag: &str) -> Vec<GameObjectId> {
+        self.find_within(point, radius, Some(tag))
+    }
+
+    /// Moves the objects that changed this frame and forgets the ones that were deleted
+    pub(crate) fn refresh(&mut self, objects: &SlotMap<GameObjectId, Box<GameObject>>) {
+        let mut moved = Vec::new();
+        let mut deleted = Vec::new();
+        for object in self.entries.keys() {
+            match objects.get(*object) {
+                Some(obj) if obj.is_alive() => {
+                    if obj.transform.is_dirty() {
+                        moved.push((*object, obj.transform.position()));
+                    }
+                }
+                _ => deleted.push(*object),
+            }
+        }
+
+        for object in deleted {
+            self.remove(object);
+        }
+        for (object, position) in moved {
+            let Some(entry) = self.entries.remove(&object) else {
+                continue;
+            };
+            self.unlink(object, entry.cell);
+            self.place(object, entry.tag, position);
+        }
+    }
+
+    fn place(&mut self, object: GameObjectId, tag: String, position: Vector3<f32>) {
+        let cell = self.cell_of(&position);
+        self.cells.entry(cell).or_default().push(object);
+        self.entries.insert(
+            object,
+            SpatialEntry {
+                tag,
+                position,
+                cell,
+            },
+        );
+    }
+
+    fn unlink(&mut self, object: GameObjectId, cell: Cell) {
+        if let Some(objects) = self.cells.get_mut(&cell) {
+            objects.retain(|o| *o != object);
+            if objects.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+    }
+
+    fn cell_of(&self, position: &Vector3<f32>) -> Cell {
+        let cell = position / self.cell_size;
+        (
+            cell.x.floor() as i32,
+            cell.y.floor() as i32,
+            cell.z.floor() as i32,
+        )
+    }
+
+    fn find_nearest(
+        &self,
+        point: Vector3<f32>,
+        radius: f32,
+        tag: Option<&str>,
+    ) -> Option<GameObjectId> {
+        self.candidates(point, radius, tag)
+            .min_by(|(a, da), (b, db)| da.total_cmp(db).then_with(|| a.cmp(b)))
+            .map(|(object, _)| object)
+    }
+
+    fn find_within(
+        &self,
+        point: Vector3<f32>,
+        radius: f32,
+        tag: Option<&str>,
+    ) -> Vec<GameObjectId> {
+        let mut found: Vec<_> = self.candidates(point, radius, tag).collect();
+        found.sort_by(|(a, da), (b, db)| da.total_cmp(db).then_with(|| a.cmp(b)));
+        found.into_iter().map(|(object, _)| object).collect()
+    }
+
+    /// Objects within `radius` of `point` with their squared distance
+    fn candidates<'a>(
+        &'a self,
+        point: Vector3<f32>,
+        radius: f32,
+        tag: Option<&'a str>,
+    ) -> impl Iterator<Item = (GameObjectId, f32)> + 'a {
+        let radius = radius.max(0.0);
+        let min = self.cell_of(&point.add_scalar(-radius));
+        let max = self.cell_of(&point.add_scalar(radius));
+
+        // a huge radius spans more cells than there are occupied ones
+        let span = |a: i32, b: i32| (b as i64 - a as i64 + 1) as u128;
+        let spanned = span(min.0, max.0) * span(min.1, max.1) * span(min.2, max.2);
+        let cells: Vec<&Vec<GameObjectId>> = if spanned > self.cells.len() as u128 {
+            self.cells.values().collect()
+        } else {
+            (min.0..=max.0)
+                .flat_map(|x| (min.1..=max.1).map(move |y| (x, y)))
+                .flat_map(|(x, y)| (min.2..=max.2).map(move |z| (x, y, z)))
+                .filter_map(|cell| self.cells.get(&cell))
+                .collect()
+        };
+
+        cells.into_iter().flatten().filter_map(move |object| {
+            let entry = &self.entries[object];
+            if tag.is_some_and(|tag| entry.tag != tag) {
+                return None;
+            }
+            let distance = (entry.position - point).norm_squared();
+            (distance <= radius * radius).then_some((*object, distance))
+        })
+    }
+}
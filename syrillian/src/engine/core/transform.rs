@@ -1,5 +1,5 @@
-use crate::core::GameObjectId;
-use nalgebra::{Affine3, Isometry3, Point, Scale3, Translation3, UnitQuaternion, Vector3};
+use crate::core::{GameObject, GameObjectId};
+use nalgebra::{Affine3, Isometry3, Point, Point3, Scale3, Translation3, UnitQuaternion, Vector3};
 use num_traits::AsPrimitive;
 
 /// Stores the translation, rotation and scale of a [`GameObject`](crate::core::GameObject).
@@ -242,6 +242,29 @@ impl Transform {
         self.set_local_rotation(local_rotation_change);
     }
 
+    /// Turns the transform so its [forward](Transform::forward) axis points at `target`, with
+    /// its up axis as close to `up` as possible. Both are in world space.
+    ///
+    /// When looking straight along `up`, the current forward direction becomes the new up, so
+    /// a camera looking straight down keeps its heading. Nothing happens if `target` is the
+    /// position of the transform.
+    pub fn look_at(&mut self, target: Point3<f32>, up: Vector3<f32>) {
+        let Some(dir) = (target.coords - self.position()).try_normalize(f32::EPSILON) else {
+            return;
+        };
+
+        let fallbacks = [up, self.forward(), Vector3::z(), Vector3::x()];
+        let Some(up) = fallbacks
+            .into_iter()
+            .find(|up| dir.cross(up).norm_squared() > 1e-6)
+        else {
+            return;
+        };
+
+        // forward is -Z, while face_towards turns +Z toward the direction
+        self.set_rotation(UnitQuaternion::face_towards(&-dir, &up));
+    }
+
     /// Returns the global rotation quaternion.
     pub fn rotation(&self) -> UnitQuaternion<f32> {
         self.global_rotation_ext(true)
@@ -330,17 +353,44 @@ impl Transform {
     }
 
     fn recalculate_combined_matrix(&mut self) {
-        self.compound_mat = Affine3::from_matrix_unchecked(
-            self.pos_mat.to_homogeneous()
-                * self.rot.to_homogeneous()
-                * self.scale_mat.to_homogeneous(),
-        );
+        let compound_mat = self.combined_matrix();
 
-        self.set_dirty();
+        // writing back the same values, like physics does for sleeping bodies, isn't a change
+        if compound_mat != self.compound_mat {
+            self.compound_mat = compound_mat;
+            self.set_dirty();
+        }
 
         debug_assert_ne!(0.0, self.compound_mat.matrix().determinant());
     }
 
+    /// Moves this transform to a global pose without marking its children dirty, which is
+    /// left to the caller, see [`World::set_transforms`](crate::World::set_transforms)
+    pub(crate) fn set_global_pose_deferred(&mut self, pose: &Isometry3<f32>) {
+        let parent_mat = self.global_transform_matrix_ext(false);
+        let parent_rot = self.global_rotation_ext(false);
+        let position = parent_mat.inverse_transform_point(&pose.translation.vector.into());
+
+        self.pos = position.coords;
+        self.rot = parent_rot.inverse() * pose.rotation;
+        let pos = if self.invert_position {
+            -self.pos
+        } else {
+            self.pos
+        };
+        self.pos_mat = Translation3::from(pos);
+        self.compound_mat = self.combined_matrix();
+        self.is_dirty = true;
+    }
+
+    fn combined_matrix(&self) -> Affine3<f32> {
+        Affine3::from_matrix_unchecked(
+            self.pos_mat.to_homogeneous()
+                * self.rot.to_homogeneous()
+                * self.scale_mat.to_homogeneous(),
+        )
+    }
+
     pub fn translation(&self) -> &Translation3<f32> {
         &self.pos_mat
     }
@@ -399,4 +449,25 @@ impl Transform {
     pub fn clear_dirty(&mut self) {
         self.is_dirty = false;
     }
+
+    /// Whether this transform, or one of its parents, was moved, rotated or scaled during
+    /// the current frame. Objects also count as changed in the frame they were created in.
+    ///
+    /// The flag is cleared in [`World::next_frame`](crate::World::next_frame). Use
+    /// [`World::query_changed`](crate::World::query_changed) to find all changed objects.
+    pub fn changed_this_frame(&self) -> bool {
+        self.is_dirty
+    }
+}
+
+/// Part of a game object that knows if it changed during the current frame, see
+/// [`World::query_changed`](crate::World::query_changed)
+pub trait ChangeTracked {
+    fn changed_this_frame(obj: &GameObject) -> bool;
+}
+
+impl ChangeTracked for Transform {
+    fn changed_this_frame(obj: &GameObject) -> bool {
+        obj.transform.changed_this_frame()
+    }
 }
@@ -0,0 +1,72 @@
+//! Which way is up in a world, for content authored Z-up.
+//!
+//! The engine works Y-up by default. A world built with
+//! [`WorldBuilder::up_axis`](crate::WorldBuilder::up_axis) set to [`UpAxis::Z`] instead pulls
+//! gravity down -Z, and everything else that needs to know where up is follows along:
+//!
+//! - [`CameraPrefab`](crate::prefabs::CameraPrefab) starts out level, looking along +Y, the
+//!   [`FirstPersonPlayerPrefab`](crate::prefabs::FirstPersonPlayerPrefab) stands upright and
+//!   the [`SunPrefab`](crate::prefabs::SunPrefab) shines down from above
+//! - the first person, free and follow cameras turn around and look relative to the up axis
+//! - the first person movement controller walks on the plane across it, and jumps along it
+//! - [`GameObjectId::look_at`](crate::core::GameObjectId::look_at) keeps objects upright
+//! - glTF scenes, which are always Y-up, are turned so their up points up. The root of the
+//!   scene carries the turn, see [`SceneLoader::load`](crate::assets::scene_loader::SceneLoader::load).
+//! - OBJ meshes are Y-up by convention too. Loading them with
+//!   [`Mesh::load_from_obj_slice_with_up_axis`](crate::assets::Mesh::load_from_obj_slice_with_up_axis)
+//!   turns their vertices upright. The built-in meshes stay as they are.
+//!
+//! Everything in one world has to use the same convention. Mixing them, for example by
+//! changing gravity to another axis later, leaves the cameras and controllers behind.
+//!
+//! ```rust
+//! use syrillian::World;
+//! use syrillian::core::UpAxis;
+//!
+//! let world = World::builder().up_axis(UpAxis::Z).build();
+//! assert!(world.physics.gravity.z < 0.0);
+//! ```
+
+use nalgebra::{Unit, UnitQuaternion, Vector3};
+use std::f32::consts::FRAC_PI_2;
+
+/// The world axis pointing up. Default: [`UpAxis::Y`]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum UpAxis {
+    /// Up is +Y and the default forward is -Z
+    #[default]
+    Y,
+    /// Up is +Z and the default forward is +Y, like in Blender
+    Z,
+}
+
+impl UpAxis {
+    pub fn up(self) -> Vector3<f32> {
+        *self.up_axis()
+    }
+
+    pub fn up_axis(self) -> Unit<Vector3<f32>> {
+        match self {
+            UpAxis::Y => Vector3::y_axis(),
+            UpAxis::Z => Vector3::z_axis(),
+        }
+    }
+
+    /// The rotation that turns Y-up content upright in this convention
+    pub fn from_y_up(self) -> UnitQuaternion<f32> {
+        match self {
+            UpAxis::Y => UnitQuaternion::identity(),
+            UpAxis::Z => UnitQuaternion::from_axis_angle(&Vector3::x_axis(), FRAC_PI_2),
+        }
+    }
+
+    /// How far `v` points up
+    pub fn height(self, v: &Vector3<f32>) -> f32 {
+        v.dot(&self.up())
+    }
+
+    /// `v` without the part that points up
+    pub fn horizontal(self, v: &Vector3<f32>) -> Vector3<f32> {
+        v - self.up() * self.height(v)
+    }
+}
@@ -0,0 +1,166 @@
+//! Notifications about objects appearing in and disappearing from the world, for systems
+//! that keep their own lists of objects, like a spatial index or an outliner. Scenes loaded
+//! in the background also report here when they're done.
+//!
+//! ```rust
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//! use syrillian::World;
+//! use syrillian::core::WorldEvent;
+//!
+//! let mut world = World::builder().build();
+//! let alive = Rc::new(RefCell::new(Vec::new()));
+//!
+//! let list = alive.clone();
+//! world.on_world_event(move |_world, event| match event {
+//!     WorldEvent::Spawned(obj) => list.borrow_mut().push(obj),
+//!     WorldEvent::Destroyed(obj) => list.borrow_mut().retain(|o| *o != obj),
+//!     _ => {}
+//! });
+//!
+//! let obj = world.new_object("Crate");
+//! world.update();
+//! assert_eq!(*alive.borrow(), [obj]);
+//!
+//! world.delete_object(obj);
+//! assert!(alive.borrow().is_empty());
+//! ```
+
+use crate::World;
+use crate::core::GameObjectId;
+
+/// Something that happened to an object, see [`World::on_world_event`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum WorldEvent {
+    /// An object was created.
+    ///
+    /// Sent at the start of the next [`World::update`] or physics tick, before any component
+    /// starts, so the object is fully set up with all its components by then. Objects deleted
+    /// before that are never announced, and don't send [`WorldEvent::Destroyed`] either.
+    Spawned(GameObjectId),
+    /// An object is being deleted.
+    ///
+    /// Sent right away, before its children are deleted and before [`Component::delete`] runs
+    /// for any of its components, so the object can still be read. It's already marked as
+    /// not alive though.
+    ///
+    /// [`Component::delete`]: crate::components::Component::delete
+    Destroyed(GameObjectId),
+    /// A scene started with [`World::load_gltf_async`] was spawned under this root
+    SceneLoaded(GameObjectId),
+    /// A scene started with [`World::load_gltf_async`] couldn't be loaded, its root stays
+    /// empty
+    SceneLoadFailed(GameObjectId),
+    /// Two objects hit each other harder than the
+    /// [threshold](crate::physics::PhysicsManager::set_hard_impact_threshold). Sent right
+    /// after the physics step, `force` is in Newtons.
+    HardImpact {
+        a: GameObjectId,
+        b: GameObjectId,
+        force: f32,
+    },
+}
+
+impl WorldEvent {
+    /// The object the event is about, or the first one for [`WorldEvent::HardImpact`]
+    pub fn object(&self) -> GameObjectId {
+        match *self {
+            WorldEvent::Spawned(obj)
+            | WorldEvent::Destroyed(obj)
+            | WorldEvent::SceneLoaded(obj)
+            | WorldEvent::SceneLoadFailed(obj)
+            | WorldEvent::HardImpact { a: obj, .. } => obj,
+        }
+    }
+}
+
+pub(crate) type WorldEventHandler = Box<dyn FnMut(&mut World, WorldEvent)>;
+
+/// The registered handlers, and the events that still have to reach them
+#[derive(Default)]
+pub(crate) struct WorldEvents {
+    handlers: Vec<WorldEventHandler>,
+    queued: Vec<WorldEvent>,
+    /// Spawned objects that haven't been announced yet
+    unannounced: Vec<GameObjectId>,
+    dispatching: bool,
+}
+
+impl World {
+    /// Runs `handler` for every [`WorldEvent`]. Handlers run in the order they were registered.
+    ///
+    /// Events caused by a handler, like an object it deletes, are delivered once all handlers
+    /// are done with the current event.
+    pub fn on_world_event(&mut self, handler: impl FnMut(&mut World, WorldEvent) + 'static) {
+        self.world_events.handlers.push(Box::new(handler));
+    }
+
+    /// Removes all handlers registered with [`World::on_world_event`]
+    pub fn clear_world_event_handlers(&mut self) {
+        self.world_events.handlers.clear();
+    }
+
+    pub(crate) fn note_spawned(&mut self, obj: GameObjectId) {
+        self.world_events.unannounced.push(obj);
+    }
+
+    /// Sends [`WorldEvent::Spawned`] for all objects created since the last call
+    pub(crate) fn announce_spawned(&mut self) {
+        for obj in std::mem::take(&mut self.world_events.unannounced) {
+            if obj.exists() {
+                self.emit_world_event(WorldEvent::Spawned(obj));
+            }
+        }
+    }
+
+    pub(crate) fn announce_destroyed(&mut self, obj: GameObjectId) {
+        let unannounced = &mut self.world_events.unannounced;
+        if let Some(index) = unannounced.iter().position(|o| *o == obj) {
+            unannounced.remove(index);
+            return;
+        }
+        self.emit_world_event(WorldEvent::Destroyed(obj));
+    }
+
+    /// Sends [`WorldEvent::HardImpact`] for the impacts the physics step added after `skip`
+    pub(crate) fn announce_hard_impacts(&mut self, skip: usize) {
+        let impacts = self.physics.hard_impacts()[skip..].to_vec();
+        for impact in impacts {
+            let a = self.physics.collider_object(impact.collider1);
+            let b = self.physics.collider_object(impact.collider2);
+            if let (Some(a), Some(b)) = (a, b) {
+                self.emit_world_event(WorldEvent::HardImpact {
+                    a,
+                    b,
+                    force: impact.force,
+                });
+            }
+        }
+    }
+
+    pub(crate) fn emit_world_event(&mut self, event: WorldEvent) {
+        if self.world_events.dispatching {
+            self.world_events.queued.push(event);
+            return;
+        }
+        if self.world_events.handlers.is_empty() {
+            return;
+        }
+
+        self.world_events.dispatching = true;
+        let mut handlers = std::mem::take(&mut self.world_events.handlers);
+        let mut events = vec![event];
+        while !events.is_empty() {
+            for event in events {
+                for handler in &mut handlers {
+                    handler(self, event);
+                }
+            }
+            events = std::mem::take(&mut self.world_events.queued);
+        }
+        // keep handlers that were registered while dispatching
+        handlers.append(&mut self.world_events.handlers);
+        self.world_events.handlers = handlers;
+        self.world_events.dispatching = false;
+    }
+}
@@ -0,0 +1,135 @@
+//! Named actions bound to keys, mouse buttons and gamepad buttons, which players can rebind.
+//!
+//! ```rust
+//! use syrillian::World;
+//! use syrillian::input::Binding;
+//! use syrillian::winit::keyboard::KeyCode;
+//!
+//! let mut world = World::builder().build();
+//! let bindings = &mut world.input.bindings;
+//! bindings.add_action("jump", [Binding::Key(KeyCode::Space)]);
+//!
+//! // the player picked another key in the settings
+//! bindings.rebind("jump", [Binding::Key(KeyCode::KeyJ)]);
+//! # let path = std::env::temp_dir().join("syrillian_doc_bindings.json");
+//! world.input.save_bindings(&path).unwrap();
+//!
+//! // on the next start
+//! world.input.load_bindings(&path).unwrap();
+//! assert!(!world.input.is_action_pressed("jump"));
+//! ```
+
+use gilrs::Button;
+use serde::{Deserialize, Serialize};
+use snafu::{ResultExt, Snafu};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use tracing::warn;
+use winit::event::MouseButton;
+use winit::keyboard::KeyCode;
+
+#[derive(Debug, Snafu)]
+#[snafu(context(suffix(Err)))]
+pub enum BindingsError {
+    #[snafu(display("failed to access the bindings file: {source}"))]
+    BindingsIo { source: std::io::Error },
+    #[snafu(display("the bindings file is malformed: {source}"))]
+    BindingsFormat { source: serde_json::Error },
+}
+
+/// An input that can trigger an action
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Binding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(Button),
+}
+
+#[derive(Debug, Clone, Default)]
+struct Action {
+    defaults: Vec<Binding>,
+    bindings: Vec<Binding>,
+}
+
+/// The actions of a game, each with the inputs that trigger it.
+///
+/// Actions are added by the game with their default bindings. Players then
+/// [rebind](InputBindings::rebind) them, and the result is [saved](InputBindings::save) to a
+/// config file and [loaded](InputBindings::load) again on the next start. Whether an action
+/// is triggered is asked through the [`InputManager`](crate::input::InputManager), like
+/// [`InputManager::is_action_pressed`](crate::input::InputManager::is_action_pressed).
+#[derive(Debug, Clone, Default)]
+pub struct InputBindings {
+    actions: HashMap<String, Action>,
+}
+
+impl InputBindings {
+    /// Adds an action that's triggered by `defaults` until it's rebound. Adding an action
+    /// again replaces its defaults and bindings.
+    pub fn add_action(
+        &mut self,
+        action: impl Into<String>,
+        defaults: impl IntoIterator<Item = Binding>,
+    ) {
+        let defaults: Vec<_> = defaults.into_iter().collect();
+        let bindings = defaults.clone();
+        self.actions
+            .insert(action.into(), Action { defaults, bindings });
+    }
+
+    /// Replaces the bindings of an action. Returns `false` for actions that weren't added.
+    pub fn rebind(&mut self, action: &str, bindings: impl IntoIterator<Item = Binding>) -> bool {
+        let Some(action) = self.actions.get_mut(action) else {
+            return false;
+        };
+        action.bindings = bindings.into_iter().collect();
+        true
+    }
+
+    /// Goes back to the default bindings of all actions
+    pub fn reset_to_defaults(&mut self) {
+        for action in self.actions.values_mut() {
+            action.bindings = action.defaults.clone();
+        }
+    }
+
+    /// The current bindings of an action, empty for actions that weren't added
+    pub fn get(&self, action: &str) -> &[Binding] {
+        self.actions
+            .get(action)
+            .map_or(&[], |action| &action.bindings)
+    }
+
+    pub fn has_action(&self, action: &str) -> bool {
+        self.actions.contains_key(action)
+    }
+
+    /// Writes the current bindings of all actions to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), BindingsError> {
+        let bindings: BTreeMap<_, _> = self
+            .actions
+            .iter()
+            .map(|(name, action)| (name, &action.bindings))
+            .collect();
+        let json = serde_json::to_string_pretty(&bindings).context(BindingsFormatErr)?;
+        std::fs::write(path, json).context(BindingsIoErr)
+    }
+
+    /// Reads bindings written by [`InputBindings::save`].
+    ///
+    /// Actions in the file that weren't added are ignored with a warning, and actions missing
+    /// from the file go back to their defaults. Nothing changes if the file can't be read.
+    pub fn load(&mut self, path: impl AsRef<Path>) -> Result<(), BindingsError> {
+        let json = std::fs::read_to_string(path).context(BindingsIoErr)?;
+        let loaded: HashMap<String, Vec<Binding>> =
+            serde_json::from_str(&json).context(BindingsFormatErr)?;
+
+        self.reset_to_defaults();
+        for (name, bindings) in loaded {
+            if !self.rebind(&name, bindings) {
+                warn!("[Input] Ignoring saved bindings of unknown action {name:?}");
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,10 +1,12 @@
 use crate::game_thread::GameAppEvent;
+use crate::input::bindings::{Binding, BindingsError, InputBindings};
 use crate::input::gamepad_manager::GamePadManager;
 use crate::windowing::RenderTargetId;
 use crossbeam_channel::Sender;
 use nalgebra::Vector2;
 use num_traits::Zero;
 use std::collections::HashMap;
+use std::path::Path;
 use tracing::{info, trace};
 use winit::dpi::PhysicalPosition;
 use winit::event::{DeviceEvent, ElementState, MouseButton, MouseScrollDelta, WindowEvent};
@@ -30,6 +32,8 @@ pub struct InputManager {
     focus: HashMap<RenderTargetId, bool>,
     active_target: RenderTargetId,
     pub gamepad: GamePadManager,
+    /// The actions of the game and their inputs
+    pub bindings: InputBindings,
     game_event_tx: Sender<GameAppEvent>,
 }
 
@@ -41,6 +45,7 @@ impl InputManager {
             focus: HashMap::default(),
             active_target: RenderTargetId::PRIMARY,
             gamepad: GamePadManager::default(),
+            bindings: InputBindings::default(),
             game_event_tx,
         }
     }
@@ -266,6 +271,58 @@ impl InputManager {
         }
     }
 
+    fn binding_pressed(&self, binding: Binding) -> bool {
+        match binding {
+            Binding::Key(key) => self.is_key_pressed(key),
+            Binding::Mouse(button) => self.is_button_pressed(button),
+            Binding::Gamepad(button) => self.gamepad.is_button_pressed(button),
+        }
+    }
+
+    fn binding_down(&self, binding: Binding) -> bool {
+        match binding {
+            Binding::Key(key) => self.is_key_down(key),
+            Binding::Mouse(button) => self.is_button_down(button),
+            Binding::Gamepad(button) => self.gamepad.is_button_down(button),
+        }
+    }
+
+    fn binding_released(&self, binding: Binding) -> bool {
+        match binding {
+            Binding::Key(key) => self.is_key_released(key),
+            Binding::Mouse(button) => self.is_button_released(button),
+            Binding::Gamepad(button) => self.gamepad.is_button_released(button),
+        }
+    }
+
+    /// True if any input bound to `action` is being held, see [`InputBindings`]
+    pub fn is_action_pressed(&self, action: &str) -> bool {
+        let bindings = self.bindings.get(action);
+        bindings.iter().any(|b| self.binding_pressed(*b))
+    }
+
+    /// Only is true if an input bound to `action` was JUST pressed
+    pub fn is_action_down(&self, action: &str) -> bool {
+        let bindings = self.bindings.get(action);
+        bindings.iter().any(|b| self.binding_down(*b))
+    }
+
+    /// Only is true if an input bound to `action` was JUST released
+    pub fn is_action_released(&self, action: &str) -> bool {
+        let bindings = self.bindings.get(action);
+        bindings.iter().any(|b| self.binding_released(*b))
+    }
+
+    /// Writes the current action bindings to a config file, see [`InputBindings::save`]
+    pub fn save_bindings(&self, path: impl AsRef<Path>) -> Result<(), BindingsError> {
+        self.bindings.save(path)
+    }
+
+    /// Reads action bindings from a config file, see [`InputBindings::load`]
+    pub fn load_bindings(&mut self, path: impl AsRef<Path>) -> Result<(), BindingsError> {
+        self.bindings.load(path)
+    }
+
     pub fn is_sprinting(&self) -> bool {
         self.is_key_pressed(KeyCode::ShiftLeft)
     }
@@ -1,9 +1,12 @@
 //! Handling of keyboard and mouse input.
 //!
 //! [`InputManager`] tracks key states and mouse movement and is used by
-//! components and systems to react to user interaction.
+//! components and systems to react to user interaction. Its [`InputBindings`] map named
+//! actions to inputs that players can rebind.
 
+pub mod bindings;
 mod gamepad_manager;
 pub mod input_manager;
 
+pub use self::bindings::{Binding, BindingsError, InputBindings};
 pub use self::input_manager::*;
@@ -9,6 +9,7 @@ pub mod core;
 pub mod input;
 pub mod physics;
 pub mod world;
+pub mod world_builder;
 
 pub mod assets;
 pub mod audio;
@@ -16,6 +17,7 @@ pub mod prefabs;
 pub mod rendering;
 
 pub use self::world::World;
+pub use self::world_builder::WorldBuilder;
 
 pub const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const ENGINE_NAME: &str = env!("CARGO_PKG_NAME");
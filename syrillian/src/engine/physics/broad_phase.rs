@@ -0,0 +1,127 @@
+//! Tuning the broad-phase for large worlds.
+//!
+//! The broad-phase is the first pass of collision detection. It keeps the bounding boxes of
+//! all colliders in a tree and finds the pairs that are close enough to maybe touch, and it's
+//! what scene queries like raycasts walk through. With tens of thousands of colliders, it can
+//! make up most of a physics step.
+//!
+//! The tree has no fixed extents. It adapts to wherever colliders are, so a large world needs
+//! no up-front size. What it's sensitive to:
+//!
+//! - **Moving colliders.** Static colliders cost close to nothing per step once they're in the
+//!   tree, but every collider that moves has to be refitted. Keep static geometry on fixed
+//!   bodies, or without a body at all.
+//! - **Tree quality.** As colliders move and get added, the tree drifts out of shape and
+//!   queries get slower. [`BroadPhaseOptimization::Incremental`] spends a little time every
+//!   step rebuilding parts of it.
+//! - **Runaway bodies.** A body falling out of the level keeps being tracked forever, and
+//!   stretches the tree far beyond the area that matters. [`PhysicsManager::world_bounds`]
+//!   stops simulating bodies that leave the playable area.
+//!
+//! ```rust
+//! use nalgebra::Vector3;
+//! use syrillian::World;
+//! use syrillian::physics::{BroadPhaseConfig, BroadPhaseOptimization};
+//!
+//! let world = World::builder()
+//!     .broad_phase(BroadPhaseConfig {
+//!         optimization: BroadPhaseOptimization::Incremental,
+//!         length_unit: 2.0,
+//!     })
+//!     .world_bounds(Vector3::new(-4096.0, -200.0, -4096.0), Vector3::new(4096.0, 1000.0, 4096.0))
+//!     .build();
+//!
+//! assert!(world.physics.world_bounds.is_some());
+//! ```
+
+use crate::core::GameObjectId;
+use crate::physics::PhysicsManager;
+use nalgebra::Point3;
+use rapier3d::prelude::{BroadPhaseBvh, BvhOptimizationStrategy};
+use tracing::warn;
+
+/// How the broad-phase tree is kept in shape
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum BroadPhaseOptimization {
+    /// Rebuilds a different part of the tree every step. Costs a small, steady amount of time
+    /// and keeps queries fast in worlds where things move around.
+    #[default]
+    Incremental,
+    /// Never rebuilds the tree. Only worth it when nearly all colliders are static and added
+    /// up front, otherwise collision detection and queries slowly get more expensive.
+    None,
+}
+
+/// Settings for the broad-phase, see the [module docs](self) for the tradeoffs.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BroadPhaseConfig {
+    pub optimization: BroadPhaseOptimization,
+    /// The typical size of a dynamic object, in world units. Default: 1
+    ///
+    /// Colliders that move less than 1% of this aren't refitted in the tree, so a larger unit
+    /// saves work on slowly drifting bodies, at the cost of looser boxes and more pairs for
+    /// the narrow-phase. This is rapier's `length_unit`, so it also scales the solver's
+    /// tolerances, which should match the scale of the world anyway.
+    pub length_unit: f32,
+}
+
+impl Default for BroadPhaseConfig {
+    fn default() -> Self {
+        Self {
+            optimization: BroadPhaseOptimization::default(),
+            length_unit: 1.0,
+        }
+    }
+}
+
+impl BroadPhaseConfig {
+    /// Replaces the broad-phase of `physics`. Colliders that are already in the old one are
+    /// lost, so this is only done on a fresh world.
+    pub(crate) fn apply(&self, physics: &mut PhysicsManager) {
+        let strategy = match self.optimization {
+            BroadPhaseOptimization::Incremental => BvhOptimizationStrategy::SubtreeOptimizer,
+            BroadPhaseOptimization::None => BvhOptimizationStrategy::None,
+        };
+        physics.broad_phase = BroadPhaseBvh::with_optimization_strategy(strategy);
+        physics.integration_parameters.length_unit = self.length_unit.max(f32::EPSILON);
+    }
+}
+
+impl PhysicsManager {
+    /// Disables the moving bodies that left [`PhysicsManager::world_bounds`]
+    pub(crate) fn disable_out_of_bounds(&mut self) {
+        let Some(bounds) = self.world_bounds else {
+            return;
+        };
+
+        let escaped: Vec<_> = self
+            .island_manager
+            .active_bodies()
+            .iter()
+            .copied()
+            .filter(|handle| {
+                self.rigid_body_set.get(*handle).is_some_and(|body| {
+                    !bounds.contains_local_point(&Point3::from(*body.translation()))
+                })
+            })
+            .collect();
+
+        for handle in escaped {
+            let Some(body) = self.rigid_body_set.get_mut(handle) else {
+                continue;
+            };
+            body.set_enabled(false);
+
+            let object = GameObjectId::from_ffi(body.user_data as u64);
+            let name = if object.exists() {
+                object.name.as_str()
+            } else {
+                "<unknown>"
+            };
+            warn!(
+                "[Physics] {name} left the world bounds at {:?}, its body was disabled",
+                body.translation()
+            );
+        }
+    }
+}
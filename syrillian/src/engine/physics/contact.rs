@@ -0,0 +1,130 @@
+//! Contact information of touching colliders, gathered from rapier's narrow phase.
+
+use crate::core::GameObjectId;
+use crate::physics::PhysicsManager;
+use nalgebra::{Point3, Vector3};
+use rapier3d::prelude::*;
+
+/// A single point where two colliders touch.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ContactPoint {
+    /// Position of the contact on the surface of the queried collider, in world space
+    pub position: Point3<f32>,
+    /// How deep the colliders overlap at this point. Positive values mean penetration.
+    pub depth: f32,
+}
+
+/// All contacts between a collider and one other collider that share a normal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contact {
+    /// The collider that is being touched
+    pub other: ColliderHandle,
+    /// The game object the other collider belongs to, if it still exists
+    pub other_object: Option<GameObjectId>,
+    /// World-space contact normal, pointing from the queried collider towards the other one
+    pub normal: Vector3<f32>,
+    /// Contact points of this manifold, never empty
+    pub points: Vec<ContactPoint>,
+    /// The impulse the solver applied along the normal in the last step, summed over all
+    /// points. Divide by the timestep for the force.
+    pub impulse: f32,
+}
+
+/// Something an object touches, summarized from a [`Contact`]. See [`World::contacts_with`](crate::World::contacts_with).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ContactInfo {
+    /// The object being touched
+    pub other: GameObjectId,
+    /// Which collider of the queried object touches it
+    pub collider: ColliderHandle,
+    /// World-space contact normal, pointing from the queried object towards the other one
+    pub normal: Vector3<f32>,
+    /// The deepest contact point, on the surface of the queried object
+    pub point: Point3<f32>,
+    /// How deep the colliders overlap at that point
+    pub depth: f32,
+}
+
+impl ContactInfo {
+    /// Turns a contact of `collider` into a summary, if the other object still exists
+    pub(crate) fn from_contact(collider: ColliderHandle, contact: &Contact) -> Option<Self> {
+        let deepest = contact
+            .points
+            .iter()
+            .max_by(|a, b| a.depth.total_cmp(&b.depth))?;
+        Some(ContactInfo {
+            other: contact.other_object?,
+            collider,
+            normal: contact.normal,
+            point: deepest.position,
+            depth: deepest.depth,
+        })
+    }
+}
+
+impl PhysicsManager {
+    /// Returns the contact manifolds of everything currently touching the given collider.
+    ///
+    /// The contacts are as of the last [`PhysicsManager::step`]. Normals and points are
+    /// expressed from the perspective of `collider`, no matter the order rapier
+    /// keeps the pair in.
+    pub fn contacts(&self, collider: ColliderHandle) -> Vec<Contact> {
+        let mut contacts = Vec::new();
+
+        for pair in self.narrow_phase.contact_pairs_with(collider) {
+            if !pair.has_any_active_contact {
+                continue;
+            }
+
+            let flipped = pair.collider2 == collider;
+            let (this, other) = if flipped {
+                (pair.collider2, pair.collider1)
+            } else {
+                (pair.collider1, pair.collider2)
+            };
+            let Some(this_collider) = self.collider_set.get(this) else {
+                continue;
+            };
+
+            for manifold in &pair.manifolds {
+                let subshape_pos = if flipped {
+                    manifold.subshape_pos2
+                } else {
+                    manifold.subshape_pos1
+                };
+                let shape_pos = match subshape_pos {
+                    Some(sub) => this_collider.position() * sub,
+                    None => *this_collider.position(),
+                };
+
+                let points: Vec<_> = manifold
+                    .points
+                    .iter()
+                    .map(|p| ContactPoint {
+                        position: shape_pos * if flipped { p.local_p2 } else { p.local_p1 },
+                        depth: -p.dist,
+                    })
+                    .collect();
+                if points.is_empty() {
+                    continue;
+                }
+
+                let normal = if flipped {
+                    -manifold.data.normal
+                } else {
+                    manifold.data.normal
+                };
+
+                contacts.push(Contact {
+                    other,
+                    other_object: self.collider_object(other),
+                    normal,
+                    points,
+                    impulse: manifold.points.iter().map(|p| p.data.impulse).sum(),
+                });
+            }
+        }
+
+        contacts
+    }
+}
@@ -0,0 +1,168 @@
+//! Grabbing dynamic bodies and pulling them around, for example with the mouse.
+//!
+//! ```rust
+//! use nalgebra::Vector3;
+//! use syrillian::World;
+//! use syrillian::components::{Collider3D, RigidBodyComponent};
+//!
+//! let mut world = World::builder().build();
+//! let mut crate_obj = world.new_object("Crate");
+//! crate_obj.add_component::<Collider3D>();
+//! crate_obj.add_component::<RigidBodyComponent>();
+//!
+//! // grab the top of the crate and pull it up
+//! let drag = world.begin_drag(crate_obj, Vector3::new(0.0, 0.5, 0.0)).unwrap();
+//! drag.move_to(Vector3::new(0.0, 3.0, 0.0));
+//! world.fixed_update();
+//! drag.end_drag();
+//! ```
+
+use crate::World;
+use crate::core::GameObjectId;
+use nalgebra::{Point3, Vector3};
+use rapier3d::prelude::*;
+use std::f32::consts::TAU;
+
+const DEFAULT_FREQUENCY_HZ: f32 = 5.0;
+const DEFAULT_DAMPING_RATIO: f32 = 1.0;
+/// Angular damping of a held body, so it stops swinging around the grab point
+const HELD_ANGULAR_DAMPING: f32 = 5.0;
+
+const LIN_AXES: [JointAxis; 3] = [JointAxis::LinX, JointAxis::LinY, JointAxis::LinZ];
+
+/// A body being dragged by a point, created with [`World::begin_drag`](crate::World::begin_drag).
+///
+/// The grabbed point is pulled toward an invisible kinematic anchor by a spring on all three
+/// linear axes, while rotation stays free, so the body swings and tumbles around the grab
+/// point like it's held on a short rubber band. The spring works on accelerations, so light
+/// and heavy bodies follow the same way. While held, the body's angular damping is raised so
+/// it comes to rest hanging below the grab point instead of swinging like a pendulum, and
+/// it's restored when the drag ends.
+///
+/// The drag keeps going until [`DragHandle::end_drag`] is called or the handle is dropped,
+/// which both let go of the body and remove the anchor.
+#[derive(Debug)]
+pub struct DragHandle {
+    object: GameObjectId,
+    anchor: RigidBodyHandle,
+    joint: ImpulseJointHandle,
+    angular_damping: f32,
+}
+
+impl DragHandle {
+    pub(crate) fn new(
+        object: GameObjectId,
+        body: RigidBodyHandle,
+        world_point: Vector3<f32>,
+    ) -> Option<Self> {
+        let physics = &mut object.world().physics;
+        let grabbed = physics.rigid_body_set.get_mut(body)?;
+        if !grabbed.is_dynamic() {
+            return None;
+        }
+        let local_point = grabbed
+            .position()
+            .inverse_transform_point(&world_point.into());
+        let angular_damping = grabbed.angular_damping();
+        grabbed.set_angular_damping(angular_damping.max(HELD_ANGULAR_DAMPING));
+        grabbed.wake_up(true);
+
+        let anchor = physics
+            .rigid_body_set
+            .insert(RigidBodyBuilder::kinematic_position_based().translation(world_point));
+
+        let mut joint = GenericJointBuilder::new(JointAxesMask::empty())
+            .local_anchor1(Point3::origin())
+            .local_anchor2(local_point)
+            .contacts_enabled(false)
+            .build();
+        set_spring(&mut joint, DEFAULT_FREQUENCY_HZ, DEFAULT_DAMPING_RATIO);
+        let joint = physics.impulse_joint_set.insert(anchor, body, joint, true);
+
+        Some(Self {
+            object,
+            anchor,
+            joint,
+            angular_damping,
+        })
+    }
+
+    /// The object being dragged
+    pub fn object(&self) -> GameObjectId {
+        self.object
+    }
+
+    /// Where the grabbed point is pulled to, in world space
+    pub fn target(&self) -> Vector3<f32> {
+        self.object
+            .world()
+            .physics
+            .rigid_body_set
+            .get(self.anchor)
+            .map_or_else(Vector3::zeros, |anchor| {
+                anchor.next_position().translation.vector
+            })
+    }
+
+    /// Pulls the grabbed point toward a new position, reached over the next physics steps
+    pub fn move_to(&self, world_point: Vector3<f32>) {
+        let physics = &mut self.object.world().physics;
+        if let Some(anchor) = physics.rigid_body_set.get_mut(self.anchor) {
+            anchor.set_next_kinematic_translation(world_point);
+        }
+        if let Some(joint) = physics.impulse_joint_set.get(self.joint)
+            && let Some(grabbed) = physics.rigid_body_set.get_mut(joint.body2)
+        {
+            grabbed.wake_up(true);
+        }
+    }
+
+    /// How snappy the body follows. Defaults to 5 Hz with a damping ratio of 1, which
+    /// follows closely without overshooting.
+    ///
+    /// Lower frequencies feel heavier and lag behind, ratios below 1 make the body bounce
+    /// around the target before settling.
+    pub fn set_spring(&self, frequency_hz: f32, damping_ratio: f32) {
+        let physics = &mut self.object.world().physics;
+        if let Some(joint) = physics.impulse_joint_set.get_mut(self.joint, true) {
+            set_spring(&mut joint.data, frequency_hz, damping_ratio);
+        }
+    }
+
+    /// Lets go of the body and removes the anchor
+    pub fn end_drag(self) {
+        drop(self);
+    }
+}
+
+impl Drop for DragHandle {
+    fn drop(&mut self) {
+        // the dragged object may be gone already, but the anchor is still in its world
+        if !World::is_thread_loaded() {
+            return;
+        }
+        let physics = &mut World::instance().physics;
+        if let Some(joint) = physics.impulse_joint_set.remove(self.joint, true)
+            && let Some(grabbed) = physics.rigid_body_set.get_mut(joint.body2)
+        {
+            grabbed.set_angular_damping(self.angular_damping);
+        }
+        physics.rigid_body_set.remove(
+            self.anchor,
+            &mut physics.island_manager,
+            &mut physics.collider_set,
+            &mut physics.impulse_joint_set,
+            &mut physics.multibody_joint_set,
+            true,
+        );
+    }
+}
+
+fn set_spring(joint: &mut GenericJoint, frequency_hz: f32, damping_ratio: f32) {
+    let omega = TAU * frequency_hz.max(0.0);
+    let stiffness = omega * omega;
+    let damping = 2.0 * damping_ratio.max(0.0) * omega;
+    for axis in LIN_AXES {
+        joint.set_motor_position(axis, 0.0, stiffness, damping);
+    }
+}
@@ -0,0 +1,119 @@
+//! Human-readable snapshots of the physics state, meant for debugging and bug reports.
+
+use crate::core::GameObjectId;
+use crate::physics::PhysicsManager;
+use crate::utils::math::QuaternionEuler;
+use nalgebra::{Point3, Vector3};
+use rapier3d::prelude::*;
+use std::fmt::{Display, Formatter, Result};
+
+/// Formats all rigid bodies and impulse joints of a [`PhysicsManager`] as an indented list.
+///
+/// Created by [`PhysicsManager::dump`]. The layout is made for reading, and may change at
+/// any time. Don't parse it.
+pub struct PhysicsDump<'a>(&'a PhysicsManager);
+
+impl PhysicsManager {
+    /// Lists every body with its type, pose, velocity and sleep state, and every joint with
+    /// its bodies, kind, anchors and last applied impulse.
+    pub fn dump(&self) -> PhysicsDump<'_> {
+        PhysicsDump(self)
+    }
+
+    fn body_label(&self, handle: RigidBodyHandle) -> String {
+        let (index, _) = handle.into_raw_parts();
+        let object = self
+            .rigid_body_set
+            .get(handle)
+            .map(|body| GameObjectId::from_ffi(body.user_data as u64))
+            .filter(GameObjectId::exists);
+
+        match object {
+            Some(object) => format!("#{index} \"{}\"", object.name),
+            None => format!("#{index}"),
+        }
+    }
+}
+
+impl Display for PhysicsDump<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let physics = self.0;
+
+        writeln!(f, "Bodies ({}):", physics.rigid_body_set.len())?;
+        for (handle, body) in physics.rigid_body_set.iter() {
+            let kind = match body.body_type() {
+                RigidBodyType::Dynamic => "dynamic",
+                RigidBodyType::Fixed => "fixed",
+                RigidBodyType::KinematicPositionBased => "kinematic (position)",
+                RigidBodyType::KinematicVelocityBased => "kinematic (velocity)",
+            };
+            let rotation = body.position().rotation.euler_vector_deg();
+
+            writeln!(f, "  {} {kind}", physics.body_label(handle))?;
+            writeln!(f, "    position: {}", vec3(body.translation()))?;
+            writeln!(f, "    rotation: {} deg", vec3(&rotation))?;
+            writeln!(f, "    linvel:   {}", vec3(body.linvel()))?;
+            writeln!(f, "    angvel:   {}", vec3(body.angvel()))?;
+            writeln!(
+                f,
+                "    sleeping: {}, enabled: {}, colliders: {}",
+                body.is_sleeping(),
+                body.is_enabled(),
+                body.colliders().len()
+            )?;
+        }
+
+        writeln!(f, "Joints ({}):", physics.impulse_joint_set.len())?;
+        for (handle, joint) in physics.impulse_joint_set.iter() {
+            let (index, _) = handle.into_raw_parts();
+            let data = &joint.data;
+            let impulse = joint.impulses;
+
+            writeln!(
+                f,
+                "  #{index} {}: {} <-> {}",
+                joint_kind(data.locked_axes),
+                physics.body_label(joint.body1),
+                physics.body_label(joint.body2),
+            )?;
+            writeln!(f, "    anchor1:  {}", point3(&data.local_anchor1()))?;
+            writeln!(f, "    anchor2:  {}", point3(&data.local_anchor2()))?;
+            writeln!(
+                f,
+                "    impulse:  {}",
+                vec3(&impulse.fixed_rows::<3>(0).into())
+            )?;
+            writeln!(
+                f,
+                "    angular:  {}",
+                vec3(&impulse.fixed_rows::<3>(3).into())
+            )?;
+            writeln!(
+                f,
+                "    enabled: {}, contacts: {}",
+                data.is_enabled(),
+                data.contacts_enabled
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn joint_kind(locked: JointAxesMask) -> String {
+    match locked {
+        JointAxesMask::LOCKED_FIXED_AXES => "fixed".to_string(),
+        JointAxesMask::LOCKED_REVOLUTE_AXES => "revolute".to_string(),
+        JointAxesMask::LOCKED_PRISMATIC_AXES => "prismatic".to_string(),
+        JointAxesMask::LOCKED_SPHERICAL_AXES => "spherical".to_string(),
+        other => format!("generic {other:?}"),
+    }
+}
+
+fn vec3(v: &Vector3<f32>) -> String {
+    format!("({:.4}, {:.4}, {:.4})", v.x, v.y, v.z)
+}
+
+fn point3(p: &Point3<f32>) -> String {
+    vec3(&p.coords)
+}
@@ -0,0 +1,126 @@
+//! Detecting hard hits between colliders, from the impulses the solver applied to their
+//! contacts.
+//!
+//! Once a threshold is set, every pair of colliders whose contact force rises above it reports
+//! a [`HardImpact`], which also reaches [`World::on_world_event`](crate::World::on_world_event)
+//! as [`WorldEvent::HardImpact`](crate::core::WorldEvent::HardImpact). A pair reports again
+//! only after its force dropped below the threshold in between, so a crate resting on the
+//! floor under its own weight doesn't keep firing.
+//!
+//! ```rust
+//! use syrillian::World;
+//! use syrillian::core::WorldEvent;
+//!
+//! let mut world = World::builder().build();
+//! // a 1 kg object resting on the ground pushes with about 10 N
+//! world.physics.set_hard_impact_threshold(Some(200.0));
+//!
+//! world.on_world_event(|_world, event| {
+//!     if let WorldEvent::HardImpact { a, b, force } = event {
+//!         println!("{} hit {} with {force} N", a.name, b.name);
+//!     }
+//! });
+//! ```
+
+use crate::physics::PhysicsManager;
+use rapier3d::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Two colliders hit each other harder than the threshold
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HardImpact {
+    pub collider1: ColliderHandle,
+    pub collider2: ColliderHandle,
+    /// The total normal force between both colliders over the step, in Newtons
+    pub force: f32,
+}
+
+/// Contact impulses of the running step, and the pairs above the threshold after the last one
+#[derive(Debug, Default, Clone)]
+pub struct ImpactState {
+    threshold: Option<f32>,
+    /// Summed over all substeps of the running step
+    impulses: HashMap<(ColliderHandle, ColliderHandle), f32>,
+    hard: HashSet<(ColliderHandle, ColliderHandle)>,
+    events: Vec<HardImpact>,
+}
+
+impl PhysicsManager {
+    /// The contact force above which colliders report a [`HardImpact`], or `None` to not
+    /// track impacts at all, which is the default.
+    pub fn set_hard_impact_threshold(&mut self, threshold: Option<f32>) {
+        self.impacts.threshold = threshold;
+        if threshold.is_none() {
+            self.impacts.hard.clear();
+        }
+    }
+
+    pub fn hard_impact_threshold(&self) -> Option<f32> {
+        self.impacts.threshold
+    }
+
+    /// Hard impacts from every step since the start of this frame's
+    /// [`World::fixed_update`](crate::World::fixed_update)
+    pub fn hard_impacts(&self) -> &[HardImpact] {
+        &self.impacts.events
+    }
+
+    pub(crate) fn clear_hard_impacts(&mut self) {
+        self.impacts.events.clear();
+    }
+
+    /// Adds the contact impulses of the substep that just ran
+    pub(crate) fn accumulate_impacts(&mut self) {
+        if self.impacts.threshold.is_none() {
+            return;
+        }
+
+        for pair in self.narrow_phase.contact_pairs() {
+            if !pair.has_any_active_contact {
+                continue;
+            }
+            let impulse = pair.total_impulse_magnitude();
+            if impulse > 0.0 {
+                *self
+                    .impacts
+                    .impulses
+                    .entry((pair.collider1, pair.collider2))
+                    .or_default() += impulse;
+            }
+        }
+    }
+
+    /// Turns the impulses of the whole step into forces and reports the pairs that just got
+    /// above the threshold
+    pub(crate) fn update_impacts(&mut self, step_duration: f32) {
+        let Some(threshold) = self.impacts.threshold else {
+            return;
+        };
+
+        let impacts = &mut self.impacts;
+        let first_new = impacts.events.len();
+        let mut hard = HashSet::new();
+        for ((collider1, collider2), impulse) in impacts.impulses.drain() {
+            let force = impulse / step_duration;
+            if force <= threshold {
+                continue;
+            }
+            if !impacts.hard.contains(&(collider1, collider2)) {
+                impacts.events.push(HardImpact {
+                    collider1,
+                    collider2,
+                    force,
+                });
+            }
+            hard.insert((collider1, collider2));
+        }
+        impacts.hard = hard;
+        // the map has no order, but the simulation is supposed to be deterministic
+        impacts.events[first_new..].sort_by_key(|impact| {
+            (
+                impact.collider1.into_raw_parts(),
+                impact.collider2.into_raw_parts(),
+            )
+        });
+    }
+}
@@ -0,0 +1,168 @@
+//! Named physics layers, for picking which colliders a scene query can hit.
+//!
+//! Every collider is in exactly one layer. Colliders start in the `"default"` layer, and up to
+//! 31 more layers can be registered by name.
+//!
+//! ```rust
+//! use nalgebra::{Point3, Vector3};
+//! use rapier3d::prelude::Ray;
+//! use syrillian::World;
+//! use syrillian::components::Collider3D;
+//! use syrillian::physics::{LayerMask, QueryFilterBuilder};
+//!
+//! let mut world = World::builder().build();
+//! let mut pickup = world.new_object("Pickup");
+//! pickup.add_component::<Collider3D>().set_layer("trigger");
+//! world.add_child(pickup);
+//! world.physics.step();
+//!
+//! let ray = Ray::new(Point3::new(0.0, 3.0, 0.0), -Vector3::y());
+//! let hit = world.raycast(&ray, 10.0, &LayerMask::all(), QueryFilterBuilder::new());
+//! assert_eq!(hit.map(|(_, obj)| obj), Some(pickup));
+//!
+//! // a ground check that doesn't trip over pickups
+//! let ground = LayerMask::all().without(["trigger", "debris"]);
+//! let hit = world.raycast(&ray, 10.0, &ground, QueryFilterBuilder::new());
+//! assert!(hit.is_none());
+//! ```
+
+use crate::physics::PhysicsManager;
+use rapier3d::prelude::{ColliderHandle, Group};
+use tracing::warn;
+
+/// The layer every collider starts in
+pub const DEFAULT_LAYER: &str = "default";
+
+const MAX_LAYERS: usize = 32;
+
+/// The names of the registered layers, a layer's index is its bit in a [`Group`]
+#[derive(Debug, Clone)]
+pub struct LayerRegistry {
+    names: Vec<String>,
+}
+
+impl Default for LayerRegistry {
+    fn default() -> Self {
+        Self {
+            names: vec![DEFAULT_LAYER.to_string()],
+        }
+    }
+}
+
+/// A set of layers by name, resolved against the registered layers when it's used.
+///
+/// Names that aren't registered are skipped. No collider can be in them anyway.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayerMask {
+    only: Option<Vec<String>>,
+    without: Vec<String>,
+}
+
+impl Default for LayerMask {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl LayerMask {
+    /// Every layer
+    pub fn all() -> Self {
+        Self {
+            only: None,
+            without: Vec::new(),
+        }
+    }
+
+    /// Only the given layers
+    pub fn only<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            only: Some(names.into_iter().map(Into::into).collect()),
+            without: Vec::new(),
+        }
+    }
+
+    /// Removes layers from the set, taking precedence over [`LayerMask::only`]
+    pub fn without<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.without.extend(names.into_iter().map(Into::into));
+        self
+    }
+}
+
+impl PhysicsManager {
+    /// Adds a layer, or returns the existing one with this name. Returns `None` once all 32
+    /// layers are taken.
+    pub fn register_layer(&mut self, name: &str) -> Option<Group> {
+        if let Some(layer) = self.layer(name) {
+            return Some(layer);
+        }
+        if self.layers.names.len() >= MAX_LAYERS {
+            warn!("[Physics] Can't register layer {name:?}, all {MAX_LAYERS} layers are taken");
+            return None;
+        }
+
+        self.layers.names.push(name.to_string());
+        Some(Group::from_bits_retain(1 << (self.layers.names.len() - 1)))
+    }
+
+    /// The group bit of a registered layer
+    pub fn layer(&self, name: &str) -> Option<Group> {
+        let index = self.layers.names.iter().position(|n| n == name)?;
+        Some(Group::from_bits_retain(1 << index))
+    }
+
+    /// The names of all registered layers, starting with [`DEFAULT_LAYER`]
+    pub fn layer_names(&self) -> impl Iterator<Item = &str> {
+        self.layers.names.iter().map(String::as_str)
+    }
+
+    /// The groups of the layers in a mask
+    pub fn resolve_layers(&self, mask: &LayerMask) -> Group {
+        let mut groups = match &mask.only {
+            None => Group::ALL,
+            Some(names) => names
+                .iter()
+                .filter_map(|name| self.layer(name))
+                .fold(Group::NONE, |groups, layer| groups | layer),
+        };
+        for layer in mask.without.iter().filter_map(|name| self.layer(name)) {
+            groups.remove(layer);
+        }
+        groups
+    }
+
+    /// Moves a collider into a layer, registering the layer if it doesn't exist yet. Returns
+    /// `false` if there's no such collider, or no layer is left.
+    ///
+    /// Only the layer membership changes, which colliders it collides with stays the same.
+    pub fn set_collider_layer(&mut self, collider: ColliderHandle, name: &str) -> bool {
+        let Some(layer) = self.register_layer(name) else {
+            return false;
+        };
+        let Some(collider) = self.collider_set.get_mut(collider) else {
+            return false;
+        };
+
+        let groups = collider.collision_groups().with_memberships(layer);
+        collider.set_collision_groups(groups);
+        true
+    }
+
+    /// The name of the layer a collider is in
+    pub fn collider_layer(&self, collider: ColliderHandle) -> Option<&str> {
+        let memberships = self
+            .collider_set
+            .get(collider)?
+            .collision_groups()
+            .memberships;
+        let index = memberships.bits().trailing_zeros() as usize;
+        self.layers.names.get(index).map(String::as_str)
+    }
+}
@@ -0,0 +1,109 @@
+//! Named friction and restitution presets shared between colliders.
+//!
+//! ```rust
+//! use syrillian::World;
+//! use syrillian::components::Collider3D;
+//! use syrillian::physics::PhysicsMaterial;
+//!
+//! let mut world = World::builder().build();
+//! world.physics.register_material("ice", PhysicsMaterial::new(0.02, 0.0));
+//!
+//! let mut rink = world.new_object("Rink");
+//! let mut collider = rink.add_component::<Collider3D>();
+//! assert!(collider.set_material("ice"));
+//!
+//! // make all ice slicker at once
+//! world.physics.update_material("ice", PhysicsMaterial::new(0.01, 0.0));
+//! assert_eq!(collider.collider().unwrap().friction(), 0.01);
+//! ```
+
+use crate::physics::PhysicsManager;
+use rapier3d::prelude::ColliderHandle;
+use std::collections::HashMap;
+
+/// Surface properties of a collider
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PhysicsMaterial {
+    pub friction: f32,
+    pub restitution: f32,
+}
+
+impl PhysicsMaterial {
+    pub const fn new(friction: f32, restitution: f32) -> Self {
+        Self {
+            friction,
+            restitution,
+        }
+    }
+}
+
+/// Registered presets, and which collider uses which one
+#[derive(Debug, Default, Clone)]
+pub struct MaterialRegistry {
+    presets: HashMap<String, PhysicsMaterial>,
+    users: HashMap<ColliderHandle, String>,
+}
+
+impl PhysicsManager {
+    /// Adds a preset, or replaces the one with the same name.
+    ///
+    /// Colliders that already use a replaced preset keep their current values. Use
+    /// [`PhysicsManager::update_material`] to change them too.
+    pub fn register_material(&mut self, name: impl Into<String>, material: PhysicsMaterial) {
+        self.materials.presets.insert(name.into(), material);
+    }
+
+    /// Replaces a preset and applies it to every collider using it. Returns how many
+    /// colliders were changed.
+    ///
+    /// Like [`PhysicsManager::register_material`], the preset is added if it doesn't exist.
+    pub fn update_material(&mut self, name: &str, material: PhysicsMaterial) -> usize {
+        self.register_material(name, material);
+
+        let mut updated = 0;
+        for (&handle, used) in &self.materials.users {
+            if used == name
+                && let Some(collider) = self.collider_set.get_mut(handle)
+            {
+                collider.set_friction(material.friction);
+                collider.set_restitution(material.restitution);
+                updated += 1;
+            }
+        }
+        updated
+    }
+
+    pub fn material(&self, name: &str) -> Option<PhysicsMaterial> {
+        self.materials.presets.get(name).copied()
+    }
+
+    /// Sets the friction and restitution of a collider from a preset, and remembers it for
+    /// [`PhysicsManager::update_material`]. Returns `false` if there's no such preset or
+    /// collider.
+    ///
+    /// Changing the friction or restitution of the collider directly afterward works, but is
+    /// overwritten by the next update of the preset.
+    pub fn apply_material(&mut self, collider: ColliderHandle, name: &str) -> bool {
+        let Some(material) = self.material(name) else {
+            return false;
+        };
+        let Some(collider_data) = self.collider_set.get_mut(collider) else {
+            return false;
+        };
+
+        collider_data.set_friction(material.friction);
+        collider_data.set_restitution(material.restitution);
+        self.materials.users.insert(collider, name.to_string());
+        true
+    }
+
+    /// The name of the preset a collider uses, if any
+    pub fn collider_material(&self, collider: ColliderHandle) -> Option<&str> {
+        self.materials.users.get(&collider).map(String::as_str)
+    }
+
+    /// Stops tracking a collider, keeping its current friction and restitution
+    pub fn clear_collider_material(&mut self, collider: ColliderHandle) {
+        self.materials.users.remove(&collider);
+    }
+}
@@ -3,6 +3,30 @@
 //! The [`PhysicsManager`] struct manages rigid bodies / joints, etc.
 //! and executes physics steps each frame.
 
+pub mod broad_phase;
+pub mod contact;
+pub mod drag;
+pub mod dump;
+pub mod impact;
+pub mod layer;
+pub mod material;
+pub mod profile;
+pub mod query_filter;
 pub mod simulator;
+pub mod snapshot;
+pub mod stiff_joints;
+pub mod trigger;
+pub(crate) mod validation;
 
+pub use broad_phase::*;
+pub use contact::*;
+pub use drag::*;
+pub use dump::*;
+pub use impact::*;
+pub use layer::*;
+pub use material::*;
+pub use profile::*;
+pub use query_filter::*;
 pub use simulator::*;
+pub use snapshot::*;
+pub use trigger::*;
@@ -0,0 +1,63 @@
+//! Timings of the physics step, to find out which stage a slow step spends its time in.
+//!
+//! Profiling is off by default and costs nothing then. Turn it on with
+//! [`World::set_physics_profiling`](crate::World::set_physics_profiling) and read the last
+//! tick with [`World::physics_profile`](crate::World::physics_profile).
+//!
+//! Rapier only measures time when syrillian is built with the `physics-profiler` feature.
+//! Without it, all durations stay zero, but the contact and body counts still work.
+
+use rapier3d::counters::Counters;
+use rapier3d::prelude::{IslandManager, NarrowPhase};
+use std::time::Duration;
+
+/// Where the last physics tick spent its time, summed over all substeps
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct PhysicsProfile {
+    /// The whole step, including the stages below
+    pub step: Duration,
+    /// Finding pairs of colliders whose bounding boxes overlap
+    pub broad_phase: Duration,
+    /// Computing the contacts of those pairs
+    pub narrow_phase: Duration,
+    /// Grouping bodies into islands, and putting resting ones to sleep
+    pub island_construction: Duration,
+    /// Resolving contacts and joints, scales with the solver iterations
+    pub solver: Duration,
+    /// Continuous collision detection of fast bodies with CCD enabled
+    pub ccd: Duration,
+    /// Collider pairs that touch, after the last substep
+    pub contact_pairs: usize,
+    /// Contact points between all touching pairs, after the last substep
+    pub contacts: usize,
+    /// Bodies that are awake and get simulated, after the last substep
+    pub active_bodies: usize,
+    /// How many pipeline steps the tick was split into
+    pub substeps: usize,
+}
+
+impl PhysicsProfile {
+    /// Adds the counters of one pipeline step
+    pub(crate) fn accumulate(&mut self, counters: &Counters) {
+        self.step += counters.step_time.time();
+        self.broad_phase += counters.cd.broad_phase_time.time();
+        self.narrow_phase += counters.cd.narrow_phase_time.time();
+        self.island_construction += counters.stages.island_construction_time.time();
+        self.solver += counters.stages.solver_time.time();
+        self.ccd += counters.stages.ccd_time.time();
+        self.substeps += 1;
+    }
+
+    /// Counts what the simulation contains right now
+    pub(crate) fn count(&mut self, narrow_phase: &NarrowPhase, island_manager: &IslandManager) {
+        self.contact_pairs = 0;
+        self.contacts = 0;
+        for pair in narrow_phase.contact_pairs() {
+            if pair.has_any_active_contact {
+                self.contact_pairs += 1;
+                self.contacts += pair.manifolds.iter().map(|m| m.points.len()).sum::<usize>();
+            }
+        }
+        self.active_bodies = island_manager.active_bodies().len();
+    }
+}
@@ -0,0 +1,133 @@
+//! Readable construction of rapier [`QueryFilter`]s for the scene query functions.
+
+use crate::components::RigidBodyComponent;
+use crate::core::GameObjectId;
+use rapier3d::prelude::*;
+
+type ColliderPredicate = dyn Fn(ColliderHandle, &Collider) -> bool;
+
+/// Builds the [`QueryFilter`] taken by raycasts, shape casts and other scene queries.
+///
+/// ```rust
+/// use syrillian::World;
+/// use syrillian::physics::QueryFilterBuilder;
+/// use rapier3d::prelude::Ray;
+/// use nalgebra::{Point3, Vector3};
+///
+/// let mut world = World::builder().build();
+/// let player = world.new_object("Player");
+///
+/// let ray = Ray::new(Point3::origin(), -Vector3::y());
+/// let filter = QueryFilterBuilder::new().exclude(player).exclude_sensors();
+/// let hit = world.physics.cast_ray(&ray, 2.0, true, filter.build());
+/// assert!(hit.is_none());
+/// ```
+#[derive(Default)]
+pub struct QueryFilterBuilder {
+    flags: QueryFilterFlags,
+    groups: Option<InteractionGroups>,
+    excluded_objects: Vec<u128>,
+    excluded_bodies: Vec<RigidBodyHandle>,
+    predicate: Option<Box<ColliderPredicate>>,
+}
+
+impl QueryFilterBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ignore all colliders of this object, and all colliders attached to its rigid body.
+    ///
+    /// Can be called multiple times to exclude several objects.
+    pub fn exclude(mut self, object: GameObjectId) -> Self {
+        self.push_excluded(object);
+        self.update_predicate();
+        self
+    }
+
+    /// Ignore all colliders of these objects, and all colliders attached to their rigid
+    /// bodies, like a caster and its minions. Adds to objects that were already excluded.
+    pub fn exclude_many(mut self, objects: &[GameObjectId]) -> Self {
+        for &object in objects {
+            self.push_excluded(object);
+        }
+        self.update_predicate();
+        self
+    }
+
+    fn push_excluded(&mut self, object: GameObjectId) {
+        self.excluded_objects.push(object.as_ffi() as u128);
+        if let Some(body) = object.get_component::<RigidBodyComponent>() {
+            self.excluded_bodies.push(body.body_handle);
+        }
+    }
+
+    fn update_predicate(&mut self) {
+        let objects = self.excluded_objects.clone();
+        let bodies = self.excluded_bodies.clone();
+        self.predicate = Some(Box::new(move |_, collider| {
+            !objects.contains(&collider.user_data)
+                && collider.parent().is_none_or(|body| !bodies.contains(&body))
+        }));
+    }
+
+    /// Only hit colliders attached to dynamic rigid bodies
+    pub fn only_dynamic(mut self) -> Self {
+        self.flags |= QueryFilterFlags::ONLY_DYNAMIC;
+        self
+    }
+
+    /// Only hit colliders attached to kinematic rigid bodies
+    pub fn only_kinematic(mut self) -> Self {
+        self.flags |= QueryFilterFlags::ONLY_KINEMATIC;
+        self
+    }
+
+    /// Only hit colliders that are fixed, or don't have a rigid body at all
+    pub fn only_fixed(mut self) -> Self {
+        self.flags |= QueryFilterFlags::ONLY_FIXED;
+        self
+    }
+
+    /// Don't hit colliders that are fixed, or don't have a rigid body at all
+    pub fn exclude_fixed(mut self) -> Self {
+        self.flags |= QueryFilterFlags::EXCLUDE_FIXED;
+        self
+    }
+
+    /// Only hit colliders whose collision groups interact with these groups
+    pub fn groups(mut self, groups: InteractionGroups) -> Self {
+        self.groups = Some(groups);
+        self
+    }
+
+    /// Only hit colliders in these layers, see [`PhysicsManager::resolve_layers`](crate::physics::PhysicsManager::resolve_layers).
+    ///
+    /// Replaces the groups set with [`QueryFilterBuilder::groups`].
+    pub fn layers(self, layers: Group) -> Self {
+        self.groups(InteractionGroups::all().with_filter(layers))
+    }
+
+    /// Don't hit sensor colliders
+    pub fn exclude_sensors(mut self) -> Self {
+        self.flags |= QueryFilterFlags::EXCLUDE_SENSORS;
+        self
+    }
+
+    /// Creates the rapier filter, which borrows the exclusion list of this builder
+    pub fn build(&self) -> QueryFilter<'_> {
+        QueryFilter {
+            flags: self.flags,
+            groups: self.groups,
+            exclude_collider: None,
+            exclude_rigid_body: None,
+            predicate: self.predicate.as_deref(),
+        }
+    }
+}
+
+impl<'a> From<&'a QueryFilterBuilder> for QueryFilter<'a> {
+    fn from(builder: &'a QueryFilterBuilder) -> Self {
+        builder.build()
+    }
+}
@@ -1,7 +1,9 @@
 use crate::World;
 use crate::core::GameObjectId;
+use crate::physics::stiff_joints::StiffJoints;
+use crate::physics::{ImpactState, LayerRegistry, MaterialRegistry, PhysicsProfile, TriggerState};
 use nalgebra::Vector3;
-use rapier3d::parry::query::{DefaultQueryDispatcher, ShapeCastOptions};
+use rapier3d::parry::query::{self, DefaultQueryDispatcher, ShapeCastOptions};
 use rapier3d::prelude::*;
 use web_time::{Duration, Instant};
 
@@ -22,10 +24,44 @@ pub struct PhysicsManager {
     pub last_update: Instant,
     pub timestep: Duration,
     pub alpha: f32,
+    /// Step exactly once per [`World::fixed_update`] instead of catching up with the wall
+    /// clock, so the same inputs always lead to the same simulation.
+    ///
+    /// Bit-exact results across platforms additionally require rapier's
+    /// `enhanced-determinism` feature.
+    pub deterministic: bool,
+    /// Amount of integration steps each [`PhysicsManager::step`] is split into, see
+    /// [`World::set_physics_substeps`]
+    pub substeps: usize,
+    /// The playable area. Moving bodies that leave it are disabled after the step, so they
+    /// don't keep getting simulated and stretch the broad-phase. Default: unbounded
+    ///
+    /// Only the position of the body is checked, and only while it's awake. To bring a body
+    /// back, move it inside and enable it again through
+    /// [`RigidBodyComponent::body_mut`](crate::components::RigidBodyComponent::body_mut).
+    pub world_bounds: Option<Aabb>,
+    pub(crate) triggers: TriggerState,
+    pub(crate) impacts: ImpactState,
+    pub(crate) stiff_joints: StiffJoints,
+    pub(crate) materials: MaterialRegistry,
+    pub(crate) layers: LayerRegistry,
+    pub(crate) profile: PhysicsProfile,
 }
 
 const EARTH_GRAVITY: f32 = 9.81;
 
+/// How often [`PhysicsManager::depenetration`] resolves the deepest overlap at most
+pub const MAX_DEPENETRATION_PASSES: usize = 8;
+/// Overlaps that [`PhysicsManager::depenetration`] ignores, so touching shapes aren't pushed
+pub const DEPENETRATION_TOLERANCE: f32 = 1e-4;
+
+/// Rapier counts by default, which is only wanted once profiling is turned on
+fn unprofiled_pipeline() -> PhysicsPipeline {
+    let mut pipeline = PhysicsPipeline::default();
+    pipeline.counters.disable();
+    pipeline
+}
+
 impl Default for PhysicsManager {
     fn default() -> Self {
         PhysicsManager {
@@ -33,7 +69,7 @@ impl Default for PhysicsManager {
             rigid_body_set: RigidBodySet::default(),
             collider_set: ColliderSet::default(),
             integration_parameters: IntegrationParameters::default(),
-            physics_pipeline: PhysicsPipeline::default(),
+            physics_pipeline: unprofiled_pipeline(),
             island_manager: IslandManager::default(),
             broad_phase: DefaultBroadPhase::default(),
             narrow_phase: NarrowPhase::default(),
@@ -45,12 +81,83 @@ impl Default for PhysicsManager {
             last_update: Instant::now(),
             timestep: Duration::from_secs_f64(1.0 / 60.0),
             alpha: 0.0,
+            deterministic: false,
+            substeps: 1,
+            world_bounds: None,
+            triggers: TriggerState::default(),
+            impacts: ImpactState::default(),
+            stiff_joints: StiffJoints::default(),
+            materials: MaterialRegistry::default(),
+            layers: LayerRegistry::default(),
+            profile: PhysicsProfile::default(),
         }
     }
 }
 
 impl PhysicsManager {
+    /// Copies the whole simulation state, for stepping ahead without changing this one.
+    ///
+    /// Bodies and colliders keep their handles and the objects they belong to, so queries on
+    /// the copy report the same objects. The copy steps deterministically.
+    pub fn fork(&self) -> PhysicsManager {
+        PhysicsManager {
+            gravity: self.gravity,
+            rigid_body_set: self.rigid_body_set.clone(),
+            collider_set: self.collider_set.clone(),
+            integration_parameters: self.integration_parameters,
+            physics_pipeline: unprofiled_pipeline(),
+            island_manager: self.island_manager.clone(),
+            broad_phase: self.broad_phase.clone(),
+            narrow_phase: self.narrow_phase.clone(),
+            impulse_joint_set: self.impulse_joint_set.clone(),
+            multibody_joint_set: self.multibody_joint_set.clone(),
+            ccd_solver: self.ccd_solver.clone(),
+            physics_hooks: (),
+            event_handler: (),
+            last_update: self.last_update,
+            timestep: self.timestep,
+            alpha: self.alpha,
+            deterministic: true,
+            substeps: self.substeps,
+            world_bounds: self.world_bounds,
+            triggers: self.triggers.clone(),
+            impacts: self.impacts.clone(),
+            stiff_joints: self.stiff_joints.clone(),
+            materials: self.materials.clone(),
+            layers: self.layers.clone(),
+            profile: PhysicsProfile::default(),
+        }
+    }
+
+    /// Advances the simulation by one timestep, divided into [`PhysicsManager::substeps`]
+    /// smaller steps.
     pub fn step(&mut self) {
+        self.profile = PhysicsProfile::default();
+        let substeps = self.substeps.max(1);
+        let dt = self.integration_parameters.dt;
+        let iterations = self.integration_parameters.num_solver_iterations;
+        self.integration_parameters.num_solver_iterations = self.solver_iterations_for_step();
+        if substeps == 1 {
+            self.step_once();
+        } else {
+            self.integration_parameters.dt = dt / substeps as f32;
+            for _ in 0..substeps {
+                self.step_once();
+            }
+            self.integration_parameters.dt = dt;
+        }
+        self.integration_parameters.num_solver_iterations = iterations;
+        self.update_impacts(dt);
+    }
+
+    fn step_once(&mut self) {
+        self.step_pipeline();
+        self.accumulate_impacts();
+        self.disable_out_of_bounds();
+        self.update_triggers();
+    }
+
+    fn step_pipeline(&mut self) {
         self.physics_pipeline.step(
             &self.gravity,
             &self.integration_parameters,
@@ -65,6 +172,11 @@ impl PhysicsManager {
             &(), // no hooks yet
             &(), // no events yet
         );
+
+        if self.physics_pipeline.counters.enabled() {
+            self.profile.accumulate(&self.physics_pipeline.counters);
+            self.profile.count(&self.narrow_phase, &self.island_manager);
+        }
     }
 
     pub fn cast_ray(
@@ -82,10 +194,33 @@ impl PhysicsManager {
         );
         let (collider, distance) = qp.cast_ray(ray, max_toi, solid)?;
 
-        let object_id = self.collider_set.get(collider)?.user_data as u64;
-        let object = GameObjectId::from_ffi(object_id);
+        Some((distance, self.collider_object(collider)?))
+    }
+
+    /// Casts many rays with the same parameters at once. The query pipeline is only set up a
+    /// single time, which is a lot cheaper than calling [`PhysicsManager::cast_ray`] in a loop.
+    ///
+    /// The results are in the same order as the given rays.
+    pub fn cast_ray_batch(
+        &self,
+        rays: &[Ray],
+        max_toi: f32,
+        solid: bool,
+        filter: QueryFilter,
+    ) -> Vec<Option<(f32, GameObjectId)>> {
+        let qp = self.broad_phase.as_query_pipeline(
+            &DefaultQueryDispatcher,
+            &self.rigid_body_set,
+            &self.collider_set,
+            filter,
+        );
 
-        object.exists().then_some((distance, object))
+        rays.iter()
+            .map(|ray| {
+                let (collider, distance) = qp.cast_ray(ray, max_toi, solid)?;
+                Some((distance, self.collider_object(collider)?))
+            })
+            .collect()
     }
 
     pub fn cast_sphere(
@@ -106,10 +241,81 @@ impl PhysicsManager {
         let options = ShapeCastOptions::with_max_time_of_impact(max_toi);
         let (collider, hit) = qp.cast_shape(shape_pos, dir, &shape, options)?;
 
+        Some((hit, self.collider_object(collider)?))
+    }
+
+    /// Returns the objects whose colliders intersect `shape` placed at `shape_pos`. Each
+    /// object is listed once, even if several of its colliders overlap.
+    pub fn intersect_shape(
+        &self,
+        shape: &dyn Shape,
+        shape_pos: &Isometry<f32>,
+        filter: QueryFilter,
+    ) -> Vec<GameObjectId> {
+        let qp = self.broad_phase.as_query_pipeline(
+            &DefaultQueryDispatcher,
+            &self.rigid_body_set,
+            &self.collider_set,
+            filter,
+        );
+
+        let mut objects = Vec::new();
+        for (collider, _) in qp.intersect_shape(*shape_pos, shape) {
+            if let Some(object) = self.collider_object(collider)
+                && !objects.contains(&object)
+            {
+                objects.push(object);
+            }
+        }
+        objects
+    }
+
+    /// The shortest translation that moves `shape` placed at `shape_pos` out of all colliders
+    /// it overlaps. Zero if it doesn't overlap anything.
+    ///
+    /// Each pass resolves the deepest overlap, for up to
+    /// [`MAX_DEPENETRATION_PASSES`] passes, so a shape wedged into a corner is pushed out of
+    /// both walls. Overlaps shallower than [`DEPENETRATION_TOLERANCE`] are left alone.
+    pub fn depenetration(
+        &self,
+        shape: &dyn Shape,
+        shape_pos: &Isometry<f32>,
+        filter: QueryFilter,
+    ) -> Vector3<f32> {
+        let qp = self.broad_phase.as_query_pipeline(
+            &DefaultQueryDispatcher,
+            &self.rigid_body_set,
+            &self.collider_set,
+            filter,
+        );
+
+        let mut offset = Vector3::zeros();
+        for _ in 0..MAX_DEPENETRATION_PASSES {
+            let pose = Translation::from(offset) * shape_pos;
+            let deepest = qp
+                .intersect_shape(pose, shape)
+                .filter_map(|(_, other)| {
+                    query::contact(&pose, shape, other.position(), other.shape(), 0.0).ok()?
+                })
+                .min_by(|a, b| a.dist.total_cmp(&b.dist));
+
+            match deepest {
+                Some(contact) if contact.dist < -DEPENETRATION_TOLERANCE => {
+                    // the normal points toward the other collider, and the distance is negative
+                    offset += contact.normal1.into_inner() * contact.dist;
+                }
+                _ => break,
+            }
+        }
+        offset
+    }
+
+    /// Returns the game object a collider belongs to, if it still exists
+    pub fn collider_object(&self, collider: ColliderHandle) -> Option<GameObjectId> {
         let object_id = self.collider_set.get(collider)?.user_data as u64;
         let object = GameObjectId::from_ffi(object_id);
 
-        object.exists().then_some((hit, object))
+        object.exists().then_some(object)
     }
 
     pub fn cursor_ray(&self, world: &World) -> Option<Ray> {
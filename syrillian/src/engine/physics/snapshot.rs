@@ -0,0 +1,168 @@
+//! Captured rigid body states, and the deltas between them for sending over the network.
+//!
+//! Bodies are keyed by the persistent [`GameObject::uuid`](crate::core::GameObject::uuid) of
+//! their object, so snapshots taken on one machine can be applied to the matching objects on
+//! another.
+
+use crate::World;
+use crate::components::RigidBodyComponent;
+use crate::core::GameObjectId;
+use nalgebra::{UnitQuaternion, Vector3};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Pose and velocity of a single rigid body
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BodySnapshot {
+    pub position: Vector3<f32>,
+    pub rotation: UnitQuaternion<f32>,
+    pub linvel: Vector3<f32>,
+    pub angvel: Vector3<f32>,
+}
+
+/// The state of one body on its own, see [`RigidBodyComponent::capture_state`]
+pub type BodyState = BodySnapshot;
+
+impl BodySnapshot {
+    /// Whether any part of the state differs by more than `threshold`. Positions and
+    /// velocities are compared by distance, and rotations by angle in radians.
+    pub fn differs_from(&self, other: &BodySnapshot, threshold: f32) -> bool {
+        (self.position - other.position).norm() > threshold
+            || self.rotation.angle_to(&other.rotation) > threshold
+            || (self.linvel - other.linvel).norm() > threshold
+            || (self.angvel - other.angvel).norm() > threshold
+    }
+}
+
+/// The state of all rigid bodies in a world at one point in time.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PhysicsSnapshot {
+    bodies: HashMap<Uuid, BodySnapshot>,
+}
+
+/// The changes needed to turn one [`PhysicsSnapshot`] into another.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SnapshotDelta {
+    /// Bodies in both snapshots whose state changed beyond the threshold
+    pub changed: Vec<(Uuid, BodySnapshot)>,
+    /// Bodies that only exist in the newer snapshot, sent regardless of the threshold
+    pub added: Vec<(Uuid, BodySnapshot)>,
+    /// Bodies that only exist in the older snapshot
+    pub removed: Vec<Uuid>,
+}
+
+impl SnapshotDelta {
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty() && self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl PhysicsSnapshot {
+    /// Captures every rigid body whose object is still alive
+    pub fn capture(world: &World) -> Self {
+        let bodies = world
+            .physics
+            .rigid_body_set
+            .iter()
+            .filter_map(|(_, body)| {
+                let object = GameObjectId::from_ffi(body.user_data as u64);
+                let object = world.get_object(object).filter(|o| o.is_alive())?;
+                let state = BodySnapshot {
+                    position: *body.translation(),
+                    rotation: *body.rotation(),
+                    linvel: *body.linvel(),
+                    angvel: *body.angvel(),
+                };
+                Some((object.uuid(), state))
+            })
+            .collect();
+
+        Self { bodies }
+    }
+
+    pub fn get(&self, uuid: Uuid) -> Option<&BodySnapshot> {
+        self.bodies.get(&uuid)
+    }
+
+    pub fn bodies(&self) -> impl Iterator<Item = (Uuid, &BodySnapshot)> {
+        self.bodies.iter().map(|(uuid, state)| (*uuid, state))
+    }
+
+    pub fn len(&self) -> usize {
+        self.bodies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bodies.is_empty()
+    }
+
+    /// Lists the bodies that differ in `newer`, down to the last bit. See
+    /// [`PhysicsSnapshot::diff_with_threshold`].
+    pub fn diff(&self, newer: &PhysicsSnapshot) -> SnapshotDelta {
+        self.diff_with_threshold(newer, 0.0)
+    }
+
+    /// Lists the bodies that were added or removed in `newer`, or changed by more than
+    /// `threshold` (see [`BodySnapshot::differs_from`]).
+    ///
+    /// To keep small movements from being dropped forever, diff against the last *sent*
+    /// state and apply each delta to it with [`PhysicsSnapshot::apply_delta`], like the
+    /// receiver does. A body that drifts slowly is then sent once the drift adds up to the
+    /// threshold.
+    pub fn diff_with_threshold(&self, newer: &PhysicsSnapshot, threshold: f32) -> SnapshotDelta {
+        let mut delta = SnapshotDelta::default();
+
+        for (&uuid, state) in &newer.bodies {
+            match self.bodies.get(&uuid) {
+                Some(old) if old.differs_from(state, threshold) => {
+                    delta.changed.push((uuid, *state))
+                }
+                Some(_) => {}
+                None => delta.added.push((uuid, *state)),
+            }
+        }
+        delta.removed = self
+            .bodies
+            .keys()
+            .filter(|uuid| !newer.bodies.contains_key(uuid))
+            .copied()
+            .collect();
+
+        delta
+    }
+
+    /// Updates this snapshot with a delta created by [`PhysicsSnapshot::diff`]
+    pub fn apply_delta(&mut self, delta: &SnapshotDelta) {
+        for uuid in &delta.removed {
+            self.bodies.remove(uuid);
+        }
+        for (uuid, state) in delta.changed.iter().chain(&delta.added) {
+            self.bodies.insert(*uuid, *state);
+        }
+    }
+
+    /// Moves the bodies of the world to their captured state, like
+    /// [`RigidBodyComponent::restore_state`] does for each of them.
+    ///
+    /// Bodies are matched by the uuid of their object. Objects that don't exist or have no
+    /// [`RigidBodyComponent`] are skipped, so spawning and deleting objects for added and
+    /// removed bodies is up to the caller. Returns how many bodies were restored.
+    pub fn restore(&self, world: &mut World) -> usize {
+        let mut restored = 0;
+        for (&uuid, state) in &self.bodies {
+            let Some(object) = world.find_by_uuid(uuid) else {
+                continue;
+            };
+            let Some(mut rb) = object.get_component::<RigidBodyComponent>() else {
+                continue;
+            };
+            if rb.body().is_none() {
+                continue;
+            }
+
+            rb.restore_state(state);
+            restored += 1;
+        }
+        restored
+    }
+}
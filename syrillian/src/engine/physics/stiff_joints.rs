@@ -0,0 +1,111 @@
+//! Extra solver iterations for joints that have to hold, like the links of a long chain.
+//!
+//! The solver doesn't satisfy all joints exactly in a step. Each iteration only gets closer, so
+//! a chain of many links carrying a load stretches and jitters. Joints are already as rigid as
+//! rapier can make them, so the only lever is solving more often.
+//!
+//! Rapier solves every island with the same amount of iterations, so a joint marked with
+//! [`JointComponent::set_stiff`](crate::components::JointComponent::set_stiff) raises the
+//! iterations of the whole step to [`PhysicsManager::stiff_joint_iterations`], but only while at
+//! least one such joint is connected and enabled. The rest of the time, steps run with the
+//! normal amount.
+//!
+//! A 20-link chain holding 20 kg stretches by about 1.8 at the default 4 iterations, and by
+//! about 0.09 at 16, where it also settles instead of bouncing.
+//!
+//! ```rust
+//! use syrillian::World;
+//! use syrillian::components::{ChainJointKind, JointChain, RigidBodyComponent, SphericalJoint};
+//!
+//! let mut world = World::builder().build();
+//! let mut crane = world.new_object("Crane");
+//! crane.add_component::<RigidBodyComponent>().set_kinematic(true);
+//! let mut hook = world.new_object("Hook");
+//! hook.transform.set_position(0.0, -10.0, 0.0);
+//! hook.add_component::<RigidBodyComponent>();
+//!
+//! let links = JointChain::build(&mut world, crane, hook, 20, ChainJointKind::Spherical).unwrap();
+//! for link in links {
+//!     for mut joint in link.iter_components::<SphericalJoint>() {
+//!         joint.set_stiff(true);
+//!     }
+//! }
+//! ```
+
+use crate::physics::PhysicsManager;
+use rapier3d::prelude::ImpulseJointHandle;
+use std::collections::HashSet;
+
+const DEFAULT_STIFF_ITERATIONS: usize = 16;
+
+#[derive(Debug, Clone)]
+pub(crate) struct StiffJoints {
+    joints: HashSet<ImpulseJointHandle>,
+    iterations: usize,
+    /// The solver iterations of the last step
+    stepped: usize,
+}
+
+impl Default for StiffJoints {
+    fn default() -> Self {
+        Self {
+            joints: HashSet::new(),
+            iterations: DEFAULT_STIFF_ITERATIONS,
+            stepped: 0,
+        }
+    }
+}
+
+impl PhysicsManager {
+    /// The solver iterations of steps while a stiff joint is connected. Default: 16
+    ///
+    /// Steps never run with fewer iterations than
+    /// [`IntegrationParameters::num_solver_iterations`](rapier3d::prelude::IntegrationParameters::num_solver_iterations).
+    pub fn set_stiff_joint_iterations(&mut self, iterations: usize) {
+        self.stiff_joints.iterations = iterations.max(1);
+    }
+
+    pub fn stiff_joint_iterations(&self) -> usize {
+        self.stiff_joints.iterations
+    }
+
+    /// Whether the joint with `handle` makes steps run with
+    /// [`PhysicsManager::stiff_joint_iterations`]
+    pub(crate) fn set_joint_stiff(&mut self, handle: ImpulseJointHandle, stiff: bool) {
+        if stiff {
+            self.stiff_joints.joints.insert(handle);
+        } else {
+            self.stiff_joints.joints.remove(&handle);
+        }
+    }
+
+    /// The solver iterations the last step ran with
+    pub(crate) fn stepped_solver_iterations(&self) -> usize {
+        match self.stiff_joints.stepped {
+            0 => self.integration_parameters.num_solver_iterations,
+            stepped => stepped,
+        }
+    }
+
+    /// The solver iterations for the next step. Forgets joints that were removed.
+    pub(crate) fn solver_iterations_for_step(&mut self) -> usize {
+        let joint_set = &self.impulse_joint_set;
+        self.stiff_joints
+            .joints
+            .retain(|handle| joint_set.contains(*handle));
+
+        let base = self.integration_parameters.num_solver_iterations;
+        let any_enabled = self.stiff_joints.joints.iter().any(|handle| {
+            joint_set
+                .get(*handle)
+                .is_some_and(|joint| joint.data.is_enabled())
+        });
+        let iterations = if any_enabled {
+            base.max(self.stiff_joints.iterations)
+        } else {
+            base
+        };
+        self.stiff_joints.stepped = iterations;
+        iterations
+    }
+}
@@ -0,0 +1,119 @@
+//! Overlap tracking for sensor colliders, derived from rapier's intersection graph.
+
+use crate::core::GameObjectId;
+use crate::physics::PhysicsManager;
+use rapier3d::prelude::*;
+use std::collections::HashSet;
+
+/// A collider started or stopped overlapping a sensor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TriggerEvent {
+    Enter {
+        sensor: ColliderHandle,
+        other: ColliderHandle,
+    },
+    Exit {
+        sensor: ColliderHandle,
+        other: ColliderHandle,
+    },
+}
+
+impl TriggerEvent {
+    pub fn sensor(&self) -> ColliderHandle {
+        match *self {
+            TriggerEvent::Enter { sensor, .. } | TriggerEvent::Exit { sensor, .. } => sensor,
+        }
+    }
+
+    pub fn other(&self) -> ColliderHandle {
+        match *self {
+            TriggerEvent::Enter { other, .. } | TriggerEvent::Exit { other, .. } => other,
+        }
+    }
+
+    pub fn is_enter(&self) -> bool {
+        matches!(self, TriggerEvent::Enter { .. })
+    }
+}
+
+/// Overlapping sensor pairs after the last step, and the transitions since the last frame
+#[derive(Debug, Default, Clone)]
+pub struct TriggerState {
+    /// `(sensor, other)` pairs
+    overlaps: HashSet<(ColliderHandle, ColliderHandle)>,
+    events: Vec<TriggerEvent>,
+}
+
+impl PhysicsManager {
+    /// Enter and exit transitions of all sensors, from every step since the start of this
+    /// frame's [`World::fixed_update`](crate::World::fixed_update).
+    ///
+    /// A pair that entered and left within the same frame reports both events.
+    pub fn trigger_events(&self) -> &[TriggerEvent] {
+        &self.triggers.events
+    }
+
+    /// Objects whose colliders overlap with this collider after the last step.
+    ///
+    /// At least one of both colliders has to be a sensor. This is the continuous set between
+    /// the [`TriggerEvent`]s: an object shows up here from the step its `Enter` was reported,
+    /// until the step its `Exit` was reported.
+    pub fn currently_overlapping(
+        &self,
+        collider: ColliderHandle,
+    ) -> impl Iterator<Item = GameObjectId> + '_ {
+        self.triggers
+            .overlaps
+            .iter()
+            .filter_map(move |&(sensor, other)| {
+                if sensor == collider {
+                    Some(other)
+                } else if other == collider {
+                    Some(sensor)
+                } else {
+                    None
+                }
+            })
+            .filter_map(|handle| self.collider_object(handle))
+    }
+
+    /// All `(sensor, other)` collider pairs that overlap after the last step
+    pub(crate) fn overlapping_pairs(
+        &self,
+    ) -> impl Iterator<Item = (ColliderHandle, ColliderHandle)> + '_ {
+        self.triggers.overlaps.iter().copied()
+    }
+
+    pub(crate) fn clear_trigger_events(&mut self) {
+        self.triggers.events.clear();
+    }
+
+    /// Diffs the intersection graph against the overlaps of the previous step
+    pub(crate) fn update_triggers(&mut self) {
+        let current: HashSet<_> = self
+            .narrow_phase
+            .intersection_graph()
+            .interactions_with_endpoints()
+            .filter(|(.., pair)| pair.intersecting)
+            .map(|(a, b, _)| {
+                let is_sensor = |h| self.collider_set.get(h).is_some_and(Collider::is_sensor);
+                // keep the order stable when both or neither are sensors
+                let swap = match (is_sensor(a), is_sensor(b)) {
+                    (true, false) => false,
+                    (false, true) => true,
+                    _ => a.into_raw_parts() > b.into_raw_parts(),
+                };
+                if swap { (b, a) } else { (a, b) }
+            })
+            .collect();
+
+        let triggers = &mut self.triggers;
+        for &(sensor, other) in triggers.overlaps.difference(&current) {
+            triggers.events.push(TriggerEvent::Exit { sensor, other });
+        }
+        for &(sensor, other) in current.difference(&triggers.overlaps) {
+            triggers.events.push(TriggerEvent::Enter { sensor, other });
+        }
+        triggers.overlaps = current;
+    }
+}
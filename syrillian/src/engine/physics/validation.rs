@@ -0,0 +1,55 @@
+//! Catching components that use a rapier handle after its entry was removed.
+//!
+//! Components like [`RigidBodyComponent`](crate::components::RigidBodyComponent) or the
+//! [joints](crate::components::JointComponent) keep handles into the sets of the
+//! [`PhysicsManager`](crate::physics::PhysicsManager). When something else removes the entry,
+//! for example a joint that rapier dropped along with the body of a deleted object, every
+//! access quietly finds nothing, and the component seems to just stop working.
+//!
+//! In debug builds, these accesses are checked, and the first miss of every handle logs an
+//! error naming the object and the component that holds it. Release builds skip the checks.
+
+use crate::core::GameObjectId;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use tracing::error;
+
+thread_local! {
+    static REPORTED: RefCell<HashSet<(GameObjectId, &'static str, String)>> =
+        RefCell::new(HashSet::new());
+}
+
+/// Passes `entry` through, the result of looking up `handle` for the `component` on `owner`.
+/// In debug builds, a missing entry is reported once per handle.
+#[inline]
+pub(crate) fn validated<T>(
+    entry: Option<T>,
+    handle: impl Debug,
+    owner: GameObjectId,
+    component: &'static str,
+) -> Option<T> {
+    if cfg!(debug_assertions) && entry.is_none() {
+        report_stale(format!("{handle:?}"), owner, component);
+    }
+    entry
+}
+
+#[cold]
+fn report_stale(handle: String, owner: GameObjectId, component: &'static str) {
+    let first =
+        REPORTED.with_borrow_mut(|reported| reported.insert((owner, component, handle.clone())));
+    if !first {
+        return;
+    }
+
+    let name = if owner.exists() {
+        owner.name.as_str()
+    } else {
+        "<deleted>"
+    };
+    error!(
+        "[Physics] {component} on {name} ({owner:?}) uses {handle}, which was removed from the \
+         physics world"
+    );
+}
@@ -12,6 +12,7 @@ impl Prefab for CameraPrefab {
 
     fn build(&self, world: &mut World) -> GameObjectId {
         let mut obj = world.new_object("Camera");
+        obj.transform.set_rotation(world.up_axis().from_y_up());
 
         obj.add_component::<CameraComponent>();
 
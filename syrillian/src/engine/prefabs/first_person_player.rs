@@ -5,7 +5,6 @@ use crate::components::{
 };
 use crate::core::GameObjectId;
 use crate::engine::prefabs::prefab::Prefab;
-use rapier3d::geometry::SharedShape;
 use tracing::warn;
 
 pub struct FirstPersonPlayerPrefab;
@@ -26,12 +25,14 @@ impl Prefab for FirstPersonPlayerPrefab {
         // Prepare character controller
         let mut char_controller = world.new_object(self.prefab_name());
         char_controller.transform.set_position(0.0, 0.0, 0.0);
+        // the capsule and the camera above it are set up Y-up
+        char_controller
+            .transform
+            .set_rotation(world.up_axis().from_y_up());
 
         char_controller
             .add_component::<Collider3D>()
-            .collider_mut()
-            .unwrap()
-            .set_shape(SharedShape::capsule_y(1.0, 0.25));
+            .use_capsule(1.0, 0.25);
 
         if let Some(rigid_body) = char_controller
             .add_component::<RigidBodyComponent>()
@@ -16,6 +16,13 @@ impl Prefab for SunPrefab {
         obj.transform.set_position(-20, 20, -20);
         obj.transform.set_euler_rotation_deg(45, 0, 45);
 
+        // the pose above is Y-up
+        let from_y_up = world.up_axis().from_y_up();
+        let position = from_y_up * obj.transform.position();
+        let rotation = from_y_up * obj.transform.rotation();
+        obj.transform.set_position_vec(position);
+        obj.transform.set_rotation(rotation);
+
         obj.add_component::<LightComponent<Sun>>();
 
         obj
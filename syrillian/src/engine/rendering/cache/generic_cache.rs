@@ -21,6 +21,12 @@ pub struct Cache<T: CacheType> {
 pub trait CacheType: Sized + StoreType {
     type Hot;
     fn upload(self, device: &Device, queue: &Queue, cache: &AssetCache) -> Self::Hot;
+
+    /// Writes the changed data into the already uploaded resources, if the layout still fits.
+    /// Returns `false` if the hot data needs to be uploaded again instead.
+    fn update_in_place(&self, _hot: &Self::Hot, _queue: &Queue) -> bool {
+        false
+    }
 }
 
 impl<T: CacheType + StoreTypeFallback> Cache<T> {
@@ -82,7 +88,15 @@ impl<T: CacheType> Cache<T> {
         let dirty = self.store.pop_dirty();
 
         for asset in &dirty {
-            self.data.remove(asset);
+            let updated = self.data.get(asset).is_some_and(|hot| {
+                self.store
+                    .try_get_quiet((*asset).into())
+                    .is_some_and(|cold| cold.update_in_place(&hot, &self.queue))
+            });
+
+            if !updated {
+                self.data.remove(asset);
+            }
         }
 
         dirty.len()
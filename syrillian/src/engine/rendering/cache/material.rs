@@ -1,5 +1,5 @@
 use crate::assets::HShader;
-use crate::engine::assets::{HTexture, Material};
+use crate::engine::assets::{HTexture, Material, RenderQueue};
 use crate::engine::rendering::cache::{AssetCache, CacheType};
 use crate::engine::rendering::uniform::ShaderUniform;
 use crate::ensure_aligned;
@@ -53,6 +53,8 @@ pub struct RuntimeMaterial {
     pub(crate) data: MaterialUniform,
     pub(crate) uniform: ShaderUniform<MaterialUniformIndex>,
     pub(crate) shader: HShader,
+    pub(crate) queue: RenderQueue,
+    pub(crate) depth_write: bool,
 }
 
 #[derive(Debug)]
@@ -93,6 +95,16 @@ impl CacheType for Material {
             params |= MaterialParams::has_transparency;
         }
 
+        // unlike the asset, this also knows about the transparency of the textures
+        let queue = self.queue.unwrap_or(
+            if params.contains(MaterialParams::has_transparency) || self.alpha < 1.0 {
+                RenderQueue::AlphaBlend
+            } else {
+                RenderQueue::Opaque
+            },
+        );
+        let depth_write = self.depth_write.unwrap_or(queue == RenderQueue::Opaque);
+
         let data = MaterialUniform {
             diffuse: self.color,
             roughness: self.roughness,
@@ -121,6 +133,8 @@ impl CacheType for Material {
             data,
             uniform,
             shader: self.shader,
+            queue,
+            depth_write,
         }
     }
 }
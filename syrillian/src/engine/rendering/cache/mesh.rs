@@ -220,7 +220,7 @@ impl CacheType for Mesh {
             let vertex_buf = device.create_buffer_init(&BufferInitDescriptor {
                 label: Some("Mesh Vertex Buffer"),
                 contents: bytemuck::cast_slice(self.vertices()),
-                usage: BufferUsages::VERTEX,
+                usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
             });
 
             for i in 0..=(indices_num / MAX_BUFFER_INDICES) {
@@ -229,7 +229,7 @@ impl CacheType for Mesh {
                 let indices_buf = device.create_buffer_init(&BufferInitDescriptor {
                     label: Some("Mesh Index Buffer"),
                     contents: bytemuck::cast_slice(&indices[start..end]),
-                    usage: BufferUsages::INDEX,
+                    usage: BufferUsages::INDEX | BufferUsages::COPY_DST,
                 });
                 meshlets.push(Meshlet {
                     vertex_buffer: vertex_buf.clone(),
@@ -247,7 +247,7 @@ impl CacheType for Mesh {
                 let vertex_buffer = device.create_buffer_init(&BufferInitDescriptor {
                     label: Some("Mesh Vertex Buffer"),
                     contents: bytemuck::cast_slice(&vertices[start..end]),
-                    usage: BufferUsages::VERTEX,
+                    usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
                 });
                 meshlets.push(Meshlet {
                     vertex_buffer,
@@ -261,4 +261,53 @@ impl CacheType for Mesh {
 
         RuntimeMesh::new(meshlets)
     }
+    fn update_in_place(&self, hot: &Self::Hot, queue: &Queue) -> bool {
+        let meshlets = hot.meshlets();
+
+        match self.indices() {
+            Some(indices) => {
+                let Some(first) = meshlets.first() else {
+                    return false;
+                };
+                let fits = first.vertex_count as usize == self.vertex_count()
+                    && hot.total_indices_count() as usize == indices.len()
+                    && meshlets.iter().all(Meshlet::has_indices);
+                if !fits {
+                    return false;
+                }
+
+                queue.write_buffer(
+                    &first.vertex_buffer,
+                    0,
+                    bytemuck::cast_slice(self.vertices()),
+                );
+                for meshlet in meshlets {
+                    let Some(index_buffer) = &meshlet.index_buffer else {
+                        continue;
+                    };
+                    let start = meshlet.offset as usize;
+                    let end = start + meshlet.index_count as usize;
+                    queue.write_buffer(index_buffer, 0, bytemuck::cast_slice(&indices[start..end]));
+                }
+            }
+            None => {
+                if hot.has_indices() || hot.total_vertex_count() as usize != self.vertex_count() {
+                    return false;
+                }
+
+                let vertices = self.vertices();
+                for meshlet in meshlets {
+                    let start = meshlet.offset as usize;
+                    let end = start + meshlet.vertex_count as usize;
+                    queue.write_buffer(
+                        &meshlet.vertex_buffer,
+                        0,
+                        bytemuck::cast_slice(&vertices[start..end]),
+                    );
+                }
+            }
+        }
+
+        true
+    }
 }
@@ -76,6 +76,13 @@ impl<'a> RenderPipelineBuilder<'a> {
         device.create_render_pipeline(&self.desc())
     }
 
+    /// The color pipeline, but without writing depth. `None` if it doesn't use depth at all.
+    pub fn build_depth_read_only(&'a self, device: &Device) -> Option<RenderPipeline> {
+        let mut desc = self.desc();
+        desc.depth_stencil.as_mut()?.depth_write_enabled = false;
+        Some(device.create_render_pipeline(&desc))
+    }
+
     pub fn build_shadow(&'a self, device: &Device) -> Option<RenderPipeline> {
         Some(device.create_render_pipeline(&self.shadow_desc()?))
     }
@@ -13,6 +13,7 @@ pub struct RuntimeShader {
     name: String,
     pub module: ShaderModule,
     pipeline: RenderPipeline,
+    depth_read_only_pipeline: Option<RenderPipeline>,
     shadow_pipeline: Option<RenderPipeline>,
     pub immediate_size: u32,
     bind_groups: BindGroupMap,
@@ -46,6 +47,7 @@ impl CacheType for Shader {
         let solid_layout = self.solid_layout(device, cache);
         let solid_builder = RenderPipelineBuilder::builder(&self, &solid_layout, &module);
         let pipeline = solid_builder.build(device);
+        let depth_read_only_pipeline = solid_builder.build_depth_read_only(device);
         let shadow_pipeline = self.shadow_layout(device, cache).and_then(|layout| {
             let shadow_builder = RenderPipelineBuilder::builder(&self, &layout, &module);
             shadow_builder.build_shadow(device)
@@ -55,6 +57,7 @@ impl CacheType for Shader {
             name,
             module,
             pipeline,
+            depth_read_only_pipeline,
             shadow_pipeline,
             immediate_size: self.immediate_size(),
             bind_groups,
@@ -68,6 +71,15 @@ impl RuntimeShader {
         &self.pipeline
     }
 
+    /// The solid pipeline, or its variant that doesn't write depth. Shaders without depth only
+    /// have the first.
+    pub fn solid_pipeline_with_depth_write(&self, depth_write: bool) -> &RenderPipeline {
+        match &self.depth_read_only_pipeline {
+            Some(pipeline) if !depth_write => pipeline,
+            _ => &self.pipeline,
+        }
+    }
+
     pub fn shadow_pipeline(&self) -> Option<&RenderPipeline> {
         self.shadow_pipeline.as_ref()
     }
@@ -1,4 +1,4 @@
-use crate::assets::{AssetStore, H, HMaterial, HMesh, HShader, Shader};
+use crate::assets::{AssetStore, H, HMaterial, HMesh, HShader, RenderQueue, Shader};
 use crate::components::mesh_renderer::BoneData;
 use crate::core::{BoundingSphere, ModelUniform};
 #[cfg(debug_assertions)]
@@ -162,22 +162,53 @@ impl SceneProxy for MeshSceneProxy {
     }
 
     fn priority(&self, store: &AssetStore) -> u32 {
-        if self.materials.iter().any(|m| {
-            let material = store.materials.get(*m);
-            material.is_transparent()
-        }) {
-            PROXY_PRIORITY_TRANSPARENT
-        } else {
-            PROXY_PRIORITY_SOLID
+        let (queue, queue_priority) = self.render_queue(store);
+        // the queue priority only orders within the queue, it can't move a mesh into another one
+        match queue {
+            RenderQueue::Opaque => PROXY_PRIORITY_SOLID
+                .saturating_add_signed(queue_priority)
+                .min(PROXY_PRIORITY_TRANSPARENT - 1),
+            RenderQueue::AlphaBlend => PROXY_PRIORITY_TRANSPARENT
+                .saturating_add_signed(queue_priority)
+                .max(PROXY_PRIORITY_TRANSPARENT),
         }
     }
 
+    fn is_transparent(&self, store: &AssetStore) -> bool {
+        self.render_queue(store).0 == RenderQueue::AlphaBlend
+    }
+
     fn bounds(&self, local_to_world: &Matrix4<f32>) -> Option<BoundingSphere> {
         Some((self.bounding * 5.0).transformed(local_to_world))
     }
 }
 
 impl MeshSceneProxy {
+    /// The queue of the mesh, alpha blended if any material is, and the highest queue priority
+    /// of the materials in it
+    fn render_queue(&self, store: &AssetStore) -> (RenderQueue, i32) {
+        let materials: Vec<_> = self
+            .materials
+            .iter()
+            .map(|m| store.materials.get(*m))
+            .collect();
+        let queue = if materials
+            .iter()
+            .any(|m| m.render_queue() == RenderQueue::AlphaBlend)
+        {
+            RenderQueue::AlphaBlend
+        } else {
+            RenderQueue::Opaque
+        };
+        let queue_priority = materials
+            .iter()
+            .filter(|m| m.render_queue() == queue)
+            .map(|m| m.queue_priority)
+            .max()
+            .unwrap_or(0);
+        (queue, queue_priority)
+    }
+
     fn draw_mesh(
         &self,
         ctx: &GPUDrawCtx,
@@ -205,6 +236,8 @@ impl MeshSceneProxy {
         pass: &mut RwLockWriteGuard<RenderPass>,
         current_shader: H<Shader>,
     ) {
+        // activating a shader selects its pipeline that writes depth
+        let mut depth_write = true;
         for (i, range) in self.material_ranges.iter().enumerate() {
             let h_mat = self
                 .materials
@@ -214,9 +247,9 @@ impl MeshSceneProxy {
             let material = cache.material(h_mat);
 
             if ctx.pass_type == RenderPassType::Color
-                && material.data.has_transparency() ^ ctx.transparency_pass
+                && (material.queue == RenderQueue::AlphaBlend) ^ ctx.transparency_pass
             {
-                continue; // either transparent in a non-transparency pass, or transparent in a non-transparency pass
+                continue; // either transparent in a non-transparency pass, or opaque in a transparency pass
             }
 
             if ctx.pass_type == RenderPassType::Shadow
@@ -230,6 +263,13 @@ impl MeshSceneProxy {
                 if !runtime.activate_shader(&shader, ctx, pass) {
                     return;
                 }
+                depth_write = true;
+            }
+
+            if ctx.pass_type == RenderPassType::Color && material.depth_write != depth_write {
+                let shader = cache.shader(material.shader);
+                pass.set_pipeline(shader.solid_pipeline_with_depth_write(material.depth_write));
+                depth_write = material.depth_write;
             }
 
             if let Some(idx) = cache.shader(material.shader).bind_groups().material {
@@ -295,3 +335,51 @@ fn draw_vertex_normals(
 
     mesh.draw_all_as_instances(0..2, pass);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::Material;
+    use nalgebra::Vector3;
+
+    fn proxy_with(store: &AssetStore, queue: RenderQueue, queue_priority: i32) -> MeshSceneProxy {
+        let material = store.materials.add(
+            Material::builder()
+                .name("Queued")
+                .queue(queue)
+                .queue_priority(queue_priority)
+                .build(),
+        );
+
+        MeshSceneProxy {
+            mesh: HMesh::UNIT_CUBE,
+            materials: vec![material],
+            material_ranges: Vec::new(),
+            bone_data: BoneData::default(),
+            bones_dirty: false,
+            bounding: BoundingSphere {
+                center: Vector3::zeros(),
+                radius: 1.0,
+            },
+        }
+    }
+
+    #[test]
+    fn queue_priority_stays_inside_its_render_queue() {
+        let store = AssetStore::new();
+
+        for queue_priority in [i32::MIN, -1000, 0, 1000, i32::MAX] {
+            let opaque = proxy_with(&store, RenderQueue::Opaque, queue_priority);
+            assert!(opaque.priority(&store) < PROXY_PRIORITY_TRANSPARENT);
+            assert!(!opaque.is_transparent(&store));
+
+            let transparent = proxy_with(&store, RenderQueue::AlphaBlend, queue_priority);
+            assert!(transparent.priority(&store) >= PROXY_PRIORITY_TRANSPARENT);
+            assert!(transparent.is_transparent(&store));
+        }
+
+        let opaque_max = proxy_with(&store, RenderQueue::Opaque, i32::MAX);
+        let transparent_min = proxy_with(&store, RenderQueue::AlphaBlend, i32::MIN);
+        assert!(opaque_max.priority(&store) < transparent_min.priority(&store));
+    }
+}
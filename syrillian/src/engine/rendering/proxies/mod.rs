@@ -78,6 +78,12 @@ pub trait SceneProxy: Send + Any + Debug {
 
     fn priority(&self, store: &AssetStore) -> u32;
 
+    /// Whether the proxy is drawn back-to-front among proxies of the same priority, instead of
+    /// front-to-back
+    fn is_transparent(&self, store: &AssetStore) -> bool {
+        self.priority(store) >= PROXY_PRIORITY_TRANSPARENT
+    }
+
     fn bounds(&self, _local_to_world: &Matrix4<f32>) -> Option<BoundingSphere> {
         None
     }
@@ -1141,6 +1141,7 @@ fn sorted_enabled_proxy_ids(
         .filter(|(_, binding)| binding.enabled)
         .filter_map(|(tid, binding)| {
             let priority = binding.proxy.priority(store);
+            let transparent = binding.proxy.is_transparent(store);
             let mut distance = 0.0;
             if let Some(f) = frustum
                 && let Some(bounds) = binding.bounds()
@@ -1151,9 +1152,13 @@ fn sorted_enabled_proxy_ids(
                 distance = f.side(FrustumSide::Near).distance_to(&bounds);
             };
 
-            Some((tid, priority, distance))
+            // opaque front-to-back to skip hidden fragments, transparent back-to-front to blend
+            let depth = (distance * 100000.0) as i64;
+            let depth = if transparent { -depth } else { depth };
+
+            Some((tid, priority, depth))
         })
-        .sorted_by_key(|(_, priority, distance)| (*priority, -(*distance * 100000.0) as i64))
+        .sorted_by_key(|(_, priority, depth)| (*priority, *depth))
         .map(|(tid, priority, _)| (priority, *tid))
         .collect()
 }
@@ -1241,7 +1246,7 @@ impl Frustum {
 mod tests {
     use super::*;
     use crate::components::ComponentId;
-    use crate::rendering::proxies::SceneProxy;
+    use crate::rendering::proxies::{PROXY_PRIORITY_SOLID, PROXY_PRIORITY_TRANSPARENT, SceneProxy};
     use nalgebra::{Affine3, Matrix4};
     use slotmap::Key;
     use std::any::{Any, TypeId};
@@ -1250,6 +1255,7 @@ mod tests {
     #[derive(Debug)]
     struct TestProxy {
         priority: u32,
+        depth: Option<f32>,
     }
 
     impl SceneProxy for TestProxy {
@@ -1264,6 +1270,13 @@ mod tests {
         fn priority(&self, _: &AssetStore) -> u32 {
             self.priority
         }
+
+        fn bounds(&self, _: &Matrix4<f32>) -> Option<BoundingSphere> {
+            self.depth.map(|depth| BoundingSphere {
+                center: Vector3::new(0.0, 0.0, -depth),
+                radius: 0.5,
+            })
+        }
     }
 
     #[test]
@@ -1299,20 +1312,62 @@ mod tests {
         assert!(!sorted.contains(&(1, id_disabled)));
     }
 
+    #[test]
+    fn resort_proxies_orders_opaque_front_to_back_and_transparent_back_to_front() {
+        struct OpaqueNear;
+        struct OpaqueFar;
+        struct TransparentNear;
+        struct TransparentFar;
+
+        let store = AssetStore::new();
+        let mut proxies = HashMap::new();
+        let solid = PROXY_PRIORITY_SOLID;
+        let transparent = PROXY_PRIORITY_TRANSPARENT;
+
+        let opaque_far = insert_proxy_at::<OpaqueFar>(&mut proxies, solid, Some(20.0));
+        let opaque_near = insert_proxy_at::<OpaqueNear>(&mut proxies, solid, Some(5.0));
+        let transparent_near =
+            insert_proxy_at::<TransparentNear>(&mut proxies, transparent, Some(5.0));
+        let transparent_far =
+            insert_proxy_at::<TransparentFar>(&mut proxies, transparent, Some(20.0));
+
+        let camera = Matrix4::new_perspective(1.0, 1.2, 0.1, 100.0);
+        let frustum = Frustum::from_matrix(&camera);
+        let sorted = sorted_enabled_proxy_ids(&proxies, &store, Some(&frustum));
+        assert_eq!(
+            sorted,
+            vec![
+                (solid, opaque_near),
+                (solid, opaque_far),
+                (transparent, transparent_far),
+                (transparent, transparent_near),
+            ]
+        );
+    }
+
     fn insert_proxy<T: 'static>(
         proxies: &mut HashMap<TypedComponentId, SceneProxyBinding>,
         priority: u32,
         enabled: bool,
+    ) -> TypedComponentId {
+        let tid = insert_proxy_at::<T>(proxies, priority, None);
+        proxies.get_mut(&tid).unwrap().enabled = enabled;
+        tid
+    }
+
+    fn insert_proxy_at<T: 'static>(
+        proxies: &mut HashMap<TypedComponentId, SceneProxyBinding>,
+        priority: u32,
+        depth: Option<f32>,
     ) -> TypedComponentId {
         let tid = TypedComponentId(TypeId::of::<T>(), ComponentId::null());
-        let mut binding = SceneProxyBinding::new(
+        let binding = SceneProxyBinding::new(
             tid,
             1,
             Affine3::identity(),
             Box::new(()),
-            Box::new(TestProxy { priority }),
+            Box::new(TestProxy { priority, depth }),
         );
-        binding.enabled = enabled;
         proxies.insert(tid, binding);
         tid
     }
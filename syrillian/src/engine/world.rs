@@ -6,14 +6,26 @@
 
 use crate::assets::{BGL, Material, Mesh, Shader, Sound, Store, Texture};
 use crate::audio::AudioScene;
-use crate::components::{CRef, CWeak, CameraComponent, Component};
+use crate::components::gravity_zone::GravityZoneClaims;
+use crate::components::{CRef, CWeak, CameraComponent, Collider3D, Component, RigidBodyComponent};
 use crate::core::component_storage::ComponentStorage;
-use crate::core::{EventType, GameObject, GameObjectId, GameObjectRef, ObjectHash, Transform};
+use crate::core::world_event::WorldEvents;
+use crate::core::{
+    ChangeTracked, EventType, GameObject, GameObjectBuilder, GameObjectId, GameObjectRef,
+    ObjectHash, SpatialGrid, Transform, UpAxis,
+};
 use crate::engine::assets::AssetStore;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::engine::assets::scene_loader::PendingScene;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::engine::assets::{AssetWatcher, HotReloadError};
 use crate::engine::prefabs::prefab::Prefab;
+use crate::engine::world_builder::WorldBuilder;
 use crate::game_thread::GameAppEvent;
 use crate::input::InputManager;
-use crate::physics::PhysicsManager;
+use crate::physics::{
+    ContactInfo, DragHandle, LayerMask, PhysicsManager, PhysicsProfile, QueryFilterBuilder,
+};
 use crate::prefabs::CameraPrefab;
 use crate::rendering::message::RenderMsg;
 use crate::rendering::picking::PickRequest;
@@ -21,7 +33,8 @@ use crate::rendering::picking::PickResult;
 use crate::rendering::strobe::StrobeFrame;
 use crate::rendering::{CPUDrawCtx, UiContext};
 use crate::windowing::RenderTargetId;
-use nalgebra::Matrix4;
+use nalgebra::{Isometry3, Matrix4, Vector3};
+use rapier3d::prelude::{QueryFilter, Ray, Shape};
 use slotmap::{Key, SlotMap};
 use std::cell::Cell;
 use std::collections::{HashMap, HashSet};
@@ -30,6 +43,7 @@ use std::mem::swap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tracing::info;
+use uuid::Uuid;
 use web_time::{Duration, Instant};
 
 use crossbeam_channel::unbounded;
@@ -37,6 +51,8 @@ use crossbeam_channel::{Receiver, Sender};
 use winit::dpi::PhysicalSize;
 use winit::event::MouseButton;
 
+type PhysicsHook = Box<dyn FnMut(&mut World)>;
+
 thread_local! {
     static CURRENT_WORLD: Cell<*mut World> = const { Cell::new(std::ptr::null_mut()) };
 }
@@ -106,6 +122,14 @@ impl WorldChannels {
         }
     }
 
+    /// Channels that lead nowhere, for worlds without a render thread
+    pub(crate) fn disconnected() -> Self {
+        let (render_tx, _) = unbounded();
+        let (game_event_tx, _) = unbounded();
+        let (_, pick_result_rx) = unbounded();
+        WorldChannels::new(render_tx, game_event_tx, pick_result_rx)
+    }
+
     pub fn set_active_camera(&mut self, target: RenderTargetId, camera: CWeak<CameraComponent>) {
         let entry = self.targets.entry(target).or_insert(RenderTargets {
             active_camera: CWeak::null(),
@@ -165,10 +189,14 @@ pub struct World {
     object_ref_counts: HashMap<GameObjectId, usize>,
     /// Objects that are awaiting final removal once all references drop
     pending_deletions: HashSet<GameObjectId>,
+    /// Objects switched off with [`World::set_active`]
+    pub(crate) inactive_objects: HashSet<GameObjectId>,
     /// Objects registered for click notifications
     click_listeners: HashSet<GameObjectId>,
     /// Allocated hashes to keep them unique per object
     object_hashes: HashSet<ObjectHash>,
+    /// Persistent ids of all objects, for lookups across save and load
+    object_uuids: HashMap<Uuid, GameObjectId>,
     /// The currently active camera used for rendering
     main_active_camera: CWeak<CameraComponent>,
     /// Physics simulation system
@@ -179,6 +207,12 @@ pub struct World {
     pub assets: Arc<AssetStore>,
     /// Spatial audio
     pub audio: AudioScene,
+    /// Proximity queries over tagged objects, apart from physics
+    pub spatial: SpatialGrid,
+    /// Which way is up, set once by the [`WorldBuilder`]
+    pub(crate) up_axis: UpAxis,
+    /// The bodies each gravity zone affects this tick
+    pub(crate) gravity_zones: GravityZoneClaims,
 
     /// Time when the world was created
     start_time: Instant,
@@ -186,6 +220,14 @@ pub struct World {
     delta_time: Duration,
     /// Time when the last frame started
     last_frame_time: Instant,
+    /// Freezes physics and the timing of everything that respects the pause
+    paused: bool,
+    /// Frames started with [`World::next_frame`]
+    frame_count: u64,
+    /// Physics ticks run by [`World::fixed_update`]
+    physics_tick_count: u64,
+    /// Sum of the frame times outside of pauses
+    elapsed_time: Duration,
     /// Sequence id for picking requests
     next_pick_request_id: u64,
 
@@ -193,6 +235,23 @@ pub struct World {
     requested_shutdown: bool,
     pub(crate) channels: WorldChannels,
     thread_binding: Option<WorldBinding>,
+    /// Skips all render proxy syncing when set
+    pub(crate) headless: bool,
+    /// Set while component storage is being iterated, so removals have to wait
+    iterating_components: bool,
+    /// Components removed during iteration, deleted once it's safe to do so
+    deferred_component_removals: Vec<CRef<dyn Component>>,
+    /// Closures run right before and after every physics step
+    pre_physics_hooks: Vec<PhysicsHook>,
+    post_physics_hooks: Vec<PhysicsHook>,
+    /// Handlers for objects being spawned and destroyed
+    pub(crate) world_events: WorldEvents,
+    /// Reloads changed asset files, polled every frame once enabled
+    #[cfg(not(target_arch = "wasm32"))]
+    hot_reload: Option<AssetWatcher>,
+    /// glTF scenes loading in the background
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) pending_scenes: Vec<PendingScene>,
     pub strobe: StrobeFrame,
 }
 
@@ -205,22 +264,41 @@ impl World {
             children: vec![],
             object_ref_counts: HashMap::new(),
             pending_deletions: HashSet::new(),
+            inactive_objects: HashSet::new(),
             click_listeners: HashSet::new(),
             object_hashes: HashSet::new(),
+            object_uuids: HashMap::new(),
             main_active_camera: CWeak::null(),
             physics: PhysicsManager::default(),
             input: InputManager::new(channels.game_event_tx.clone()),
             assets,
             audio: AudioScene::default(),
+            spatial: SpatialGrid::default(),
+            gravity_zones: GravityZoneClaims::default(),
+            up_axis: UpAxis::default(),
 
             start_time: Instant::now(),
             delta_time: Duration::default(),
             last_frame_time: Instant::now(),
+            paused: false,
+            frame_count: 0,
+            physics_tick_count: 0,
+            elapsed_time: Duration::ZERO,
             next_pick_request_id: 0,
 
             requested_shutdown: false,
             channels,
             thread_binding: None,
+            headless: false,
+            iterating_components: false,
+            deferred_component_removals: Vec::new(),
+            pre_physics_hooks: Vec::new(),
+            post_physics_hooks: Vec::new(),
+            world_events: WorldEvents::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            hot_reload: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            pending_scenes: Vec::new(),
             strobe: StrobeFrame::default(),
         })
     }
@@ -258,6 +336,11 @@ impl World {
         (world, rx1, rx2, pick_tx)
     }
 
+    /// Configure physics and rendering of a new world up front. See [`WorldBuilder`].
+    pub fn builder() -> WorldBuilder {
+        WorldBuilder::new()
+    }
+
     /// Returns a mutable reference to the global [`World`] instance.
     ///
     /// # Panics
@@ -352,8 +435,12 @@ impl World {
         self.pending_deletions.remove(&obj);
         self.object_ref_counts.remove(&obj);
         if let Some(existing) = self.objects.get(obj) {
+            let (hash, uuid) = (existing.hash, existing.uuid);
             self.click_listeners.remove(&obj);
-            self.release_object_hash(existing.hash);
+            self.release_object_hash(hash);
+            if self.object_uuids.get(&uuid) == Some(&obj) {
+                self.object_uuids.remove(&uuid);
+            }
         }
         self.detach_relationships(obj);
         self.objects.remove(obj);
@@ -438,6 +525,7 @@ impl World {
             custom_properties: HashMap::new(),
             event_mask: Cell::new(EventType::empty()),
             hash: 0,
+            uuid: Uuid::nil(),
         };
 
         let id = self.objects.insert(Box::new(obj));
@@ -451,9 +539,40 @@ impl World {
         entry.transform.owner = id;
         entry.hash = hash;
 
+        let mut uuid = Uuid::new_v4();
+        while self.object_uuids.contains_key(&uuid) {
+            uuid = Uuid::new_v4();
+        }
+        self.object_uuids.insert(uuid, id);
+        entry.uuid = uuid;
+
+        self.note_spawned(id);
         id
     }
 
+    /// Gives an object a new persistent id, for example the one it was saved with.
+    ///
+    /// Fails and returns `false` if another object already uses this id.
+    pub fn set_object_uuid(&mut self, obj: GameObjectId, uuid: Uuid) -> bool {
+        if self
+            .object_uuids
+            .get(&uuid)
+            .is_some_and(|&other| other != obj)
+        {
+            return false;
+        }
+        let Some(object) = self.objects.get_mut(obj) else {
+            return false;
+        };
+
+        let old = mem::replace(&mut object.uuid, uuid);
+        if self.object_uuids.get(&old) == Some(&obj) {
+            self.object_uuids.remove(&old);
+        }
+        self.object_uuids.insert(uuid, obj);
+        true
+    }
+
     /// Creates a new camera game object
     ///
     /// If no active camera exists yet, this camera will be set as the active camera
@@ -609,29 +728,181 @@ impl World {
         obj.parent = None;
     }
 
+    /// Creates a root object that starts out at `pose`.
+    ///
+    /// The pose is in place before anything else sees the object, so a rigid body added
+    /// afterward, even one created in [`Component::on_start`], begins at the pose too, and
+    /// neither rendering nor interpolation passes through the origin. For more setup, see
+    /// [`GameObjectBuilder::pose`].
+    pub fn spawn_at(&mut self, name: impl Into<String>, pose: Isometry3<f32>) -> GameObjectId {
+        let mut obj = self.new_object(name);
+        obj.transform.set_position_vec(pose.translation.vector);
+        obj.transform.set_rotation(pose.rotation);
+        self.add_child(obj);
+        obj
+    }
+
+    /// Spawns `count` root objects described by `build`, which gets the index of each object.
+    ///
+    /// Storage for all objects is reserved up front, so filling a large scene this way
+    /// avoids repeated reallocation. Returns the objects in spawn order.
+    pub fn spawn_batch(
+        &mut self,
+        count: usize,
+        mut build: impl FnMut(usize) -> GameObjectBuilder,
+    ) -> Vec<GameObjectId> {
+        self.objects.reserve(count);
+        self.object_hashes.reserve(count);
+        self.object_uuids.reserve(count);
+        self.children.reserve(count);
+
+        let mut spawned = Vec::with_capacity(count);
+        for i in 0..count {
+            let obj = build(i).spawn_detached(self);
+            self.children.push(obj);
+            spawned.push(obj);
+        }
+        spawned
+    }
+
+    /// Deletes all given objects and their children, like calling [`World::delete_object`]
+    /// on each, including [`Component::delete`] for all of their components.
+    ///
+    /// Deleting root objects one by one searches the root list every time. Here, the root
+    /// list is only filtered once at the end.
+    pub fn despawn_batch(&mut self, objects: &[GameObjectId]) {
+        let roots = mem::take(&mut self.children);
+        for &obj in objects {
+            self.delete_object(obj);
+        }
+
+        let mut kept: Vec<_> = roots
+            .into_iter()
+            .filter(|obj| self.objects.get(*obj).is_some_and(|o| o.is_alive()))
+            .collect();
+        // objects a delete hook added to the root while the list was taken
+        kept.append(&mut self.children);
+        self.children = kept;
+    }
+
+    /// Moves many objects to new global poses at once, for example when scattering foliage
+    /// or laying out a voxel grid.
+    ///
+    /// Setting each transform on its own marks all of its children dirty right away. Here,
+    /// children are only marked once all poses are set. Objects with a rigid body are
+    /// teleported there without interpolating, kinematic ones too, so they don't sweep
+    /// through the scene on the next physics step. Deleted objects are skipped.
+    ///
+    /// Poses are global, so when a parent and its child are both in the list, the parent
+    /// has to come first.
+    pub fn set_transforms(&mut self, poses: &[(GameObjectId, Isometry3<f32>)]) {
+        let mut parents = Vec::new();
+        for &(mut obj, pose) in poses {
+            if !obj.exists() {
+                continue;
+            }
+            obj.transform.set_global_pose_deferred(&pose);
+            if let Some(mut rb) = obj.get_component::<RigidBodyComponent>() {
+                rb.teleport(pose);
+            }
+            if !obj.children.is_empty() {
+                parents.push(obj);
+            }
+        }
+
+        for mut parent in parents {
+            parent.transform.set_dirty();
+        }
+    }
+
     /// Spawns a game object from a prefab
     pub fn spawn<P: Prefab>(&mut self, prefab: &P) -> GameObjectId {
         prefab.spawn(self)
     }
 
-    /// Executes a component function on all components of all game objects
+    /// Executes a component function on all components of all game objects, ordered by
+    /// [`Component::update_priority`]. Components added meanwhile are left for the next call.
     pub(crate) fn execute_component_func<F>(&mut self, func: F)
     where
         F: Fn(&mut dyn Component, &mut World),
     {
-        let world = unsafe { &mut *(self as *mut World) };
-        self.components.values_mut().for_each(|c| func(c, world))
+        let order: Vec<_> = self.components.update_order().collect();
+        self.iterating_components = true;
+        for ctid in order {
+            if let Some(mut comp) = self.components.get_dyn(ctid) {
+                func(&mut *comp, self);
+            }
+        }
+        self.iterating_components = false;
+
+        self.flush_component_removals();
+    }
+
+    /// Runs [`Component::delete`] on a component that was already detached from its object
+    /// and drops it from the world.
+    ///
+    /// While components are being iterated, e.g. when a component removes itself during its
+    /// own update, this is deferred until the iteration is done.
+    pub(crate) fn destroy_component(&mut self, mut comp: CRef<dyn Component>) {
+        if self.iterating_components {
+            self.deferred_component_removals.push(comp);
+            return;
+        }
+
+        comp.delete(self);
+        self.components.remove(&comp);
+    }
+
+    /// Runs [`Component::on_start`] for all components added since the last call, including
+    /// the ones added by other components while starting. Newly spawned objects are
+    /// announced right before.
+    fn start_components(&mut self) {
+        self.announce_spawned();
+        while !self.components.unstarted.is_empty() {
+            self.iterating_components = true;
+            let mut unstarted: Vec<_> = mem::take(&mut self.components.unstarted)
+                .into_iter()
+                .filter_map(|ctid| self.components.get_dyn(ctid))
+                .collect();
+            unstarted.sort_by_key(|comp| comp.update_priority());
+            for mut comp in unstarted {
+                comp.on_start(self);
+            }
+            self.iterating_components = false;
+            self.flush_component_removals();
+        }
+    }
+
+    fn flush_component_removals(&mut self) {
+        for comp in mem::take(&mut self.deferred_component_removals) {
+            self.destroy_component(comp);
+        }
     }
 
     /// Runs possible physics update if the timestep time has elapsed yet
+    ///
+    /// In [deterministic](PhysicsManager::deterministic) mode, exactly one step is run per call.
     pub fn fixed_update(&mut self) {
-        while self.physics.last_update.elapsed() >= self.physics.timestep {
-            self.execute_component_func(Component::pre_fixed_update);
+        self.physics.clear_trigger_events();
+        self.physics.clear_hard_impacts();
 
-            self.physics.last_update += self.physics.timestep;
-            self.physics.step();
+        if self.paused {
+            // don't catch up on the paused time once resumed
+            self.physics.last_update = Instant::now();
+            return;
+        }
+
+        if self.physics.deterministic {
+            self.fixed_tick();
+
+            self.physics.last_update = Instant::now();
+            self.physics.alpha = 1.0;
+            return;
+        }
 
-            self.execute_component_func(Component::fixed_update);
+        while self.physics.last_update.elapsed() >= self.physics.timestep {
+            self.physics.last_update += self.physics.timestep;
+            self.fixed_tick();
         }
 
         let rem = self.physics.last_update.elapsed();
@@ -639,6 +910,373 @@ impl World {
             (rem.as_secs_f32() / self.physics.timestep.as_secs_f32()).clamp(0.0, 1.0);
     }
 
+    fn fixed_tick(&mut self) {
+        self.physics_tick_count += 1;
+        self.start_components();
+        self.execute_component_func(Component::pre_fixed_update);
+        self.run_physics_hooks(|world| &mut world.pre_physics_hooks);
+        let known_impacts = self.physics.hard_impacts().len();
+        self.physics.step();
+        self.announce_hard_impacts(known_impacts);
+        self.run_physics_hooks(|world| &mut world.post_physics_hooks);
+        self.execute_component_func(Component::fixed_update);
+    }
+
+    fn run_physics_hooks(&mut self, hooks: fn(&mut World) -> &mut Vec<PhysicsHook>) {
+        let mut running = mem::take(hooks(self));
+        for hook in &mut running {
+            hook(self);
+        }
+        // keep hooks that were registered while running
+        running.append(hooks(self));
+        *hooks(self) = running;
+    }
+
+    /// Runs `hook` every physics tick, right before the simulation steps.
+    ///
+    /// This is after all [`Component::pre_fixed_update`] calls, so it's the last chance to
+    /// apply forces or move kinematic bodies for this tick. Hooks run in the order they were
+    /// registered.
+    pub fn on_pre_physics(&mut self, hook: impl FnMut(&mut World) + 'static) {
+        self.pre_physics_hooks.push(Box::new(hook));
+    }
+
+    /// Runs `hook` every physics tick, right after the simulation stepped.
+    ///
+    /// Contacts, trigger events and body poses are already up to date. Components haven't
+    /// seen the tick yet, so object transforms still hold the previous poses until
+    /// [`Component::fixed_update`] syncs them right after.
+    pub fn on_post_physics(&mut self, hook: impl FnMut(&mut World) + 'static) {
+        self.post_physics_hooks.push(Box::new(hook));
+    }
+
+    /// Removes all hooks registered with [`World::on_pre_physics`] and
+    /// [`World::on_post_physics`]
+    pub fn clear_physics_hooks(&mut self) {
+        self.pre_physics_hooks.clear();
+        self.post_physics_hooks.clear();
+    }
+
+    /// Starts watching asset files for changes, and returns the watcher to load assets with.
+    ///
+    /// Textures and meshes loaded through the [`AssetWatcher`] are reloaded during
+    /// [`World::update`] whenever their file changes. Calling this again returns the same
+    /// watcher.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn enable_hot_reload(&mut self) -> Result<&mut AssetWatcher, HotReloadError> {
+        if self.hot_reload.is_none() {
+            self.hot_reload = Some(AssetWatcher::new(self.assets.clone())?);
+        }
+        Ok(self.hot_reload.as_mut().expect("watcher was just created"))
+    }
+
+    /// The asset watcher, if [`World::enable_hot_reload`] was called
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn hot_reload(&mut self) -> Option<&mut AssetWatcher> {
+        self.hot_reload.as_mut()
+    }
+
+    /// Describes all rigid bodies and joints in a readable text form, for debugging setups
+    /// that behave oddly or to attach to bug reports. See [`PhysicsManager::dump`].
+    pub fn dump_physics(&self) -> String {
+        self.physics.dump().to_string()
+    }
+
+    /// Creates a headless world with a copy of this world's physics, to simulate ahead
+    /// without changing anything here, for example to check where a throw would land.
+    ///
+    /// Only the physics state is copied, see [`PhysicsManager::fork`]. The fork has no
+    /// objects or components, so nothing drives kinematic bodies or applies forces in it, but
+    /// every [`World::fixed_update`] on it steps exactly once and queries on it still report
+    /// the objects of this world. Keep the fork on the thread of this world, since it relies
+    /// on this world's [`World::instance`] binding.
+    pub fn fork_physics(&self) -> Box<World> {
+        let mut fork = World::empty(WorldChannels::disconnected(), self.assets.clone());
+        fork.headless = true;
+        fork.physics = self.physics.fork();
+        fork
+    }
+
+    /// Grabs a dynamic object at `world_point` to pull it around, for example with the
+    /// cursor. Move the grab point with [`DragHandle::move_to`] and let go with
+    /// [`DragHandle::end_drag`].
+    ///
+    /// Returns `None` if the object has no rigid body, or it isn't dynamic.
+    pub fn begin_drag(
+        &mut self,
+        object: GameObjectId,
+        world_point: Vector3<f32>,
+    ) -> Option<DragHandle> {
+        let body = object.get_component::<RigidBodyComponent>()?.body_handle;
+        DragHandle::new(object, body, world_point)
+    }
+
+    /// Casts a ray that only hits colliders in the given layers, and returns the distance to
+    /// the first hit and its object.
+    ///
+    /// The layers are resolved with [`PhysicsManager::resolve_layers`] and replace any groups
+    /// set on the `filter`. Other options of the filter, like excluded objects, still apply.
+    /// Colliders are checked where they were after the last physics step.
+    pub fn raycast(
+        &self,
+        ray: &Ray,
+        max_distance: f32,
+        layers: &LayerMask,
+        filter: QueryFilterBuilder,
+    ) -> Option<(f32, GameObjectId)> {
+        let filter = filter.layers(self.physics.resolve_layers(layers));
+        self.physics
+            .cast_ray(ray, max_distance, true, filter.build())
+    }
+
+    /// Casts many rays like [`World::raycast`] at once, with the same layers and filter. The
+    /// results are in the same order as the rays. See [`PhysicsManager::cast_ray_batch`].
+    pub fn raycast_batch(
+        &self,
+        rays: &[Ray],
+        max_distance: f32,
+        layers: &LayerMask,
+        filter: QueryFilterBuilder,
+    ) -> Vec<Option<(f32, GameObjectId)>> {
+        let filter = filter.layers(self.physics.resolve_layers(layers));
+        self.physics
+            .cast_ray_batch(rays, max_distance, true, filter.build())
+    }
+
+    /// What the colliders of `object` touch, as of the last physics step, for polling things
+    /// like whether a character stands on the ground. There's one entry per touching surface,
+    /// so an object resting on a crate and leaning against a wall lists both. Contacts
+    /// between colliders of `object` itself are left out, and so are sensors, which only
+    /// overlap, see [`Collider3D::currently_overlapping`].
+    ///
+    /// ```rust
+    /// use syrillian::World;
+    ///
+    /// # let mut world = World::builder().build();
+    /// # let player = world.new_object("Player");
+    /// let grounded = world
+    ///     .contacts_with(player)
+    ///     .any(|contact| contact.normal.y < -0.7);
+    /// ```
+    pub fn contacts_with(&self, object: GameObjectId) -> impl Iterator<Item = ContactInfo> + use<> {
+        let mut contacts = Vec::new();
+        for collider in object.iter_components::<Collider3D>() {
+            let handle = collider.phys_handle;
+            contacts.extend(
+                self.physics
+                    .contacts(handle)
+                    .iter()
+                    .filter_map(|contact| ContactInfo::from_contact(handle, contact))
+                    .filter(|info| info.other != object),
+            );
+        }
+        contacts.into_iter()
+    }
+
+    /// Whether nothing solid is between the positions of `from` and `to`.
+    ///
+    /// Casts a ray from one object to the other. The colliders of both objects are always
+    /// ignored, and so are sensors, so trigger volumes don't block the view. Anything else
+    /// that should be seen through, like glass or other members of the same squad, can be
+    /// excluded with the `filter`.
+    pub fn has_line_of_sight(
+        &self,
+        from: GameObjectId,
+        to: GameObjectId,
+        filter: QueryFilterBuilder,
+    ) -> bool {
+        let start = from.transform.position();
+        let offset = to.transform.position() - start;
+        let distance = offset.norm();
+        if distance <= f32::EPSILON {
+            return true;
+        }
+
+        let ray = Ray::new(start.into(), offset / distance);
+        let filter = filter.exclude(from).exclude(to).exclude_sensors();
+        self.physics
+            .cast_ray(&ray, distance, true, filter.build())
+            .is_none()
+    }
+
+    /// Whether `shape` placed at `pose` would overlap any collider, for example to check if a
+    /// building fits on its spot before placing it.
+    ///
+    /// Colliders are checked where they were after the last physics step, so objects spawned
+    /// this frame are only found after the next [`World::fixed_update`].
+    pub fn test_overlap(
+        &self,
+        shape: &dyn Shape,
+        pose: Isometry3<f32>,
+        filter: QueryFilter,
+    ) -> bool {
+        !self.overlapping_objects(shape, pose, filter).is_empty()
+    }
+
+    /// Lists the objects whose colliders overlap `shape` placed at `pose`. See
+    /// [`World::test_overlap`].
+    pub fn overlapping_objects(
+        &self,
+        shape: &dyn Shape,
+        pose: Isometry3<f32>,
+        filter: QueryFilter,
+    ) -> Vec<GameObjectId> {
+        self.physics.intersect_shape(shape, &pose, filter)
+    }
+
+    /// Pushes an object out of the static geometry its collider overlaps, for example after
+    /// teleporting a character into a wall. Returns how far it was moved, or `None` if the
+    /// object has no [`Collider3D`].
+    ///
+    /// Only fixed colliders and ones without a rigid body push, sensors are ignored. The
+    /// object is moved by the shortest translation found by
+    /// [`PhysicsManager::depenetration`], its rotation stays the same. Rigid bodies are
+    /// teleported there, so they don't sweep through the scene to get there. Static
+    /// colliders are checked where they were after the last physics step.
+    pub fn depenetrate(&mut self, object: GameObjectId) -> Option<Vector3<f32>> {
+        let collider = object.get_component::<Collider3D>()?;
+        let rotation = object.transform.rotation();
+        let pose = Isometry3::from_parts(object.transform.position().into(), rotation);
+        let shape = collider.collider()?.shared_shape().clone();
+
+        let filter = QueryFilterBuilder::new()
+            .exclude(object)
+            .only_fixed()
+            .exclude_sensors();
+        let offset = self.physics.depenetration(
+            shape.as_ref(),
+            &(pose * collider.local_transform()),
+            filter.build(),
+        );
+        if offset == Vector3::zeros() {
+            return Some(offset);
+        }
+
+        let pose = Isometry3::from_parts((pose.translation.vector + offset).into(), rotation);
+        let mut obj = object;
+        obj.transform.set_position_vec(pose.translation.vector);
+        if let Some(mut rb) = object.get_component::<RigidBodyComponent>() {
+            rb.teleport(pose);
+        } else if let Some(c) = collider.collider_mut() {
+            c.set_position(pose * collider.local_transform());
+        }
+        Some(offset)
+    }
+
+    /// Splits every fixed physics tick into `substeps` smaller integration steps.
+    ///
+    /// This is different from solver iterations: each substep runs the full pipeline,
+    /// including collision detection, with a fraction of the timestep. Fast bodies move a
+    /// shorter distance between two collision checks, which keeps things like a pinball on a
+    /// thin table stable. A 10cm ball hitting a 1cm thick table at 60 m/s needs around 16
+    /// substeps at 60Hz to stay on top. The cost of a tick grows about linearly with the amount of
+    /// substeps, and `fixed_update` components still only run once per tick.
+    ///
+    /// CCD is still worth enabling for small and very fast bodies, since it prevents
+    /// tunneling entirely. It runs in every substep, so it gets more expensive with them too.
+    /// A value of 1 (the default) disables sub-stepping.
+    pub fn set_physics_substeps(&mut self, substeps: usize) {
+        self.physics.substeps = substeps.max(1);
+    }
+
+    pub fn physics_substeps(&self) -> usize {
+        self.physics.substeps
+    }
+
+    /// Whether the contact solver starts each step from the contact impulses of the previous
+    /// one. On by default.
+    ///
+    /// Warm-starting is what lets resting stacks converge over a few steps, instead of
+    /// sagging and jittering every frame. But it makes a step depend on how the previous ones
+    /// went, so bodies restored from a snapshot can diverge from the original run. Turning it
+    /// off makes every step only depend on the current state of the bodies, which costs
+    /// stability, mostly for stacks.
+    ///
+    /// [Deterministic](crate::physics::PhysicsManager::deterministic) mode already makes
+    /// identical runs step identically, warm-start or not. This is for reproducing a step from
+    /// the pose alone. Joints aren't affected, rapier solves them from scratch every step.
+    pub fn set_contact_warmstart(&mut self, warmstart: bool) {
+        self.physics.integration_parameters.warmstart_coefficient =
+            if warmstart { 1.0 } else { 0.0 };
+    }
+
+    pub fn contact_warmstart(&self) -> bool {
+        self.physics.integration_parameters.warmstart_coefficient > 0.0
+    }
+
+    /// Records where each physics tick spends its time, see [`World::physics_profile`].
+    ///
+    /// Off by default. Timings additionally need the `physics-profiler` feature, which
+    /// makes rapier measure them.
+    pub fn set_physics_profiling(&mut self, enabled: bool) {
+        let counters = &mut self.physics.physics_pipeline.counters;
+        if enabled {
+            counters.enable();
+        } else {
+            counters.disable();
+        }
+    }
+
+    pub fn is_physics_profiling(&self) -> bool {
+        self.physics.physics_pipeline.counters.enabled()
+    }
+
+    /// Breakdown of the last physics tick, or all zeros if profiling is off.
+    ///
+    /// A large `solver` share points to too many solver iterations or substeps, a large
+    /// `ccd` share to too many bodies with CCD enabled, and a large `narrow_phase` share to
+    /// many touching or complex colliders.
+    pub fn physics_profile(&self) -> PhysicsProfile {
+        self.physics.profile
+    }
+
+    /// How fast contacts push overlapping colliders apart, in Hz. Defaults to 30.
+    ///
+    /// Lower values make contacts softer, so bodies sink into each other and settle slowly,
+    /// which can help arcade effects like squishy landings. Higher values resolve
+    /// penetration snappier. At 60Hz, a resting 1m crate sinks about 10cm into the ground
+    /// at 1 Hz, 1cm at 5 Hz and 1mm at the default.
+    ///
+    /// This applies to all contacts in the world. Rapier derives its error reduction
+    /// parameter from this and [`World::set_contact_damping_ratio`], see [`World::contact_erp`].
+    pub fn set_contact_natural_frequency(&mut self, hz: f32) {
+        self.physics
+            .integration_parameters
+            .contact_softness
+            .natural_frequency = hz.max(f32::EPSILON);
+    }
+
+    /// How much contact corrections are damped. Defaults to 5.
+    ///
+    /// Larger values make contacts more compliant and remove less penetration per step. The
+    /// effect is much smaller than the one of the frequency; values between 0.2 and 20 are
+    /// all stable.
+    pub fn set_contact_damping_ratio(&mut self, ratio: f32) {
+        self.physics
+            .integration_parameters
+            .contact_softness
+            .damping_ratio = ratio.max(0.0);
+    }
+
+    /// Upper limit for the velocity used to push penetrating bodies apart, in meters per
+    /// second. Defaults to 10.
+    ///
+    /// Lowering this keeps deeply overlapping bodies from popping apart violently.
+    pub fn set_max_corrective_velocity(&mut self, velocity: f32) {
+        let params = &mut self.physics.integration_parameters;
+        params.normalized_max_corrective_velocity = velocity.max(0.0) / params.length_unit;
+    }
+
+    /// The fraction of contact penetration that's removed per physics step, between 0 and 1.
+    ///
+    /// This results from the contact frequency and damping ratio along with the duration of
+    /// one (sub)step, and can't be set directly.
+    pub fn contact_erp(&self) -> f32 {
+        let params = &self.physics.integration_parameters;
+        let dt = params.dt / self.physics.substeps.max(1) as f32;
+        params.contact_softness.erp(dt)
+    }
+
     /// Updates all game objects and their components
     ///
     /// It will tick delta time and update all components
@@ -646,8 +1284,15 @@ impl World {
     /// If you're using the App runtime, this will be handled for you. Only call this function
     /// if you are trying to use a detached world context.
     pub fn update(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(watcher) = &mut self.hot_reload {
+            watcher.poll();
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        self.poll_scene_loads();
         self.process_pick_results();
         self.maybe_request_pick();
+        self.start_components();
         self.execute_component_func(Component::update);
         self.execute_component_func(Component::late_update);
     }
@@ -660,12 +1305,22 @@ impl World {
         let world = self as *mut World;
         self.execute_component_func(Component::post_update);
 
+        if self.headless {
+            self.components.fresh.clear();
+            self.components.removed.clear();
+            self.strobe = StrobeFrame::default();
+            return;
+        }
+
+        self.iterating_components = true;
         for mut comp in self.components.iter_refs() {
             let ctx = UiContext::new(comp.ctx.parent.hash, comp.ctx.tid);
             unsafe {
                 comp.on_gui(&mut *world, ctx);
             }
         }
+        self.iterating_components = false;
+        self.flush_component_removals();
 
         self.sync_fresh_components();
         self.sync_removed_components();
@@ -809,6 +1464,7 @@ impl World {
     /// If you're using the App runtime, this will be handled for you. Only call this function
     /// if you are trying to use a detached world context.
     pub fn next_frame(&mut self) {
+        self.spatial.refresh(&self.objects);
         for child in self.objects.values_mut() {
             if child.is_alive() {
                 child.transform.clear_dirty();
@@ -828,6 +1484,13 @@ impl World {
             .map(|o| o.0)
     }
 
+    /// Finds a game object by its persistent id, see [`GameObject::uuid`]
+    pub fn find_by_uuid(&self, uuid: Uuid) -> Option<GameObjectId> {
+        let id = *self.object_uuids.get(&uuid)?;
+        let obj = self.objects.get(id)?;
+        obj.is_alive().then_some(id)
+    }
+
     /// Gets all components of a specific type from all game objects in the world
     ///
     /// This method recursively traverses the entire scene graph to find all components
@@ -858,6 +1521,27 @@ impl World {
         collection.extend(obj.iter_components::<C>());
     }
 
+    /// Find all objects whose `T` changed during the current frame, for systems that only
+    /// need to react to changes, like updating a spatial index when objects move:
+    /// ```rust
+    /// use syrillian::World;
+    /// use syrillian::core::Transform;
+    ///
+    /// let mut world = World::builder().build();
+    /// let mut obj = world.new_object("Crate");
+    /// world.next_frame();
+    /// assert!(world.query_changed::<Transform>().is_empty());
+    ///
+    /// obj.transform.translate(nalgebra::Vector3::x());
+    /// assert_eq!(world.query_changed::<Transform>(), vec![obj]);
+    /// ```
+    pub fn query_changed<T: ChangeTracked>(&self) -> Vec<GameObjectId> {
+        self.objects
+            .iter()
+            .filter_map(|(id, o)| (o.is_alive() && T::changed_this_frame(o)).then_some(id))
+            .collect()
+    }
+
     /// Find all objects that contain a property with the given key
     pub fn find_objects_with_property(&self, key: &str) -> Vec<GameObjectId> {
         self.objects
@@ -914,6 +1598,13 @@ impl World {
     fn tick_delta_time(&mut self) {
         self.delta_time = self.last_frame_time.elapsed();
         self.last_frame_time = Instant::now();
+        self.frame_count += 1;
+        self.elapsed_time += self.game_delta_time();
+    }
+
+    /// Returns true if the world runs without a renderer
+    pub fn is_headless(&self) -> bool {
+        self.headless
     }
 
     /// Returns the time elapsed since the last frame
@@ -921,6 +1612,37 @@ impl World {
         self.delta_time
     }
 
+    /// The time elapsed since the last frame as gameplay sees it, which is zero while the
+    /// world is paused. See [`World::set_paused`].
+    pub fn game_delta_time(&self) -> Duration {
+        if self.paused {
+            Duration::ZERO
+        } else {
+            self.delta_time
+        }
+    }
+
+    /// Which way is up in this world, see [`WorldBuilder::up_axis`]
+    pub fn up_axis(&self) -> UpAxis {
+        self.up_axis
+    }
+
+    /// Pauses or resumes the game, like for a pause menu.
+    ///
+    /// While paused, physics doesn't step and no fixed updates run. Components still update
+    /// every frame, so menus and cameras keep working. What freezes with the game is up to
+    /// them: [`Timer`](crate::utils::Timer)s and
+    /// [`AnimationComponent`](crate::components::AnimationComponent)s stop advancing by
+    /// default, and anything UI that should keep going opts out with `set_ignore_pause(true)`.
+    /// Gameplay code of its own should use [`World::game_delta_time`].
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
     /// Returns the instant in time when the world was created
     pub fn start_time(&self) -> Instant {
         self.start_time
@@ -931,6 +1653,30 @@ impl World {
         self.start_time.elapsed()
     }
 
+    /// How long the game has been running, as the sum of all frame times so far.
+    ///
+    /// Unlike [`World::time`], this stands still while the world is
+    /// [paused](World::set_paused), and only moves on once per frame, so everything during a
+    /// frame sees the same value. Frame times follow the wall clock, so for timing that has to
+    /// be reproducible, go by [`World::physics_tick_count`] instead.
+    pub fn elapsed_time(&self) -> Duration {
+        self.elapsed_time
+    }
+
+    /// The amount of frames started with [`World::next_frame`] so far, pauses included
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// The amount of physics ticks run so far. It stays the same while paused.
+    ///
+    /// Every tick advances the simulation by one timestep, so in
+    /// [deterministic](PhysicsManager::deterministic) worlds, this is the clock to key
+    /// reproducible behavior off, like spawning waves or recording inputs for replays.
+    pub fn physics_tick_count(&self) -> u64 {
+        self.physics_tick_count
+    }
+
     /// Marks a game object for deletion. This will immediately run the object internal destruction routine
     /// and also clean up any component-specific data.
     pub fn delete_object(&mut self, object: GameObjectId) {
@@ -941,6 +1687,49 @@ impl World {
         }
     }
 
+    /// Switches an object and its children off or back on.
+    ///
+    /// Inactive objects still exist and render, but their components are skipped in every
+    /// update pass, and their rigid bodies and colliders are taken out of the simulation. This
+    /// is much cheaper than updating large amounts of scenery that rarely changes. Once
+    /// reactivated, bodies rejoin the simulation where they were, and components update in
+    /// their usual order again.
+    ///
+    /// Only the current children are affected, objects added to an inactive object later stay
+    /// active.
+    pub fn set_active(&mut self, object: GameObjectId, active: bool) {
+        let Some(obj) = self.get_object(object).filter(|o| o.is_alive()) else {
+            return;
+        };
+        let children = obj.children.clone();
+        let components = obj.components.clone();
+
+        let changed = if active {
+            self.inactive_objects.remove(&object)
+        } else {
+            self.inactive_objects.insert(object)
+        };
+        if changed {
+            for mut comp in components {
+                if active {
+                    self.components.unpark(comp.ctx.tid);
+                } else {
+                    self.components.park(comp.ctx.tid);
+                }
+                comp.on_active_changed(self, active);
+            }
+        }
+
+        for child in children {
+            self.set_active(child, active);
+        }
+    }
+
+    /// Whether an object is updated and simulated, see [`World::set_active`]
+    pub fn is_active(&self, object: GameObjectId) -> bool {
+        !self.inactive_objects.contains(&object)
+    }
+
     /// Internal method to unlink and remove a game object from the world
     ///
     /// This method will remove the object from the world's children list if it's a root-level object,
@@ -1011,6 +1800,7 @@ impl World {
         self.components = ComponentStorage::default();
         self.click_listeners.clear();
         self.object_hashes.clear();
+        self.object_uuids.clear();
         self.next_pick_request_id = 0;
         self.pending_deletions.clear();
     }
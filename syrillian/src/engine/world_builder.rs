@@ -0,0 +1,149 @@
+//! Up-front configuration of a [`World`] and its physics simulation.
+
+use crate::World;
+use crate::core::UpAxis;
+use crate::engine::assets::AssetStore;
+use crate::physics::BroadPhaseConfig;
+use crate::world::WorldChannels;
+use nalgebra::{Point3, Vector3};
+use rapier3d::prelude::Aabb;
+use std::sync::Arc;
+use web_time::Duration;
+
+/// Configures a [`World`] before it's created.
+///
+/// Everything not set explicitly keeps the defaults of [`World::new`], so
+/// `WorldBuilder::new().build()` is equivalent to a fresh world.
+///
+/// ```rust
+/// use nalgebra::Vector3;
+/// use syrillian::World;
+/// use std::time::Duration;
+///
+/// let world = World::builder()
+///     .gravity(Vector3::new(0.0, -1.62, 0.0))
+///     .timestep(Duration::from_secs_f64(1.0 / 120.0))
+///     .solver_iterations(8)
+///     .headless(true)
+///     .build();
+///
+/// assert_eq!(world.physics.gravity.y, -1.62);
+/// ```
+#[derive(Default)]
+pub struct WorldBuilder {
+    assets: Option<Arc<AssetStore>>,
+    channels: Option<WorldChannels>,
+    gravity: Option<Vector3<f32>>,
+    timestep: Option<Duration>,
+    solver_iterations: Option<usize>,
+    broad_phase: Option<BroadPhaseConfig>,
+    world_bounds: Option<Aabb>,
+    up_axis: UpAxis,
+    deterministic: bool,
+    headless: bool,
+}
+
+impl WorldBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use an existing asset store instead of creating a new one
+    pub fn assets(mut self, assets: Arc<AssetStore>) -> Self {
+        self.assets = Some(assets);
+        self
+    }
+
+    /// Connect the world to a render and windowing thread.
+    ///
+    /// A world built without channels has no renderer to talk to, and is always headless.
+    pub fn channels(mut self, channels: WorldChannels) -> Self {
+        self.channels = Some(channels);
+        self
+    }
+
+    /// Gravity acceleration applied to all dynamic rigid bodies
+    pub fn gravity(mut self, gravity: Vector3<f32>) -> Self {
+        self.gravity = Some(gravity);
+        self
+    }
+
+    /// Which way is up, for content authored Z-up. Default: [`UpAxis::Y`]
+    ///
+    /// Unless [`WorldBuilder::gravity`] is set as well, gravity pulls down this axis. See
+    /// [`core::up_axis`](crate::core::up_axis) for what else follows it. There's no changing
+    /// it later, one world has to stick to one convention.
+    pub fn up_axis(mut self, up_axis: UpAxis) -> Self {
+        self.up_axis = up_axis;
+        self
+    }
+
+    /// Duration of one fixed physics step
+    pub fn timestep(mut self, timestep: Duration) -> Self {
+        self.timestep = Some(timestep);
+        self
+    }
+
+    /// Amount of solver iterations per physics step. More iterations improve the
+    /// stability of stacks and joints at the cost of performance.
+    pub fn solver_iterations(mut self, iterations: usize) -> Self {
+        self.solver_iterations = Some(iterations.max(1));
+        self
+    }
+
+    /// Tune the broad-phase for the size and layout of the world. See
+    /// [`physics::broad_phase`](crate::physics::broad_phase) for the tradeoffs.
+    pub fn broad_phase(mut self, config: BroadPhaseConfig) -> Self {
+        self.broad_phase = Some(config);
+        self
+    }
+
+    /// The playable area, see [`PhysicsManager::world_bounds`](crate::physics::PhysicsManager::world_bounds)
+    pub fn world_bounds(mut self, min: Vector3<f32>, max: Vector3<f32>) -> Self {
+        self.world_bounds = Some(Aabb::new(Point3::from(min), Point3::from(max)));
+        self
+    }
+
+    /// Advance physics exactly one timestep per [`World::fixed_update`] call instead of
+    /// following the wall clock. See [`PhysicsManager::deterministic`](crate::physics::PhysicsManager::deterministic).
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// Run the world without a renderer. No render proxies are created or synced.
+    pub fn headless(mut self, headless: bool) -> Self {
+        self.headless = headless;
+        self
+    }
+
+    /// Creates the world and binds it to the current thread.
+    pub fn build(self) -> Box<World> {
+        let assets = self.assets.unwrap_or_else(AssetStore::new);
+        let headless = self.headless || self.channels.is_none();
+        let channels = self.channels.unwrap_or_else(WorldChannels::disconnected);
+
+        let mut world = World::new_with_channels(assets, channels);
+        world.headless = headless;
+        world.up_axis = self.up_axis;
+
+        let physics = &mut world.physics;
+        physics.gravity = self
+            .gravity
+            .unwrap_or_else(|| self.up_axis.from_y_up() * physics.gravity);
+        if let Some(timestep) = self.timestep {
+            physics.timestep = timestep;
+            physics.integration_parameters.dt = timestep.as_secs_f32();
+        }
+        if let Some(iterations) = self.solver_iterations {
+            physics.integration_parameters.num_solver_iterations = iterations;
+        }
+        if let Some(config) = self.broad_phase {
+            config.apply(physics);
+        }
+        physics.world_bounds = self.world_bounds;
+        physics.deterministic = self.deterministic;
+
+        world
+    }
+}
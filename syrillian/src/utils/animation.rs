@@ -92,7 +92,7 @@ fn find_key(times: &[f32], t: f32) -> usize {
     }
     times
         .binary_search_by(|k| k.partial_cmp(&t).unwrap_or(Ordering::Equal))
-        .unwrap_or_else(|i| (i - 1).max(0))
+        .unwrap_or_else(|i| i.saturating_sub(1))
 }
 
 fn lerp_vec3(a: &Vector3<f32>, b: &Vector3<f32>, alpha: f32) -> Vector3<f32> {
@@ -8,9 +8,11 @@ pub(crate) mod hacks;
 pub mod iter;
 pub mod math;
 pub mod sizes;
+pub mod timer;
 
 pub use buffer::*;
 pub use checks::*;
 pub use color::*;
 pub use frame_counter::*;
 pub use math::*;
+pub use timer::*;
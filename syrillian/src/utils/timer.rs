@@ -0,0 +1,126 @@
+//! Countdowns for cooldowns, respawns and other gameplay timing.
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use syrillian::World;
+//! use syrillian::utils::Timer;
+//!
+//! let world = World::builder().build();
+//! let mut respawn = Timer::new(Duration::from_secs(3));
+//! // the blinking cursor of the pause menu keeps going
+//! let mut cursor_blink = Timer::repeating(Duration::from_millis(500));
+//! cursor_blink.set_ignore_pause(true);
+//!
+//! // every frame
+//! if respawn.tick(&world) {
+//!     println!("respawning");
+//! }
+//! cursor_blink.tick(&world);
+//! ```
+
+use crate::World;
+use std::time::Duration;
+
+/// Counts up to a duration, driven by the frame times of the [`World`].
+///
+/// Timers freeze while the world is paused, unless they're set to
+/// [ignore the pause](Timer::set_ignore_pause), which is what timers of menus and other UI
+/// should do. See [`World::set_paused`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timer {
+    duration: Duration,
+    elapsed: Duration,
+    repeating: bool,
+    ignore_pause: bool,
+    finished: bool,
+}
+
+impl Timer {
+    /// A timer that finishes once after `duration`
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            elapsed: Duration::ZERO,
+            repeating: false,
+            ignore_pause: false,
+            finished: false,
+        }
+    }
+
+    /// A timer that finishes every `duration`
+    pub fn repeating(duration: Duration) -> Self {
+        Self {
+            repeating: true,
+            ..Self::new(duration)
+        }
+    }
+
+    /// Advances by the last frame time of `world`, or not at all while it's paused.
+    /// Returns whether the timer finished during this frame.
+    pub fn tick(&mut self, world: &World) -> bool {
+        let dt = if self.ignore_pause {
+            world.delta_time()
+        } else {
+            world.game_delta_time()
+        };
+        self.advance(dt)
+    }
+
+    /// Advances by `dt`, whether the world is paused or not. Returns whether the timer
+    /// finished during this call.
+    ///
+    /// A repeating timer that passed its duration several times at once only reports it once,
+    /// and keeps the remainder.
+    pub fn advance(&mut self, dt: Duration) -> bool {
+        if self.finished && !self.repeating {
+            return false;
+        }
+
+        self.elapsed += dt;
+        if self.elapsed < self.duration {
+            return false;
+        }
+
+        self.finished = true;
+        if self.repeating && !self.duration.is_zero() {
+            self.elapsed =
+                Duration::from_nanos((self.elapsed.as_nanos() % self.duration.as_nanos()) as u64);
+        } else {
+            self.elapsed = self.duration;
+        }
+        true
+    }
+
+    /// Starts counting from zero again
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.finished = false;
+    }
+
+    /// Keeps counting while the world is paused. Off by default, so gameplay timing freezes
+    /// with the game.
+    pub fn set_ignore_pause(&mut self, ignore: bool) {
+        self.ignore_pause = ignore;
+    }
+
+    pub fn ignores_pause(&self) -> bool {
+        self.ignore_pause
+    }
+
+    /// Whether a one-shot timer ran out, or a repeating one ran out at least once
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.elapsed)
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
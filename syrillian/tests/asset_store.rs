@@ -213,3 +213,42 @@ fn test_iterate_assets() {
 
     assert!(count >= 7);
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn hot_reload_replaces_texture_behind_handle() {
+    use std::time::{Duration, Instant};
+    use syrillian::assets::AssetWatcher;
+
+    let dir = std::env::temp_dir().join(format!("syrillian-hot-reload-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("albedo.png");
+    image::RgbaImage::new(2, 2).save(&path).unwrap();
+
+    let store = AssetStore::new();
+    let mut watcher = AssetWatcher::new(store.clone()).unwrap();
+    let handle = watcher.load_texture(&path).unwrap();
+    assert_eq!(store.textures.try_get(handle).unwrap().width, 2);
+
+    let poll_until = |watcher: &mut AssetWatcher, done: &dyn Fn() -> bool| {
+        let start = Instant::now();
+        while !done() && start.elapsed() < Duration::from_secs(5) {
+            watcher.poll();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    };
+
+    image::RgbaImage::new(4, 8).save(&path).unwrap();
+    poll_until(&mut watcher, &|| {
+        store.textures.try_get(handle).unwrap().width == 4
+    });
+    let texture = store.textures.try_get(handle).unwrap().clone();
+    assert_eq!((texture.width, texture.height), (4, 8));
+
+    std::fs::write(&path, b"not an image").unwrap();
+    std::thread::sleep(Duration::from_millis(200));
+    assert_eq!(watcher.poll(), 0);
+    assert_eq!(store.textures.try_get(handle).unwrap().width, 4);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,43 @@
+use syrillian::World;
+use syrillian::audio::MASTER_BUS;
+use syrillian::components::audio::AudioEmitter;
+
+#[test]
+fn mixer_buses_keep_their_volumes() {
+    let (mut world, ..) = World::fresh();
+
+    let names: Vec<_> = world.audio.mixer().bus_names().collect();
+    assert_eq!(names, [MASTER_BUS, "music", "sfx"]);
+    assert!(world.audio.add_bus("voice"));
+    assert!(!world.audio.add_bus("music"));
+
+    let mixer = world.audio.mixer_mut();
+    assert!(mixer.set_volume("music", 0.5));
+    assert!(mixer.set_volume(MASTER_BUS, 0.5));
+    assert!(!mixer.set_volume("ambience", 0.5));
+    assert_eq!(mixer.volume("music"), Some(0.5));
+    assert_eq!(mixer.effective_volume("music"), Some(0.25));
+    assert_eq!(mixer.effective_volume("voice"), Some(0.5));
+
+    // muting keeps the volume for unmuting later
+    assert!(mixer.set_muted("music", true));
+    assert_eq!(mixer.is_muted("music"), Some(true));
+    assert_eq!(mixer.effective_volume("music"), Some(0.0));
+    mixer.set_muted("music", false);
+    assert_eq!(mixer.effective_volume("music"), Some(0.25));
+
+    mixer.set_muted(MASTER_BUS, true);
+    assert_eq!(mixer.effective_volume("sfx"), Some(0.0));
+}
+
+#[test]
+fn audio_emitters_play_through_their_bus() {
+    let (mut world, ..) = World::fresh();
+    let mut radio = world.new_object("Radio");
+    world.add_child(radio);
+
+    let mut emitter = radio.add_component::<AudioEmitter>();
+    assert_eq!(emitter.bus(), MASTER_BUS);
+    emitter.set_bus("music");
+    assert_eq!(emitter.bus(), "music");
+}
@@ -1,7 +1,12 @@
 use nalgebra::Vector3;
 use std::any::TypeId;
+use std::cell::RefCell;
 use syrillian::World;
-use syrillian::components::{Component, NewComponent};
+use syrillian::assets::HMesh;
+use syrillian::components::{
+    Collider3D, Component, FieldValue, FixedJoint, FollowCameraComponent, LodComponent,
+    MeshRenderer, NewComponent, ReflectError, RigidBodyComponent, priority,
+};
 use syrillian::core::GameObjectId;
 
 struct MyComponent {
@@ -78,3 +83,314 @@ fn check_typed() {
 
     assert_eq!(world.components.values().count(), 0);
 }
+
+struct SelfRemoving {
+    parent: GameObjectId,
+}
+
+impl NewComponent for SelfRemoving {
+    fn new(parent: GameObjectId) -> Self {
+        Self { parent }
+    }
+}
+
+impl Component for SelfRemoving {
+    fn update(&mut self, _world: &mut World) {
+        assert!(self.parent.remove_component_of::<SelfRemoving>());
+    }
+
+    fn delete(&mut self, _world: &mut World) {
+        self.parent.add_property("deleted", true.into());
+    }
+}
+
+#[test]
+fn remove_component_by_type_runs_delete() {
+    let (mut world, ..) = World::fresh();
+    let mut obj = world.new_object("Welded");
+    let mut other = world.new_object("Wall");
+    obj.add_component::<RigidBodyComponent>();
+    other.add_component::<RigidBodyComponent>();
+
+    let mut joint = obj.add_component::<FixedJoint>();
+    joint.connect_to(other);
+    assert_eq!(world.physics.impulse_joint_set.len(), 1);
+
+    assert!(obj.remove_component_of::<FixedJoint>());
+    assert!(obj.get_component::<FixedJoint>().is_none());
+    assert_eq!(world.physics.impulse_joint_set.len(), 0);
+    assert!(!obj.remove_component_of::<FixedJoint>());
+
+    assert!(obj.get_component::<RigidBodyComponent>().is_some());
+}
+
+#[test]
+fn component_can_remove_itself_during_update() {
+    let (mut world, ..) = World::fresh();
+    let mut obj = world.new_object("Ephemeral");
+    obj.add_component::<SelfRemoving>();
+    obj.add_component::<MyComponent>();
+    world.add_child(obj);
+
+    world.update();
+
+    assert!(obj.get_component::<SelfRemoving>().is_none());
+    assert!(
+        world
+            .components
+            .values_of_type::<SelfRemoving>()
+            .unwrap()
+            .next()
+            .is_none()
+    );
+    assert_eq!(obj.property("deleted"), Some(&true.into()));
+    assert_eq!(obj.iter_components::<MyComponent>().count(), 1);
+}
+
+/// Records whether its sibling rigid body already existed when it started
+#[derive(Default)]
+struct SiblingLookup {
+    starts: u32,
+    updates_before_start: u32,
+}
+
+impl Component for SiblingLookup {
+    fn on_start(&mut self, world: &mut World) {
+        self.starts += 1;
+        let sibling = world
+            .components
+            .values_of_type::<RigidBodyComponent>()
+            .into_iter()
+            .flatten()
+            .next()
+            .map(|rb| rb.parent());
+        if let Some(mut sibling) = sibling {
+            sibling.add_property("seen", true.into());
+        }
+    }
+
+    fn update(&mut self, _world: &mut World) {
+        if self.starts == 0 {
+            self.updates_before_start += 1;
+        }
+    }
+}
+
+#[test]
+fn on_start_runs_once_after_the_object_is_spawned() {
+    let (mut world, ..) = World::fresh();
+    let mut obj = world.new_object("Spawned");
+    let lookup = obj.add_component::<SiblingLookup>();
+    assert_eq!(lookup.starts, 0);
+
+    // added after the component that looks it up
+    obj.add_component::<RigidBodyComponent>();
+    world.add_child(obj);
+
+    world.update();
+    world.fixed_update();
+    world.update();
+
+    assert_eq!(lookup.starts, 1);
+    assert_eq!(lookup.updates_before_start, 0);
+    assert_eq!(obj.property("seen"), Some(&true.into()));
+
+    // components added later start before their first physics tick too
+    world.physics.deterministic = true;
+    let mut late = world.new_object("Late");
+    let late_lookup = late.add_component::<SiblingLookup>();
+    world.fixed_update();
+    assert_eq!(late_lookup.starts, 1);
+}
+
+thread_local! {
+    static UPDATE_LOG: RefCell<Vec<(i32, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+struct Prioritized<const P: i32> {
+    parent: GameObjectId,
+}
+
+impl<const P: i32> NewComponent for Prioritized<P> {
+    fn new(parent: GameObjectId) -> Self {
+        Self { parent }
+    }
+}
+
+impl<const P: i32> Component for Prioritized<P> {
+    fn update(&mut self, _world: &mut World) {
+        UPDATE_LOG.with_borrow_mut(|log| log.push((P, self.parent.name.clone())));
+    }
+
+    fn update_priority(&self) -> i32 {
+        P
+    }
+}
+
+#[test]
+fn components_update_by_priority_then_spawn_order() {
+    let (mut world, ..) = World::fresh();
+    let mut first = world.new_object("First");
+    let mut second = world.new_object("Second");
+    first.add_component::<Prioritized<{ priority::CAMERA }>>();
+    first.add_component::<Prioritized<{ priority::DEFAULT }>>();
+    second.add_component::<Prioritized<{ priority::INPUT }>>();
+    second.add_component::<Prioritized<{ priority::DEFAULT }>>();
+    world.add_child(first);
+    world.add_child(second);
+
+    let expected = [
+        (priority::INPUT, "Second"),
+        (priority::DEFAULT, "First"),
+        (priority::DEFAULT, "Second"),
+        (priority::CAMERA, "First"),
+    ];
+    for _ in 0..3 {
+        world.update();
+        let log = UPDATE_LOG.with_borrow_mut(std::mem::take);
+        let log: Vec<_> = log.iter().map(|(p, name)| (*p, name.as_str())).collect();
+        assert_eq!(log, expected);
+    }
+
+    // removing one keeps the others in order
+    first.remove_component_of::<Prioritized<{ priority::DEFAULT }>>();
+    world.update();
+    let log = UPDATE_LOG.with_borrow_mut(std::mem::take);
+    let priorities: Vec<_> = log.iter().map(|(p, _)| *p).collect();
+    assert_eq!(
+        priorities,
+        [priority::INPUT, priority::DEFAULT, priority::CAMERA]
+    );
+}
+
+#[test]
+fn reflect_lists_and_edits_component_fields() {
+    let (mut world, ..) = World::fresh();
+    let mut obj = world.new_object("Inspected");
+    let mut other = world.new_object("Other");
+    obj.add_component::<RigidBodyComponent>();
+    other.add_component::<RigidBodyComponent>();
+    obj.add_component::<MyComponent>();
+    let mut joint = obj.add_component::<FixedJoint>();
+    joint.connect_to(other);
+
+    let mut names = Vec::new();
+    for comp in obj.iter_dyn_components() {
+        let mut comp = comp.clone();
+        if let Some(reflect) = comp.reflect() {
+            names.push(reflect.type_name());
+        }
+    }
+    assert_eq!(names, ["RigidBody", "FixedJoint"]);
+
+    let mut comp = obj.iter_dyn_components().nth(2).unwrap().clone();
+    let reflect = comp.reflect().unwrap();
+    assert_eq!(
+        reflect.field("anchor1"),
+        Some(FieldValue::Vec3(Vector3::zeros()))
+    );
+    assert_eq!(
+        reflect.field("break_force"),
+        Some(FieldValue::F32(f32::INFINITY))
+    );
+
+    let anchor = Vector3::new(0.0, 1.0, 0.0);
+    reflect.set_field("anchor1", anchor.into()).unwrap();
+    reflect.set_field("break_force", 50.0.into()).unwrap();
+    assert!(matches!(
+        reflect.set_field("anchor2", true.into()),
+        Err(ReflectError::WrongType { .. })
+    ));
+    assert!(matches!(
+        reflect.set_field("missing", true.into()),
+        Err(ReflectError::UnknownField { .. })
+    ));
+
+    assert_eq!(joint.anchor1().coords, anchor);
+    assert_eq!(joint.joint_data().unwrap().local_anchor1().coords, anchor);
+    assert_eq!(joint.break_force, Some(50.0));
+}
+
+#[test]
+fn follow_camera_trails_the_target_and_stays_out_of_walls() {
+    let (mut world, ..) = World::fresh();
+    let mut player = world.new_object("Player");
+    world.add_child(player);
+    let mut camera = world.new_camera().parent();
+
+    let mut follow = camera.add_component::<FollowCameraComponent>();
+    follow.set_target(Some(player));
+    follow.offset = Vector3::new(0.0, 0.0, 5.0);
+    follow.look_offset = Vector3::zeros();
+
+    // the first frame jumps right behind the target, looking at it
+    follow.advance(&world, 1.0 / 60.0);
+    assert!((camera.transform.position() - Vector3::new(0.0, 0.0, 5.0)).norm() < 1e-4);
+    assert!((camera.transform.forward() - -Vector3::z()).norm() < 1e-4);
+
+    // then it lags behind, and catches up without overshooting
+    player.transform.set_position(10.0, 0.0, 0.0);
+    follow.advance(&world, 1.0 / 60.0);
+    let x = camera.transform.position().x;
+    assert!(x > 0.0 && x < 5.0, "{x}");
+    let mut last_x = x;
+    for _ in 0..300 {
+        follow.advance(&world, 1.0 / 60.0);
+        let x = camera.transform.position().x;
+        assert!(x >= last_x - 1e-4 && x <= 10.0 + 1e-4, "{x}");
+        last_x = x;
+    }
+    assert!((camera.transform.position() - Vector3::new(10.0, 0.0, 5.0)).norm() < 1e-2);
+
+    // a wall between the two pulls the camera in front of it
+    let mut wall = world.new_object("Wall");
+    wall.transform.set_position(10.0, 0.0, 3.0);
+    wall.add_component::<Collider3D>();
+    world.add_child(wall);
+    world.physics.step();
+
+    follow.advance(&world, 1.0 / 60.0);
+    let z = camera.transform.position().z;
+    assert!((z - (2.5 - follow.wall_margin)).abs() < 1e-3, "{z}");
+
+    follow.avoid_walls = false;
+    follow.advance(&world, 1.0 / 60.0);
+    assert!(camera.transform.position().z > 4.9);
+}
+
+#[test]
+fn lod_switches_meshes_by_distance_with_hysteresis() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut obj = world.new_object("Rock");
+    obj.add_component::<MeshRenderer>();
+    let mut lod = obj.add_component::<LodComponent>();
+    lod.set_levels(vec![(HMesh::UNIT_CUBE, 50.0), (HMesh::SPHERE, 10.0)]);
+    lod.hysteresis = 2.0;
+    assert_eq!(lod.levels()[0].0, HMesh::SPHERE);
+
+    let renderer = || obj.get_component::<MeshRenderer>().unwrap();
+
+    lod.select(5.0);
+    assert_eq!(lod.current_level(), Some(0));
+    assert_eq!(renderer().mesh(), HMesh::SPHERE);
+
+    // inside the band past the boundary, the level stays
+    lod.select(11.0);
+    assert_eq!(lod.current_level(), Some(0));
+    lod.select(12.5);
+    assert_eq!(lod.current_level(), Some(1));
+    assert_eq!(renderer().mesh(), HMesh::UNIT_CUBE);
+    lod.select(9.0);
+    assert_eq!(lod.current_level(), Some(1));
+    lod.select(7.0);
+    assert_eq!(lod.current_level(), Some(0));
+
+    lod.select(100.0);
+    assert_eq!(lod.current_level(), None);
+    assert!(!renderer().is_visible());
+    lod.select(49.0);
+    assert_eq!(lod.current_level(), None);
+    lod.select(30.0);
+    assert_eq!(lod.current_level(), Some(1));
+    assert!(renderer().is_visible());
+}
@@ -0,0 +1,46 @@
+use syrillian::World;
+use syrillian::input::{Binding, BindingsError};
+use syrillian::winit::event::MouseButton;
+use syrillian::winit::keyboard::KeyCode;
+
+#[test]
+fn saved_bindings_survive_a_restart() {
+    let path = std::env::temp_dir().join("syrillian_test_bindings.json");
+    let jump = [
+        Binding::Key(KeyCode::Space),
+        Binding::Gamepad(gilrs::Button::South),
+    ];
+
+    let (mut world, ..) = World::fresh();
+    let bindings = &mut world.input.bindings;
+    bindings.add_action("jump", jump);
+    bindings.add_action("fire", [Binding::Mouse(MouseButton::Left)]);
+    assert!(bindings.rebind("fire", [Binding::Key(KeyCode::KeyF)]));
+    assert!(!bindings.rebind("dance", [Binding::Key(KeyCode::KeyD)]));
+    world.input.save_bindings(&path).unwrap();
+    drop(world);
+
+    let (mut world, ..) = World::fresh();
+    let bindings = &mut world.input.bindings;
+    bindings.add_action("fire", [Binding::Mouse(MouseButton::Left)]);
+    bindings.add_action("crouch", [Binding::Key(KeyCode::ControlLeft)]);
+    world.input.load_bindings(&path).unwrap();
+
+    let bindings = &world.input.bindings;
+    assert_eq!(bindings.get("fire"), [Binding::Key(KeyCode::KeyF)]);
+    // not part of this game anymore, so it's left out
+    assert!(!bindings.has_action("jump"));
+    // new since the save, so it keeps its defaults
+    assert_eq!(bindings.get("crouch"), [Binding::Key(KeyCode::ControlLeft)]);
+    assert!(!world.input.is_action_pressed("fire"));
+    assert!(!world.input.is_action_down("jump"));
+
+    std::fs::write(&path, "not json").unwrap();
+    let err = world.input.load_bindings(&path).unwrap_err();
+    assert!(matches!(err, BindingsError::BindingsFormat { .. }));
+    assert_eq!(
+        world.input.bindings.get("fire"),
+        [Binding::Key(KeyCode::KeyF)]
+    );
+    std::fs::remove_file(&path).unwrap();
+}
@@ -1,10 +1,13 @@
 use nalgebra::{Isometry3, Point3, Vector3};
-use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use rapier3d::prelude::{JointAxesMask, JointAxis};
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, FRAC_PI_6, PI};
+use std::time::{Duration, Instant};
 use syrillian::World;
 use syrillian::components::{
-    Component, RigidBodyComponent,
+    CRef, ChainJointKind, Collider3D, Component, JointChain, RigidBodyComponent,
     joints::{FixedJoint, PrismaticJoint, RevoluteJoint, RopeJoint, SphericalJoint, SpringJoint},
 };
+use syrillian::core::GameObjectId;
 
 #[test]
 fn joint_connect_disconnect() {
@@ -75,6 +78,57 @@ fn joint_fails_on_deleted_connector() {
     assert!(result.is_err());
 }
 
+#[test]
+fn try_connect_to_returns_the_joint_handle() {
+    let (mut world, ..) = World::fresh();
+    let mut obj1 = world.new_object("Obj1");
+    let mut obj2 = world.new_object("Obj2");
+    obj1.add_component::<RigidBodyComponent>();
+    obj2.add_component::<RigidBodyComponent>();
+
+    let mut joint = obj1.add_component::<FixedJoint>();
+    let handle = joint
+        .try_connect_to(obj2)
+        .expect("both objects have bodies");
+
+    assert_eq!(joint.handle(), Some(handle));
+    assert!(world.physics.impulse_joint_set.get(handle).is_some());
+}
+
+#[test]
+fn stale_handles_fail_gracefully() {
+    let (mut world, ..) = World::fresh();
+    let mut obj1 = world.new_object("Obj1");
+    let mut obj2 = world.new_object("Obj2");
+    let mut rb = obj1.add_component::<RigidBodyComponent>();
+    obj2.add_component::<RigidBodyComponent>();
+
+    let mut joint = obj1.add_component::<FixedJoint>();
+    joint.connect_to(obj2);
+
+    // rapier drops the joint along with the body of the deleted object
+    obj2.delete();
+    assert!(joint.handle().is_some());
+    assert!(joint.joint_data().is_none());
+    assert!(joint.force().is_none());
+    joint.set_enabled(false);
+    joint.set_break_force(Some(1.0));
+    world.fixed_update();
+
+    let physics = &mut world.physics;
+    physics.rigid_body_set.remove(
+        rb.body_handle,
+        &mut physics.island_manager,
+        &mut physics.collider_set,
+        &mut physics.impulse_joint_set,
+        &mut physics.multibody_joint_set,
+        true,
+    );
+    assert!(rb.body().is_none());
+    rb.set_kinematic(true);
+    assert!(rb.is_kinematic());
+}
+
 #[test]
 fn joint_reconnect() {
     let (mut world, ..) = World::fresh();
@@ -1225,3 +1279,495 @@ fn multiple_joints_on_same_object() {
     assert!(rope.is_connected());
     assert_ne!(spring.handle(), rope.handle());
 }
+
+#[test]
+fn spherical_joint_cone_limits() {
+    let (mut world, ..) = World::fresh();
+    let mut obj1 = world.new_object("Obj1");
+    let mut obj2 = world.new_object("Obj2");
+    obj1.add_component::<RigidBodyComponent>();
+    obj2.add_component::<RigidBodyComponent>();
+
+    let mut joint = obj1.add_component::<SphericalJoint>();
+    joint.set_cone_limits_deg(30.0, 10.0);
+    joint.connect_to(obj2);
+
+    let data = joint.joint_data().unwrap();
+    let swing = data.limits(JointAxis::AngX).unwrap();
+    assert!((swing.max - FRAC_PI_6).abs() < 1e-6);
+    assert!((swing.min + FRAC_PI_6).abs() < 1e-6);
+    assert_eq!(data.limits(JointAxis::AngZ).unwrap().max, swing.max);
+    assert!((data.limits(JointAxis::AngY).unwrap().max - 10f32.to_radians()).abs() < 1e-6);
+    assert!(data.contacts_enabled);
+
+    joint.set_contacts_enabled(false);
+    assert!(!joint.joint_data().unwrap().contacts_enabled);
+}
+
+#[test]
+fn joint_chain_links_segments_between_ends() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut start = world.new_object("Start");
+    let mut end = world.new_object("End");
+    end.transform.set_position(4.0, 0.0, 0.0);
+    start
+        .add_component::<RigidBodyComponent>()
+        .set_kinematic(true);
+    end.add_component::<RigidBodyComponent>()
+        .set_kinematic(true);
+    world.add_child(start);
+    world.add_child(end);
+
+    let links = JointChain::build(&mut world, start, end, 3, ChainJointKind::Spherical).unwrap();
+    assert_eq!(links.len(), 3);
+    for (i, link) in links.iter().enumerate() {
+        assert_eq!(
+            link.transform.position(),
+            Vector3::new(i as f32 + 1.0, 0.0, 0.0)
+        );
+    }
+
+    let first = links[0].get_component::<SphericalJoint>().unwrap();
+    assert_eq!(first.connected(), Some(start));
+    assert_eq!(first.anchor1(), Point3::new(-0.5, 0.0, 0.0));
+    assert_eq!(first.anchor2(), Point3::new(0.5, 0.0, 0.0));
+    let last_joints: Vec<_> = links[2]
+        .iter_components::<SphericalJoint>()
+        .filter_map(|j| j.connected())
+        .collect();
+    assert_eq!(last_joints, vec![links[1], end]);
+
+    for _ in 0..30 {
+        world.fixed_update();
+    }
+
+    assert!(links[1].transform.position().y < 0.0);
+    let mut chain = vec![start];
+    chain.extend(&links);
+    chain.push(end);
+    for pair in chain.windows(2) {
+        let gap = (pair[1].transform.position() - pair[0].transform.position()).norm();
+        assert!(gap <= 1.05, "links drifted apart: {gap}");
+    }
+}
+
+#[test]
+fn joint_chain_spring_uses_link_length() {
+    let (mut world, ..) = World::fresh();
+    let mut start = world.new_object("Start");
+    let mut end = world.new_object("End");
+    end.transform.set_position(0.0, -3.0, 0.0);
+    start.add_component::<RigidBodyComponent>();
+    end.add_component::<RigidBodyComponent>();
+
+    let kind = ChainJointKind::Spring {
+        stiffness: 50.0,
+        damping: 2.0,
+    };
+    let links = JointChain::build(&mut world, start, end, 2, kind).unwrap();
+    let spring = links[0].get_component::<SpringJoint>().unwrap();
+    assert!((spring.rest_length() - 1.0).abs() < 1e-6);
+    assert_eq!(spring.stiffness(), 50.0);
+    assert_eq!(spring.connected(), Some(start));
+
+    let lonely = world.new_object("Lonely");
+    assert!(JointChain::build(&mut world, start, lonely, 2, kind).is_err());
+}
+
+#[test]
+fn multiple_joints_of_same_type_on_one_object() {
+    let (mut world, ..) = World::fresh();
+    let mut plate = world.new_object("Plate");
+    let mut left = world.new_object("Left");
+    let mut right = world.new_object("Right");
+    plate.add_component::<RigidBodyComponent>();
+    left.add_component::<RigidBodyComponent>();
+    right.add_component::<RigidBodyComponent>();
+
+    plate.add_component::<FixedJoint>().connect_to(left);
+    plate.add_component::<FixedJoint>().connect_to(right);
+
+    let joints = plate.get_components::<FixedJoint>();
+    assert_eq!(joints.len(), 2);
+    assert_eq!(joints[0].connected(), Some(left));
+    assert_eq!(joints[1].connected(), Some(right));
+    assert_ne!(joints[0].handle(), joints[1].handle());
+    assert_eq!(world.physics.impulse_joint_set.len(), 2);
+
+    let mut to_left = joints[0].clone();
+    to_left.disconnect(&mut world);
+    assert!(joints[1].is_connected());
+    assert_eq!(world.physics.impulse_joint_set.len(), 1);
+
+    assert!(plate.remove_component_by_id(&joints[1]));
+    assert_eq!(plate.get_components::<FixedJoint>().len(), 1);
+    assert_eq!(world.physics.impulse_joint_set.len(), 0);
+}
+
+fn welded_pairs(world: &mut World, count: usize) -> Vec<(CRef<FixedJoint>, GameObjectId)> {
+    (0..count)
+        .map(|i| {
+            let mut a = world.new_object(format!("A{i}"));
+            let mut b = world.new_object(format!("B{i}"));
+            a.add_component::<RigidBodyComponent>();
+            b.add_component::<RigidBodyComponent>();
+            let mut joint = a.add_component::<FixedJoint>();
+            joint.connect_to(b);
+            (joint, b)
+        })
+        .collect()
+}
+
+#[test]
+fn joint_disable_keeps_handle() {
+    let (mut world, ..) = World::fresh();
+    let (mut joint, _) = welded_pairs(&mut world, 1).remove(0);
+    let handle = joint.handle();
+
+    joint.set_enabled(false);
+    assert!(!joint.is_enabled());
+    assert!(joint.is_connected());
+    assert_eq!(joint.handle(), handle);
+    assert!(!joint.joint_data().unwrap().is_enabled());
+
+    joint.reconnect(&mut world);
+    assert!(!joint.joint_data().unwrap().is_enabled());
+
+    joint.set_enabled(true);
+    assert!(joint.joint_data().unwrap().is_enabled());
+}
+
+/// Times 500 joints being switched off and on every frame, once by reconnecting and once by
+/// toggling them. Run with `cargo test --release --test joint -- --ignored`.
+#[test]
+#[ignore]
+fn joint_toggle_stress() {
+    const JOINTS: usize = 500;
+    const FRAMES: u32 = 200;
+
+    let (mut world, ..) = World::fresh();
+    let mut joints = welded_pairs(&mut world, JOINTS);
+
+    let mut reconnecting = Duration::ZERO;
+    for frame in 0..FRAMES {
+        let start = Instant::now();
+        for (joint, other) in &mut joints {
+            if frame % 2 == 0 {
+                joint.disconnect(&mut world);
+            } else {
+                joint.connect_to(*other);
+            }
+        }
+        reconnecting += start.elapsed();
+        world.physics.step();
+    }
+    assert!(joints.iter().all(|(joint, _)| joint.is_connected()));
+    assert_eq!(world.physics.impulse_joint_set.len(), JOINTS);
+
+    let handles: Vec<_> = joints.iter().map(|(joint, _)| joint.handle()).collect();
+    let mut toggling = Duration::ZERO;
+    for frame in 0..FRAMES {
+        let start = Instant::now();
+        for (joint, _) in &mut joints {
+            joint.set_enabled(frame % 2 != 0);
+        }
+        toggling += start.elapsed();
+        world.physics.step();
+    }
+    assert!(joints.iter().all(|(joint, _)| joint.is_enabled()));
+    let kept: Vec<_> = joints.iter().map(|(joint, _)| joint.handle()).collect();
+    assert_eq!(kept, handles);
+
+    let (reconnecting, toggling) = (reconnecting / FRAMES, toggling / FRAMES);
+    assert!(
+        toggling < reconnecting,
+        "toggling took {toggling:?} per frame, reconnecting {reconnecting:?}"
+    );
+}
+
+#[test]
+fn physics_dump_lists_bodies_and_joints() {
+    let (mut world, ..) = World::fresh();
+    let mut hinge = world.new_object("Hinge");
+    let mut door = world.new_object("Door");
+    hinge.transform.set_position(0.0, 2.0, 0.0);
+    hinge
+        .add_component::<RigidBodyComponent>()
+        .set_kinematic(true);
+    door.add_component::<RigidBodyComponent>();
+    let mut joint = door.add_component::<RevoluteJoint>();
+    joint.set_anchor1(Point3::new(0.5, 0.0, 0.0));
+    joint.connect_to(hinge);
+    world.physics.step();
+
+    let dump = world.dump_physics();
+
+    assert!(dump.contains("Bodies (2):"));
+    assert!(dump.contains("\"Hinge\" kinematic (position)"));
+    assert!(dump.contains("\"Door\" dynamic"));
+    assert!(dump.contains("position: (0.0000, 2.0000, 0.0000)"));
+    assert!(dump.contains("Joints (1):"));
+    assert!(dump.contains("revolute: #") && dump.contains("\"Door\" <-> #"));
+    assert!(dump.contains("anchor1:  (0.5000, 0.0000, 0.0000)"));
+}
+
+#[test]
+fn reaction_force_matches_hanging_weight() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut hook = world.new_object("Hook");
+    hook.transform.set_position(0.0, 5.0, 0.0);
+    hook.add_component::<RigidBodyComponent>()
+        .set_kinematic(true);
+    world.add_child(hook);
+
+    let mut weight = world.new_object("Weight");
+    weight.transform.set_position(0.0, 3.0, 0.0);
+    weight.add_component::<Collider3D>();
+    let rb = weight.add_component::<RigidBodyComponent>();
+    world.add_child(weight);
+
+    let mut joint = hook.add_component::<SphericalJoint>();
+    assert_eq!(joint.reaction_impulse(), None);
+    joint.set_anchor1(Point3::new(0.0, -1.0, 0.0));
+    joint.set_anchor2(Point3::new(0.0, 1.0, 0.0));
+    joint.connect_to(weight);
+
+    for _ in 0..120 {
+        world.fixed_update();
+    }
+
+    let mass = rb.body().unwrap().mass();
+    let (force, torque) = joint.reaction_impulse().unwrap();
+    assert!(
+        (force.norm() - mass * 9.81).abs() < 0.05 * mass * 9.81,
+        "{force:?}"
+    );
+    assert!(force.y < 0.0);
+    assert!(torque.norm() < 1e-3);
+}
+
+#[test]
+fn prismatic_motor_reports_arrival_at_target() {
+    let mut world = World::builder()
+        .gravity(Vector3::zeros())
+        .deterministic(true)
+        .build();
+    let mut shaft = world.new_object("Shaft");
+    shaft
+        .add_component::<RigidBodyComponent>()
+        .set_kinematic(true);
+    world.add_child(shaft);
+
+    let mut cabin = world.new_object("Cabin");
+    cabin.add_component::<Collider3D>();
+    cabin.add_component::<RigidBodyComponent>();
+    world.add_child(cabin);
+
+    let mut elevator = shaft.add_component::<PrismaticJoint>();
+    elevator.set_axis(Vector3::y_axis());
+    elevator.connect_to(cabin);
+    assert_eq!(elevator.is_at_motor_target(0.05), None);
+
+    elevator.set_motor_position(3.0, 200.0, 40.0);
+    assert_eq!(elevator.motor_target(), Some(3.0));
+    world.fixed_update();
+    assert_eq!(elevator.is_at_motor_target(0.05), Some(false));
+
+    let mut steps = 0;
+    while elevator.is_at_motor_target(0.05) == Some(false) && steps < 600 {
+        world.fixed_update();
+        steps += 1;
+    }
+    assert!(steps < 600);
+    assert!((cabin.transform.position().y - 3.0).abs() <= 0.05);
+}
+
+#[test]
+fn revolute_motor_target_without_position_motor_is_none() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut door = world.new_object("Door");
+    let mut frame = world.new_object("Frame");
+    door.add_component::<RigidBodyComponent>();
+    frame
+        .add_component::<RigidBodyComponent>()
+        .set_kinematic(true);
+
+    let mut hinge = frame.add_component::<RevoluteJoint>();
+    hinge.connect_to(door);
+    hinge.set_motor_velocity(1.0, 10.0);
+    assert_eq!(hinge.motor_target(), None);
+    assert_eq!(hinge.is_at_motor_target(0.1), None);
+
+    hinge.set_motor_position(0.0, 100.0, 10.0);
+    assert_eq!(hinge.is_at_motor_target(0.1), Some(true));
+}
+
+fn swing_door_into_limit(spring: Option<(f32, f32)>) -> Vec<f32> {
+    let mut world = World::builder()
+        .gravity(Vector3::zeros())
+        .deterministic(true)
+        .build();
+    let mut frame = world.new_object("Frame");
+    frame
+        .add_component::<RigidBodyComponent>()
+        .set_kinematic(true);
+    world.add_child(frame);
+
+    let mut door = world.new_object("Door");
+    door.transform.set_position(1.0, 0.0, 0.0);
+    door.transform.set_nonuniform_scale(2.0, 2.0, 0.1);
+    door.add_component::<Collider3D>();
+    let mut rb = door.add_component::<RigidBodyComponent>();
+    world.add_child(door);
+
+    let mut hinge = frame.add_component::<RevoluteJoint>();
+    hinge.set_anchor2(Point3::new(-1.0, 0.0, 0.0));
+    hinge.set_limits(-0.5, 0.5);
+    if let Some((stiffness, damping)) = spring {
+        hinge.set_limit_spring(stiffness, damping);
+    }
+    hinge.connect_to(door);
+    world.fixed_update();
+
+    // spin around the hinge, not the door's center
+    let body = rb.body_mut().unwrap();
+    body.set_angvel(Vector3::new(0.0, 4.0, 0.0), true);
+    body.set_linvel(Vector3::new(0.0, 0.0, -4.0), true);
+    (0..120)
+        .map(|_| {
+            world.fixed_update();
+            hinge.angle().unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn soft_limits_ease_the_joint_into_its_bounds() {
+    let hard = swing_door_into_limit(None);
+    assert!(hard.iter().all(|&a| a <= 0.5 + 1e-3));
+    assert!(hard[10..].iter().all(|&a| (a - 0.5).abs() < 1e-3));
+
+    let soft = swing_door_into_limit(Some((5.0, 1.0)));
+    let peak = soft.iter().copied().fold(0.0, f32::max);
+    let peak_at = soft.iter().position(|&a| a == peak).unwrap();
+    assert!(peak > 0.53, "{peak}");
+    // eases back without bouncing off the limit
+    assert!(soft[peak_at..].windows(2).all(|w| w[1] <= w[0] + 1e-5));
+    assert!((soft.last().unwrap() - 0.5).abs() < 1e-3);
+}
+
+#[test]
+fn fixed_joint_frees_single_axes_at_runtime() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut base = world.new_object("Base");
+    base.add_component::<RigidBodyComponent>()
+        .set_kinematic(true);
+    let mut wheel = world.new_object("Wheel");
+    wheel.add_component::<Collider3D>();
+    let wheel_body = wheel.add_component::<RigidBodyComponent>().body_handle;
+    world.add_child(base);
+    world.add_child(wheel);
+
+    let mut weld = wheel.add_component::<FixedJoint>();
+    weld.connect_to(base);
+    weld.set_locked_axes(JointAxesMask::LOCKED_FIXED_AXES - JointAxesMask::ANG_X);
+    assert_eq!(weld.joint_data().unwrap().locked_axes, weld.locked_axes());
+    world.fixed_update();
+
+    let spin = Vector3::new(5.0, 5.0, 0.0);
+    world.physics.rigid_body_set[wheel_body].set_angvel(spin, true);
+    for _ in 0..30 {
+        world.fixed_update();
+    }
+
+    // spins freely around X, but still held in place and around Y
+    let rb = &world.physics.rigid_body_set[wheel_body];
+    assert!((rb.angvel().x - 5.0).abs() < 0.1, "{:?}", rb.angvel());
+    assert!(rb.angvel().y.abs() < 0.01, "{:?}", rb.angvel());
+    assert!(rb.translation().norm() < 0.01);
+
+    weld.set_locked_axes(JointAxesMask::LOCKED_FIXED_AXES);
+    for _ in 0..30 {
+        world.fixed_update();
+    }
+    assert!(world.physics.rigid_body_set[wheel_body].angvel().norm() < 0.01);
+}
+
+#[test]
+fn fixed_joint_preserving_pose_holds_bodies_where_they_are() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut base = world.new_object("Base");
+    base.transform.set_euler_rotation_deg(0.0, 30.0, 0.0);
+    base.add_component::<RigidBodyComponent>()
+        .set_kinematic(true);
+    let mut cargo = world.new_object("Cargo");
+    cargo.transform.set_position(1.5, 0.5, 0.2);
+    cargo.transform.set_euler_rotation_deg(0.0, 0.0, 45.0);
+    cargo.add_component::<Collider3D>();
+    let cargo_body = cargo.add_component::<RigidBodyComponent>().body_handle;
+    world.add_child(base);
+    world.add_child(cargo);
+    let start = *world.physics.rigid_body_set[cargo_body].position();
+
+    let mut weld = cargo.add_component::<FixedJoint>();
+    weld.connect_to_preserving_pose(base);
+    assert!(weld.anchor_distance().unwrap() < 1e-5);
+    assert!(weld.rotation_error().unwrap() < 1e-5);
+
+    for _ in 0..60 {
+        world.fixed_update();
+    }
+
+    // held in place against gravity instead of snapping onto the base
+    let rb = &world.physics.rigid_body_set[cargo_body];
+    assert!(
+        (rb.translation() - start.translation.vector).norm() < 0.01,
+        "{:?}",
+        rb.translation()
+    );
+    assert!(rb.rotation().angle_to(&start.rotation) < 0.01);
+}
+
+/// How far a 20-link chain holding a 20kg weight stretches past its length
+fn chain_stretch(stiff: bool) -> f32 {
+    let mut world = World::builder().deterministic(true).build();
+    let mut crane = world.new_object("Crane");
+    crane
+        .add_component::<RigidBodyComponent>()
+        .set_kinematic(true);
+    world.add_child(crane);
+
+    let mut weight = world.new_object("Weight");
+    weight.transform.set_position(0.0, -21.0, 0.0);
+    weight.add_component::<Collider3D>().set_mass(20.0);
+    weight.add_component::<RigidBodyComponent>();
+    world.add_child(weight);
+
+    let links =
+        JointChain::build(&mut world, crane, weight, 20, ChainJointKind::Spherical).unwrap();
+    for link in links {
+        for mut joint in link.iter_components::<SphericalJoint>() {
+            joint.set_stiff(stiff);
+            assert_eq!(joint.is_stiff(), stiff);
+        }
+    }
+
+    for _ in 0..300 {
+        world.fixed_update();
+    }
+    // only the step itself runs with more iterations
+    assert_eq!(
+        world.physics.integration_parameters.num_solver_iterations,
+        4
+    );
+
+    -21.0 - weight.transform.position().y
+}
+
+#[test]
+fn stiff_joints_keep_long_chains_from_stretching() {
+    let loose = chain_stretch(false);
+    let stiff = chain_stretch(true);
+    assert!(stiff < 0.2, "{stiff}");
+    assert!(stiff * 5.0 < loose, "{stiff} vs {loose}");
+}
@@ -0,0 +1,154 @@
+use nalgebra::Vector3;
+use syrillian::World;
+use syrillian::assets::{Mesh, StoreType};
+use syrillian::components::{Collider3D, MeshRenderer};
+use syrillian::core::UpAxis;
+
+fn assert_outward_winding(mesh: &Mesh, center_of: impl Fn(Vector3<f32>) -> Vector3<f32>) {
+    let vertices = mesh.vertices();
+    let indices = mesh.indices().expect("primitives are indexed");
+
+    for tri in indices.chunks_exact(3) {
+        let a = vertices[tri[0] as usize].position;
+        let b = vertices[tri[1] as usize].position;
+        let c = vertices[tri[2] as usize].position;
+
+        let face_normal = (b - a).cross(&(c - a));
+        let centroid = (a + b + c) / 3.0;
+        assert!(
+            face_normal.dot(&(centroid - center_of(centroid))) > 0.0,
+            "triangle {tri:?} is wound inwards"
+        );
+    }
+}
+
+fn assert_unit_normals(mesh: &Mesh) {
+    for v in mesh.vertices() {
+        assert!((v.normal.norm() - 1.0).abs() < 1e-4);
+        assert!((0.0..=1.0).contains(&v.uv.x) && (0.0..=1.0).contains(&v.uv.y));
+    }
+}
+
+#[test]
+fn plane_is_subdivided_and_faces_up() {
+    let mesh = Mesh::plane(2.0, 4.0, 3);
+
+    assert_eq!(mesh.vertex_count(), 16);
+    assert_eq!(mesh.triangle_count(), 18);
+    assert_unit_normals(&mesh);
+
+    for v in mesh.vertices() {
+        assert_eq!(v.normal, Vector3::y());
+        assert!(v.position.x.abs() <= 1.0 && v.position.z.abs() <= 2.0);
+    }
+    assert_outward_winding(&mesh, |p| Vector3::new(p.x, -1.0, p.z));
+}
+
+#[test]
+fn cube_matches_unit_extents() {
+    let mesh = Mesh::cube(1.0);
+
+    assert_eq!(mesh.vertex_count(), 24);
+    assert_eq!(mesh.triangle_count(), 12);
+    assert_unit_normals(&mesh);
+
+    for v in mesh.vertices() {
+        assert_eq!(v.position.abs().max(), 0.5);
+    }
+    assert_outward_winding(&mesh, |_| Vector3::zeros());
+}
+
+#[test]
+fn uv_sphere_has_radius_normals() {
+    let mesh = Mesh::uv_sphere(16);
+
+    assert_unit_normals(&mesh);
+    for v in mesh.vertices() {
+        assert!((v.position.norm() - 1.0).abs() < 1e-4);
+        assert!((v.position - v.normal).norm() < 1e-4);
+    }
+    assert!((mesh.bounding_sphere.radius - 1.0).abs() < 1e-4);
+    assert_outward_winding(&mesh, |_| Vector3::zeros());
+}
+
+#[test]
+fn capsule_spans_full_height() {
+    let mesh = Mesh::capsule(1.0, 0.5, 16);
+
+    assert_unit_normals(&mesh);
+    let max_y = mesh
+        .vertices()
+        .iter()
+        .map(|v| v.position.y)
+        .fold(f32::MIN, f32::max);
+    let min_y = mesh
+        .vertices()
+        .iter()
+        .map(|v| v.position.y)
+        .fold(f32::MAX, f32::min);
+
+    assert!((max_y - 1.5).abs() < 1e-4);
+    assert!((min_y + 1.5).abs() < 1e-4);
+    assert_outward_winding(&mesh, |p| Vector3::new(0.0, p.y.clamp(-1.0, 1.0), 0.0));
+}
+
+#[test]
+fn handle_constructors_store_the_generated_mesh() {
+    let (mut world, ..) = World::fresh();
+
+    let cube = Mesh::cube_handle(world.as_ref(), 1.0);
+    let sphere = Mesh::uv_sphere_handle(world.as_ref(), 16);
+    let capsule = Mesh::capsule_handle(world.as_ref(), 1.0, 0.5, 16);
+    let plane = Mesh::plane_handle(world.as_ref(), 2.0, 2.0, 2);
+    let cuboid = Mesh::cuboid_handle(world.as_ref(), Vector3::new(1.0, 2.0, 3.0));
+
+    let handles = [cube, sphere, capsule, plane, cuboid];
+    for (i, handle) in handles.iter().enumerate() {
+        assert!(!handles[..i].contains(handle));
+        assert!(!StoreType::is_builtin(*handle));
+    }
+
+    let stored = world.assets.meshes.try_get(sphere).unwrap().vertex_count();
+    assert_eq!(stored, Mesh::uv_sphere(16).vertex_count());
+
+    let mut crate_obj = world.new_object("Crate");
+    crate_obj.add_component::<MeshRenderer>().set_mesh(cube);
+    crate_obj.add_component::<Collider3D>();
+    assert!(crate_obj.get_component::<Collider3D>().is_some());
+}
+
+#[test]
+fn mesh_renderer_vertices_can_be_deformed() {
+    let (mut world, ..) = World::fresh();
+    let mesh = Mesh::plane(1.0, 1.0, 4).store(world.as_ref());
+
+    let mut obj = world.new_object("Cloth");
+    let mut renderer = obj.add_component::<MeshRenderer>();
+    renderer.set_mesh(mesh);
+
+    {
+        let mut vertices = renderer.vertices_mut().expect("mesh was stored");
+        for v in vertices.iter_mut() {
+            v.position.y += 3.0;
+        }
+    }
+    renderer.mark_dirty(false);
+
+    let stored = world.assets.meshes.try_get(mesh).unwrap();
+    assert!(stored.vertices().iter().all(|v| v.position.y == 3.0));
+    assert!((stored.bounding_sphere.center.y - 3.0).abs() < 1e-5);
+}
+
+#[test]
+fn obj_meshes_are_turned_upright_for_z_up() {
+    const RAMP: &[u8] = b"v 0 0 0\nv 1 0 0\nv 0 1 -1\nvt 0 0\nvn 0 1 0\nf 1/1/1 2/1/1 3/1/1\n";
+
+    let y_up = Mesh::load_from_obj_slice(RAMP).unwrap();
+    assert_eq!(y_up.vertices()[2].position, Vector3::new(0.0, 1.0, -1.0));
+
+    let z_up = Mesh::load_from_obj_slice_with_up_axis(RAMP, UpAxis::Z).unwrap();
+    let top = z_up.vertices()[2];
+    assert!((top.position - Vector3::new(0.0, 1.0, 1.0)).norm() < 1e-6);
+    assert!((top.normal - Vector3::z()).norm() < 1e-6);
+    assert_eq!(z_up.vertices()[1].position, Vector3::new(1.0, 0.0, 0.0));
+}
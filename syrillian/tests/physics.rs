@@ -0,0 +1,1424 @@
+use nalgebra::{Isometry3, Point3, Vector3};
+use rapier3d::prelude::{Cuboid, QueryFilter, Ray, SharedShape};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use syrillian::World;
+use syrillian::components::{Collider3D, GravityZoneComponent, MassMode, RigidBodyComponent};
+use syrillian::core::{GameObjectId, WorldEvent};
+use syrillian::physics::{
+    BroadPhaseConfig, BroadPhaseOptimization, LayerMask, PhysicsMaterial, PhysicsProfile,
+    PhysicsSnapshot, QueryFilterBuilder,
+};
+
+fn spawn_box(world: &mut World, name: &str, pos: Vector3<f32>) -> GameObjectId {
+    let mut obj = world.new_object(name);
+    obj.transform.set_position_vec(pos);
+    obj.add_component::<Collider3D>();
+    world.add_child(obj);
+    obj
+}
+
+#[test]
+fn batched_raycasts_keep_input_order() {
+    let (mut world, ..) = World::fresh();
+    let right = spawn_box(&mut world, "Right", Vector3::new(5.0, 0.0, 0.0));
+    let left = spawn_box(&mut world, "Left", Vector3::new(-5.0, 0.0, 0.0));
+    world.physics.step();
+
+    let origin = Point3::origin();
+    let rays = [
+        Ray::new(origin, Vector3::x()),
+        Ray::new(origin, Vector3::y()),
+        Ray::new(origin, -Vector3::x()),
+    ];
+
+    let hits = world
+        .physics
+        .cast_ray_batch(&rays, 100.0, true, QueryFilter::default());
+
+    assert_eq!(hits.len(), 3);
+    let (toi, obj) = hits[0].expect("ray towards +x should hit");
+    assert_eq!(obj, right);
+    assert!((toi - 4.5).abs() < 1e-4);
+    assert!(hits[1].is_none());
+    assert_eq!(hits[2].map(|(_, o)| o), Some(left));
+
+    for (ray, hit) in rays.iter().zip(&hits) {
+        let single = world
+            .physics
+            .cast_ray(ray, 100.0, true, QueryFilter::default());
+        assert_eq!(single, *hit);
+    }
+}
+
+fn drop_body(steps: usize) -> Vector3<f32> {
+    let mut world = World::builder()
+        .gravity(Vector3::new(0.0, -2.0, 0.0))
+        .timestep(Duration::from_secs_f64(1.0 / 50.0))
+        .solver_iterations(8)
+        .deterministic(true)
+        .headless(true)
+        .build();
+
+    let mut obj = world.new_object("Falling");
+    obj.transform.set_position(0.0, 10.0, 0.0);
+    obj.add_component::<Collider3D>();
+    let rb = obj.add_component::<RigidBodyComponent>();
+    world.add_child(obj);
+
+    for _ in 0..steps {
+        world.fixed_update();
+        world.update();
+        world.post_update();
+        world.next_frame();
+    }
+
+    *rb.body().unwrap().linvel()
+}
+
+#[test]
+fn world_builder_configures_physics() {
+    let world = World::builder()
+        .gravity(Vector3::new(0.0, -1.62, 0.0))
+        .timestep(Duration::from_secs_f64(1.0 / 120.0))
+        .solver_iterations(2)
+        .build();
+
+    assert!(world.is_headless());
+    assert!(!world.physics.deterministic);
+    assert_eq!(world.physics.gravity, Vector3::new(0.0, -1.62, 0.0));
+    assert_eq!(world.physics.timestep, Duration::from_secs_f64(1.0 / 120.0));
+    assert!((world.physics.integration_parameters.dt - 1.0 / 120.0).abs() < 1e-6);
+    assert_eq!(
+        world.physics.integration_parameters.num_solver_iterations,
+        2
+    );
+}
+
+#[test]
+fn deterministic_world_steps_once_per_fixed_update() {
+    let velocity = drop_body(10);
+    assert_eq!(drop_body(10), velocity);
+
+    let gained = drop_body(20) - velocity;
+    assert!((gained.y + 2.0 / 50.0 * 10.0).abs() < 1e-4, "{gained:?}");
+}
+
+#[test]
+fn collider_local_transform_offsets_shape_only() {
+    let mut world = World::builder().deterministic(true).build();
+    let offset = Isometry3::translation(0.0, 1.0, 0.0);
+
+    let mut standalone = world.new_object("Standalone");
+    standalone.transform.set_position(2.0, 0.0, 0.0);
+    let mut collider = standalone.add_component::<Collider3D>();
+    collider.set_local_transform(offset);
+    world.add_child(standalone);
+
+    let pos = collider.collider().unwrap().position().translation.vector;
+    assert_eq!(pos, Vector3::new(2.0, 1.0, 0.0));
+
+    let mut character = world.new_object("Character");
+    character.transform.set_position(-2.0, 0.0, 0.0);
+    let mut body_collider = character.add_component::<Collider3D>();
+    character
+        .add_component::<RigidBodyComponent>()
+        .set_kinematic(true);
+    body_collider.set_local_transform(offset);
+    world.add_child(character);
+
+    for _ in 0..3 {
+        world.fixed_update();
+    }
+
+    let collider = body_collider.collider().unwrap();
+    assert_eq!(collider.position_wrt_parent(), Some(&offset));
+    assert!((collider.position().translation.vector - Vector3::new(-2.0, 1.0, 0.0)).norm() < 1e-5);
+    assert_eq!(character.transform.position(), Vector3::new(-2.0, 0.0, 0.0));
+    assert_eq!(standalone.transform.position(), Vector3::new(2.0, 0.0, 0.0));
+}
+
+#[test]
+fn non_interpolated_body_renders_at_current_transform() {
+    let mut world = World::builder().deterministic(true).build();
+
+    let mut obj = world.new_object("Cursor");
+    let mut rb = obj.add_component::<RigidBodyComponent>();
+    rb.set_kinematic(true);
+    world.add_child(obj);
+    assert!(rb.is_interpolated());
+
+    obj.transform.set_position(1.0, 0.0, 0.0);
+    world.fixed_update();
+    obj.transform.set_position(3.0, 0.0, 0.0);
+    world.fixed_update();
+
+    let lagging = rb.render_isometry(0.0).translation.vector;
+    assert_ne!(lagging, Vector3::new(3.0, 0.0, 0.0));
+
+    rb.set_interpolated(false);
+    obj.transform.set_position(5.0, 0.0, 0.0);
+    for alpha in [0.0, 0.5, 1.0] {
+        let exact = rb.render_isometry(alpha).translation.vector;
+        assert_eq!(exact, Vector3::new(5.0, 0.0, 0.0));
+    }
+}
+
+#[test]
+fn contacts_report_world_space_manifold() {
+    let mut world = World::builder().deterministic(true).build();
+
+    let mut ground = world.new_object("Ground");
+    ground.transform.set_nonuniform_scale(10.0, 1.0, 10.0);
+    let ground_collider = ground.add_component::<Collider3D>();
+    world.add_child(ground);
+
+    let mut crate_obj = world.new_object("Crate");
+    crate_obj.transform.set_position(0.0, 1.2, 0.0);
+    let crate_collider = crate_obj.add_component::<Collider3D>();
+    crate_obj.add_component::<RigidBodyComponent>();
+    world.add_child(crate_obj);
+
+    for _ in 0..60 {
+        world.fixed_update();
+    }
+
+    let contacts = crate_collider.contacts();
+    assert_eq!(contacts.len(), 1);
+    let contact = &contacts[0];
+    assert_eq!(contact.other, ground_collider.phys_handle);
+    assert_eq!(contact.other_object, Some(ground));
+    assert!((contact.normal - -Vector3::y()).norm() < 1e-3);
+    assert!(!contact.points.is_empty());
+    for point in &contact.points {
+        assert!((point.position.y - 0.5).abs() < 0.05, "{point:?}");
+        assert!(point.position.x.abs() <= 0.5 + 1e-3);
+        assert!(point.depth > -0.05);
+    }
+
+    let from_ground = ground_collider.contacts();
+    assert_eq!(from_ground.len(), 1);
+    assert_eq!(from_ground[0].other_object, Some(crate_obj));
+    assert!((from_ground[0].normal - Vector3::y()).norm() < 1e-3);
+}
+
+#[test]
+fn query_filter_builder_excludes_objects() {
+    let mut world = World::builder().deterministic(true).build();
+    let near = spawn_box(&mut world, "Near", Vector3::new(3.0, 0.0, 0.0));
+    let mut far = spawn_box(&mut world, "Far", Vector3::new(6.0, 0.0, 0.0));
+    far.add_component::<RigidBodyComponent>()
+        .set_kinematic(true);
+    let sensor = spawn_box(&mut world, "Sensor", Vector3::new(9.0, 0.0, 0.0));
+    sensor
+        .get_component::<Collider3D>()
+        .unwrap()
+        .collider_mut()
+        .unwrap()
+        .set_sensor(true);
+    world.fixed_update();
+
+    let ray = Ray::new(Point3::origin(), Vector3::x());
+    let cast = |filter: &QueryFilterBuilder| {
+        world
+            .physics
+            .cast_ray(&ray, 100.0, true, filter.build())
+            .map(|(_, obj)| obj)
+    };
+
+    assert_eq!(cast(&QueryFilterBuilder::new()), Some(near));
+    assert_eq!(cast(&QueryFilterBuilder::new().exclude(near)), Some(far));
+    assert_eq!(cast(&QueryFilterBuilder::new().only_kinematic()), Some(far));
+
+    let everything = QueryFilterBuilder::new().exclude(near).exclude(far);
+    assert_eq!(cast(&everything), Some(sensor));
+    assert_eq!(cast(&everything.exclude_sensors()), None);
+}
+
+#[test]
+fn exclude_many_drops_every_collider_of_the_objects() {
+    let mut world = World::builder()
+        .gravity(Vector3::zeros())
+        .deterministic(true)
+        .build();
+    let mut caster = spawn_box(&mut world, "Caster", Vector3::zeros());
+    // a second collider, so the caster has to be excluded as a whole
+    caster
+        .add_component::<Collider3D>()
+        .set_local_transform(Isometry3::translation(1.0, 0.0, 0.0));
+    caster.add_component::<RigidBodyComponent>();
+    let minion = spawn_box(&mut world, "Minion", Vector3::new(0.0, 0.0, 2.0));
+    let other_minion = spawn_body(&mut world, "Other Minion", Vector3::new(0.0, 0.0, -2.0));
+    let enemy = spawn_box(&mut world, "Enemy", Vector3::new(-2.0, 0.0, 0.0));
+    world.fixed_update();
+
+    let area = SharedShape::ball(3.0);
+    let hit = |filter: &QueryFilterBuilder| {
+        let mut hit =
+            world.overlapping_objects(area.as_ref(), Isometry3::identity(), filter.build());
+        hit.sort_by_key(|obj| obj.name.clone());
+        hit
+    };
+
+    assert_eq!(hit(&QueryFilterBuilder::new()).len(), 4);
+    let filter = QueryFilterBuilder::new().exclude_many(&[caster, minion, other_minion]);
+    assert_eq!(hit(&filter), vec![enemy]);
+    assert_eq!(hit(&filter.exclude(enemy)), vec![]);
+}
+
+fn shoot_ball_at_table(substeps: usize) -> f32 {
+    let mut world = World::builder().deterministic(true).build();
+    world.set_physics_substeps(substeps);
+
+    let mut table = world.new_object("Table");
+    table.transform.set_nonuniform_scale(10.0, 0.01, 10.0);
+    table.add_component::<Collider3D>();
+    world.add_child(table);
+
+    let mut ball = world.new_object("Ball");
+    ball.transform.set_position(0.0, 2.0, 0.0);
+    ball.add_component::<Collider3D>()
+        .set_shape(SharedShape::ball(0.1));
+    let mut rb = ball.add_component::<RigidBodyComponent>();
+    world.add_child(ball);
+    world.fixed_update();
+    rb.body_mut()
+        .unwrap()
+        .set_linvel(Vector3::new(10.0, -60.0, 0.0), true);
+
+    for _ in 0..30 {
+        world.fixed_update();
+    }
+
+    ball.transform.position().y
+}
+
+#[test]
+fn substeps_keep_fast_ball_on_thin_table() {
+    assert!(shoot_ball_at_table(1) < 0.0);
+    assert!(shoot_ball_at_table(16) > 0.05);
+}
+
+#[test]
+fn trigger_stay_set_matches_enter_and_exit() {
+    let mut world = World::builder()
+        .gravity(Vector3::zeros())
+        .deterministic(true)
+        .build();
+
+    let fire = spawn_box(&mut world, "Fire", Vector3::zeros());
+    let fire_collider = fire.get_component::<Collider3D>().unwrap();
+    fire_collider.collider_mut().unwrap().set_sensor(true);
+
+    let mut player = world.new_object("Player");
+    player.transform.set_position(-3.0, 0.0, 0.0);
+    player.add_component::<Collider3D>();
+    let mut rb = player.add_component::<RigidBodyComponent>();
+    world.add_child(player);
+    world.fixed_update();
+    rb.body_mut()
+        .unwrap()
+        .set_linvel(Vector3::new(6.0, 0.0, 0.0), true);
+
+    let mut entered = 0;
+    let mut exited = 0;
+    let mut frames_inside = 0;
+    let mut inside = false;
+    for _ in 0..120 {
+        world.fixed_update();
+
+        for event in world.physics.trigger_events() {
+            assert_eq!(event.sensor(), fire_collider.phys_handle);
+            inside = event.is_enter();
+            if inside {
+                entered += 1;
+            } else {
+                exited += 1;
+            }
+        }
+
+        let overlapping: Vec<_> = fire_collider.currently_overlapping().collect();
+        assert_eq!(overlapping.contains(&player), inside);
+        if inside {
+            frames_inside += 1;
+        }
+    }
+
+    assert_eq!((entered, exited), (1, 1));
+    assert!(frames_inside > 10, "{frames_inside}");
+    assert_eq!(fire_collider.currently_overlapping().count(), 0);
+}
+
+#[test]
+fn constant_torque_reaches_steady_spin() {
+    let mut world = World::builder()
+        .gravity(Vector3::zeros())
+        .deterministic(true)
+        .build();
+
+    let mut turret = world.new_object("Turret");
+    turret.add_component::<Collider3D>();
+    let mut rb = turret.add_component::<RigidBodyComponent>();
+    world.add_child(turret);
+    rb.body_mut().unwrap().set_angular_damping(2.0);
+    world.fixed_update();
+
+    rb.body_mut().unwrap().sleep();
+    rb.apply_torque(Vector3::new(0.0, 1.0, 0.0));
+    assert!(!rb.body().unwrap().is_sleeping());
+
+    let mut spin = |steps: usize, torque: f32| {
+        for _ in 0..steps {
+            rb.apply_torque(Vector3::new(0.0, torque, 0.0));
+            world.fixed_update();
+        }
+        rb.body().unwrap().angvel().y
+    };
+
+    let early = spin(10, 1.0);
+    let settled = spin(300, 1.0);
+    let later = spin(100, 1.0);
+    assert!(early > 0.0 && early < settled);
+    assert!(
+        (later - settled).abs() < 1e-3 * settled,
+        "{settled} {later}"
+    );
+
+    let coasting = spin(100, 0.0);
+    assert!(coasting < settled * 0.1, "{coasting}");
+}
+
+#[test]
+fn off_center_impulses_push_and_spin() {
+    let mut world = World::builder()
+        .gravity(Vector3::zeros())
+        .deterministic(true)
+        .build();
+    let target = spawn_body(&mut world, "Target", Vector3::zeros());
+    world.fixed_update();
+
+    let mut rb = target.get_component::<RigidBodyComponent>().unwrap();
+    rb.body_mut().unwrap().sleep();
+    // a shot along -Z hitting the right edge of the box
+    rb.apply_impulse_at_point(Vector3::new(0.0, 0.0, -2.0), Point3::new(0.9, 0.0, 0.0));
+    assert!(!rb.body().unwrap().is_sleeping());
+
+    for _ in 0..10 {
+        world.fixed_update();
+    }
+
+    let position = target.transform.position();
+    assert!(position.z < -0.1, "{position:?}");
+    // r × J = (0.9, 0, 0) × (0, 0, -2) spins it around +Y
+    let angvel = *rb.body().unwrap().angvel();
+    assert!(angvel.y > 0.1, "{angvel:?}");
+    assert!(target.transform.rotation().angle() > 0.01);
+}
+
+fn resting_height(configure: impl FnOnce(&mut World)) -> f32 {
+    let mut world = World::builder().deterministic(true).build();
+    configure(&mut world);
+
+    let mut ground = world.new_object("Ground");
+    ground.transform.set_nonuniform_scale(10.0, 1.0, 10.0);
+    ground.add_component::<Collider3D>();
+    world.add_child(ground);
+
+    let mut crate_obj = world.new_object("Crate");
+    crate_obj.transform.set_position(0.0, 1.2, 0.0);
+    crate_obj.add_component::<Collider3D>();
+    crate_obj.add_component::<RigidBodyComponent>();
+    world.add_child(crate_obj);
+
+    for _ in 0..180 {
+        world.fixed_update();
+    }
+    crate_obj.transform.position().y
+}
+
+#[test]
+fn soft_contacts_let_bodies_sink_in() {
+    let default_erp = World::builder().build().contact_erp();
+    let rigid = resting_height(|_| {});
+    let soft = resting_height(|world| {
+        world.set_contact_natural_frequency(2.0);
+        world.set_contact_damping_ratio(1.0);
+        assert!(world.contact_erp() < default_erp);
+    });
+
+    assert!((rigid - 1.0).abs() < 0.01, "{rigid}");
+    assert!(soft < rigid - 0.01, "{soft} {rigid}");
+}
+
+fn spawn_body(world: &mut World, name: &str, pos: Vector3<f32>) -> GameObjectId {
+    let mut obj = spawn_box(world, name, pos);
+    obj.add_component::<RigidBodyComponent>();
+    obj
+}
+
+#[test]
+fn snapshot_delta_tracks_moved_added_and_removed_bodies() {
+    let mut world = World::builder()
+        .gravity(Vector3::zeros())
+        .deterministic(true)
+        .build();
+    let mover = spawn_body(&mut world, "Mover", Vector3::new(-5.0, 0.0, 0.0));
+    let drifter = spawn_body(&mut world, "Drifter", Vector3::new(0.0, 0.0, 0.0));
+    let resting = spawn_body(&mut world, "Resting", Vector3::new(5.0, 0.0, 0.0));
+    let resting_uuid = resting.uuid();
+    world.fixed_update();
+
+    let mut sent = PhysicsSnapshot::capture(&world);
+    assert_eq!(sent.len(), 3);
+    assert!(sent.diff(&sent).is_empty());
+
+    let mut rb = mover.get_component::<RigidBodyComponent>().unwrap();
+    rb.body_mut()
+        .unwrap()
+        .set_linvel(Vector3::new(0.0, 2.0, 0.0), true);
+    let nudge = |mut obj: GameObjectId| {
+        let mut rb = obj.get_component::<RigidBodyComponent>().unwrap();
+        let body = rb.body_mut().unwrap();
+        let pos = body.translation() + Vector3::new(0.0, 0.0, 0.004);
+        body.set_translation(pos, false);
+        obj.transform.set_position_vec(pos);
+    };
+    nudge(drifter);
+    world.fixed_update();
+
+    let added = spawn_body(&mut world, "Added", Vector3::new(0.0, 5.0, 0.0));
+    world.delete_object(resting);
+    world.fixed_update();
+
+    let current = PhysicsSnapshot::capture(&world);
+    let delta = sent.diff_with_threshold(&current, 0.01);
+    let changed: Vec<_> = delta.changed.iter().map(|(uuid, _)| *uuid).collect();
+    assert_eq!(changed, vec![mover.uuid()]);
+    assert_eq!(delta.added.len(), 1);
+    assert_eq!(delta.added[0].0, added.uuid());
+    assert_eq!(delta.removed, vec![resting_uuid]);
+
+    let mut received = sent.clone();
+    received.apply_delta(&delta);
+    sent.apply_delta(&delta);
+    assert_eq!(received, sent);
+    assert_eq!(received.get(mover.uuid()), current.get(mover.uuid()));
+    assert!(received.get(resting_uuid).is_none());
+
+    // the small drift is held back until it adds up
+    assert!(received.get(drifter.uuid()) != current.get(drifter.uuid()));
+    let drift_sent: Vec<bool> = (0..2)
+        .map(|_| {
+            nudge(drifter);
+            let delta = sent.diff_with_threshold(&PhysicsSnapshot::capture(&world), 0.01);
+            sent.apply_delta(&delta);
+            delta
+                .changed
+                .iter()
+                .any(|(uuid, _)| *uuid == drifter.uuid())
+        })
+        .collect();
+    assert_eq!(drift_sent, [false, true]);
+}
+
+#[test]
+fn snapshot_restore_rewinds_bodies() {
+    let mut world = World::builder().deterministic(true).build();
+    let ball = spawn_body(&mut world, "Ball", Vector3::new(0.0, 10.0, 0.0));
+    world.fixed_update();
+
+    let snapshot = PhysicsSnapshot::capture(&world);
+    for _ in 0..30 {
+        world.fixed_update();
+    }
+    assert!(ball.transform.position().y < 9.0);
+
+    assert_eq!(snapshot.restore(&mut world), 1);
+    let rb = ball.get_component::<RigidBodyComponent>().unwrap();
+    let state = snapshot.get(ball.uuid()).unwrap();
+    assert_eq!(rb.body().unwrap().translation(), &state.position);
+    assert_eq!(rb.body().unwrap().linvel(), &state.linvel);
+    assert_eq!(ball.transform.position(), state.position);
+}
+
+#[test]
+fn line_of_sight_is_blocked_by_solid_colliders_only() {
+    let mut world = World::builder().deterministic(true).build();
+    let guard = spawn_box(&mut world, "Guard", Vector3::new(0.0, 0.0, 0.0));
+    let thief = spawn_box(&mut world, "Thief", Vector3::new(10.0, 0.0, 0.0));
+    let trigger = spawn_box(&mut world, "Trigger", Vector3::new(5.0, 0.0, 0.0));
+    trigger
+        .get_component::<Collider3D>()
+        .unwrap()
+        .collider_mut()
+        .unwrap()
+        .set_sensor(true);
+    world.fixed_update();
+
+    assert!(world.has_line_of_sight(guard, thief, QueryFilterBuilder::new()));
+    assert!(world.has_line_of_sight(thief, guard, QueryFilterBuilder::new()));
+
+    spawn_box(&mut world, "Pillar", Vector3::new(5.0, 0.0, 3.0));
+    world.fixed_update();
+    assert!(world.has_line_of_sight(guard, thief, QueryFilterBuilder::new()));
+
+    let wall = spawn_box(&mut world, "Wall", Vector3::new(7.0, 0.0, 0.0));
+    world.fixed_update();
+    assert!(!world.has_line_of_sight(guard, thief, QueryFilterBuilder::new()));
+    assert!(world.has_line_of_sight(guard, thief, QueryFilterBuilder::new().exclude(wall)));
+}
+
+#[test]
+fn overlap_test_validates_building_placement() {
+    let mut world = World::builder().deterministic(true).build();
+    let house = spawn_box(&mut world, "House", Vector3::new(0.0, 0.5, 0.0));
+    let mut ground = spawn_box(&mut world, "Ground", Vector3::new(0.0, -0.5, 0.0));
+    ground.transform.set_nonuniform_scale(20.0, 1.0, 20.0);
+    // the collider picks up the new scale in the first step, and the broad phase in the next
+    world.fixed_update();
+    world.fixed_update();
+
+    // footprint slightly above the ground, so only buildings block it
+    let footprint = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let at = |x: f32| Isometry3::translation(x, 0.51, 0.0);
+    let filter = || QueryFilterBuilder::new().exclude(ground);
+
+    assert!(world.test_overlap(&footprint, at(0.8), filter().build()));
+    assert_eq!(
+        world.overlapping_objects(&footprint, at(0.8), filter().build()),
+        vec![house]
+    );
+    assert!(!world.test_overlap(&footprint, at(5.0), filter().build()));
+
+    let sunken = Isometry3::translation(5.0, 0.4, 0.0);
+    let found = world.overlapping_objects(&footprint, sunken, QueryFilter::default());
+    assert_eq!(found, vec![ground]);
+}
+
+#[test]
+fn velocity_clamp_caps_huge_impulses() {
+    let mut world = World::builder()
+        .gravity(Vector3::zeros())
+        .deterministic(true)
+        .build();
+
+    let mut obj = world.new_object("Cannonball");
+    obj.add_component::<Collider3D>();
+    let mut rb = obj.add_component::<RigidBodyComponent>();
+    world.add_child(obj);
+    rb.set_max_linvel(20.0);
+    rb.set_max_angvel(5.0);
+    world.fixed_update();
+
+    let body = rb.body_mut().unwrap();
+    body.apply_impulse(Vector3::new(1000.0, 0.0, 1000.0), true);
+    body.apply_torque_impulse(Vector3::new(0.0, 1000.0, 0.0), true);
+    world.fixed_update();
+
+    let body = rb.body().unwrap();
+    assert!((body.linvel().norm() - 20.0).abs() < 1e-3);
+    assert!((body.linvel().x - body.linvel().z).abs() < 1e-3);
+    assert!((body.angvel().norm() - 5.0).abs() < 1e-3);
+
+    rb.set_max_linvel(f32::INFINITY);
+    rb.body_mut()
+        .unwrap()
+        .apply_impulse(Vector3::new(1000.0, 0.0, 0.0), true);
+    world.fixed_update();
+    assert!(rb.body().unwrap().linvel().norm() > 20.0);
+}
+
+#[test]
+fn material_presets_propagate_when_updated() {
+    let mut world = World::builder().deterministic(true).build();
+    world
+        .physics
+        .register_material("ice", PhysicsMaterial::new(0.02, 0.0));
+    world
+        .physics
+        .register_material("rubber", PhysicsMaterial::new(0.9, 0.8));
+
+    let rink = spawn_box(&mut world, "Rink", Vector3::zeros());
+    let puck = spawn_box(&mut world, "Puck", Vector3::new(0.0, 2.0, 0.0));
+    let ball = spawn_box(&mut world, "Ball", Vector3::new(3.0, 2.0, 0.0));
+    let collider = |obj: GameObjectId| obj.get_component::<Collider3D>().unwrap();
+
+    assert!(collider(rink).set_material("ice"));
+    assert!(collider(puck).set_material("ice"));
+    assert!(collider(ball).set_material("rubber"));
+    assert!(!collider(ball).set_material("lava"));
+    assert_eq!(collider(ball).material(), Some("rubber"));
+    assert_eq!(collider(ball).collider().unwrap().restitution(), 0.8);
+
+    let changed = world
+        .physics
+        .update_material("ice", PhysicsMaterial::new(0.01, 0.1));
+    assert_eq!(changed, 2);
+    for obj in [rink, puck] {
+        let c = collider(obj);
+        assert_eq!(c.collider().unwrap().friction(), 0.01);
+        assert_eq!(c.collider().unwrap().restitution(), 0.1);
+    }
+    assert_eq!(collider(ball).collider().unwrap().friction(), 0.9);
+
+    world
+        .physics
+        .register_material("rubber", PhysicsMaterial::new(0.5, 0.5));
+    assert_eq!(collider(ball).collider().unwrap().friction(), 0.9);
+
+    world.delete_object(puck);
+    let changed = world
+        .physics
+        .update_material("ice", PhysicsMaterial::new(0.0, 0.0));
+    assert_eq!(changed, 1);
+}
+
+#[test]
+fn physics_hooks_run_around_the_step() {
+    let mut world = World::builder()
+        .gravity(Vector3::zeros())
+        .deterministic(true)
+        .build();
+    let mut puck = spawn_box(&mut world, "Puck", Vector3::zeros());
+    let body = puck.add_component::<RigidBodyComponent>().body_handle;
+    world.fixed_update();
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let pre_log = log.clone();
+    world.on_pre_physics(move |world| {
+        let body = &mut world.physics.rigid_body_set[body];
+        pre_log.borrow_mut().push(("pre", body.translation().x));
+        body.set_linvel(Vector3::new(6.0, 0.0, 0.0), true);
+    });
+    let post_log = log.clone();
+    world.on_post_physics(move |world| {
+        let body = &world.physics.rigid_body_set[body];
+        post_log.borrow_mut().push(("post", body.translation().x));
+    });
+
+    world.fixed_update();
+    world.fixed_update();
+
+    {
+        let log = log.borrow();
+        let labels: Vec<_> = log.iter().map(|(label, _)| *label).collect();
+        assert_eq!(labels, ["pre", "post", "pre", "post"]);
+        assert_eq!(log[0].1, 0.0);
+        assert!((log[1].1 - 0.1).abs() < 1e-4, "{}", log[1].1);
+        assert_eq!(log[2].1, log[1].1);
+        assert!((log[3].1 - 0.2).abs() < 1e-4);
+    }
+
+    world.clear_physics_hooks();
+    world.fixed_update();
+    assert_eq!(log.borrow().len(), 4);
+    assert_eq!(Rc::strong_count(&log), 1);
+}
+
+#[test]
+fn capsule_and_cylinder_colliders_stand_upright() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut player = spawn_box(&mut world, "Player", Vector3::new(1.0, 2.0, 3.0));
+    let mut pillar = spawn_box(&mut world, "Pillar", Vector3::new(-4.0, 0.0, 0.0));
+
+    player
+        .get_component::<Collider3D>()
+        .unwrap()
+        .use_capsule(0.5, 0.25);
+    pillar
+        .get_component::<Collider3D>()
+        .unwrap()
+        .use_cylinder(2.0, 0.5);
+
+    let capsule = player.get_component::<Collider3D>().unwrap();
+    let shape = capsule.collider().unwrap().shape();
+    assert!(shape.as_capsule().is_some());
+    let aabb = capsule.aabb().unwrap();
+    assert!(
+        (aabb.mins - Point3::new(0.75, 1.25, 2.75)).norm() < 1e-5,
+        "{aabb:?}"
+    );
+    assert!(
+        (aabb.maxs - Point3::new(1.25, 2.75, 3.25)).norm() < 1e-5,
+        "{aabb:?}"
+    );
+
+    let cylinder = pillar.get_component::<Collider3D>().unwrap();
+    assert!(cylinder.collider().unwrap().shape().as_cylinder().is_some());
+    let aabb = cylinder.aabb().unwrap();
+    assert!((aabb.extents() - Vector3::new(1.0, 4.0, 1.0)).norm() < 1e-5);
+
+    // scaling the object keeps the procedural shape instead of going back to a cuboid
+    player.transform.set_scale(3.0);
+    pillar.transform.set_scale(3.0);
+    world.fixed_update();
+    let shape = player.get_component::<Collider3D>().unwrap();
+    assert!(shape.collider().unwrap().shape().as_capsule().is_some());
+    let shape = pillar.get_component::<Collider3D>().unwrap();
+    assert!(shape.collider().unwrap().shape().as_cylinder().is_some());
+}
+
+#[test]
+fn capsule_and_cylinder_constructors_add_shaped_colliders() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut player = world.new_object("Player");
+    player.transform.set_position(0.0, 2.0, 0.0);
+    world.add_child(player);
+    let pillar = world.new_object("Pillar");
+    world.add_child(pillar);
+
+    let capsule = Collider3D::capsule(player, 0.5, 0.25);
+    assert_eq!(player.get_components::<Collider3D>().len(), 1);
+    let shape = capsule.collider().unwrap().shape().as_capsule().unwrap();
+    assert_eq!((shape.half_height(), shape.radius), (0.5, 0.25));
+    let aabb = capsule.aabb().unwrap();
+    assert!((aabb.extents() - Vector3::new(0.5, 1.5, 0.5)).norm() < 1e-5);
+    assert!((aabb.center() - Point3::new(0.0, 2.0, 0.0)).norm() < 1e-5);
+
+    let cylinder = Collider3D::cylinder(pillar, 2.0, 0.5);
+    let shape = cylinder.collider().unwrap().shape().as_cylinder().unwrap();
+    assert_eq!((shape.half_height, shape.radius), (2.0, 0.5));
+}
+
+#[test]
+fn raycasts_only_hit_the_requested_layers() {
+    let mut world = World::builder().deterministic(true).build();
+    let pickup = spawn_box(&mut world, "Pickup", Vector3::new(0.0, 2.0, 0.0));
+    let debris = spawn_box(&mut world, "Debris", Vector3::new(0.0, 0.0, 0.0));
+    let ground = spawn_box(&mut world, "Ground", Vector3::new(0.0, -2.0, 0.0));
+
+    assert!(
+        pickup
+            .get_component::<Collider3D>()
+            .unwrap()
+            .set_layer("trigger")
+    );
+    assert!(
+        debris
+            .get_component::<Collider3D>()
+            .unwrap()
+            .set_layer("debris")
+    );
+    assert!(
+        ground
+            .get_component::<Collider3D>()
+            .unwrap()
+            .set_layer("ground")
+    );
+    assert_eq!(
+        pickup.get_component::<Collider3D>().unwrap().layer(),
+        Some("trigger")
+    );
+    world.physics.step();
+
+    let ray = Ray::new(Point3::new(0.0, 10.0, 0.0), -Vector3::y());
+    let hit = |mask: LayerMask| {
+        world
+            .raycast(&ray, 100.0, &mask, QueryFilterBuilder::new())
+            .map(|(_, obj)| obj)
+    };
+
+    assert_eq!(hit(LayerMask::all()), Some(pickup));
+    assert_eq!(hit(LayerMask::all().without(["trigger"])), Some(debris));
+    assert_eq!(
+        hit(LayerMask::all().without(["trigger", "debris"])),
+        Some(ground)
+    );
+    assert_eq!(hit(LayerMask::only(["ground"])), Some(ground));
+    assert_eq!(hit(LayerMask::only(["default"])), None);
+    assert_eq!(hit(LayerMask::only(["missing"])), None);
+
+    let rays = [
+        ray,
+        Ray::new(Point3::new(5.0, 10.0, 0.0), -Vector3::y()),
+        ray,
+    ];
+    let hits = world.raycast_batch(
+        &rays,
+        100.0,
+        &LayerMask::all().without(["trigger"]),
+        QueryFilterBuilder::new().exclude(ground),
+    );
+    let hit_objects: Vec<_> = hits.iter().map(|hit| hit.map(|(_, obj)| obj)).collect();
+    assert_eq!(hit_objects, vec![Some(debris), None, Some(debris)]);
+    assert_eq!(
+        hits[0],
+        world.raycast(
+            &ray,
+            100.0,
+            &LayerMask::all().without(["trigger"]),
+            QueryFilterBuilder::new()
+        )
+    );
+
+    // moving between layers keeps the collider colliding with everything
+    let groups = pickup
+        .get_component::<Collider3D>()
+        .unwrap()
+        .collider()
+        .unwrap()
+        .collision_groups();
+    assert_eq!(groups.filter, rapier3d::prelude::Group::ALL);
+}
+
+#[test]
+fn explicit_collider_mass_ignores_the_shape_size() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut obj = spawn_box(&mut world, "Crate", Vector3::zeros());
+    let rb = obj.add_component::<RigidBodyComponent>();
+    world.fixed_update();
+
+    let mut collider = obj.get_component::<Collider3D>().unwrap();
+    assert_eq!(collider.mass_mode(), MassMode::Density(1.0));
+    assert!((rb.body().unwrap().mass() - 1.0).abs() < 1e-5);
+
+    // the body picks up the new mass right away
+    collider.set_mass(10.0);
+    assert_eq!(collider.mass_mode(), MassMode::Mass(10.0));
+    assert!((rb.body().unwrap().mass() - 10.0).abs() < 1e-4);
+
+    obj.transform.set_scale(2.0);
+    world.fixed_update();
+    assert!((collider.mass() - 10.0).abs() < 1e-4);
+    assert!((rb.body().unwrap().mass() - 10.0).abs() < 1e-4);
+
+    // back to density, a 2x2x2 crate now weighs 8 times the density
+    collider.set_density(2.0);
+    assert!((rb.body().unwrap().mass() - 16.0).abs() < 1e-3);
+}
+
+#[test]
+fn depenetrate_pushes_objects_out_of_static_geometry() {
+    let mut world = World::builder().deterministic(true).build();
+    spawn_box(&mut world, "Floor", Vector3::new(0.0, -0.5, 0.0));
+    spawn_box(&mut world, "Wall", Vector3::new(1.0, 0.5, 0.0));
+    let crate_obj = spawn_box(&mut world, "Crate", Vector3::new(5.0, 0.5, 0.0));
+    let mut player = spawn_box(&mut world, "Player", Vector3::new(-5.0, 0.5, 0.0));
+    player.add_component::<RigidBodyComponent>();
+    world.physics.step();
+
+    // teleported halfway into the floor and the wall
+    player.transform.set_position(0.2, 0.3, 0.0);
+    let offset = world.depenetrate(player).unwrap();
+    assert!(
+        (offset - Vector3::new(-0.2, 0.2, 0.0)).norm() < 1e-3,
+        "{offset:?}"
+    );
+    assert!((player.transform.position() - Vector3::new(0.0, 0.5, 0.0)).norm() < 1e-3);
+    let body = player.get_component::<RigidBodyComponent>().unwrap();
+    let translation = body.body().unwrap().translation();
+    assert!((translation - Vector3::new(0.0, 0.5, 0.0)).norm() < 1e-3);
+
+    // already free, so nothing moves
+    assert_eq!(world.depenetrate(crate_obj), Some(Vector3::zeros()));
+    let nothing = world.new_object("Nothing");
+    assert_eq!(world.depenetrate(nothing), None);
+}
+
+#[test]
+fn custom_collider_shapes_scale_with_their_object() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut ball = spawn_box(&mut world, "Ball", Vector3::zeros());
+    let crate_obj = spawn_box(&mut world, "Crate", Vector3::new(5.0, 0.0, 0.0));
+
+    ball.get_component::<Collider3D>()
+        .unwrap()
+        .set_shape(SharedShape::ball(0.5));
+    ball.transform.set_scale(2.0);
+    world.fixed_update();
+
+    let collider = ball.get_component::<Collider3D>().unwrap();
+    let radius = collider
+        .collider()
+        .unwrap()
+        .shape()
+        .as_ball()
+        .unwrap()
+        .radius;
+    assert!((radius - 1.0).abs() < 1e-5, "{radius}");
+
+    // the collider's own scale stacks on top of the object's
+    let mut cuboid = crate_obj.get_component::<Collider3D>().unwrap();
+    cuboid.set_scale(Vector3::new(2.0, 2.0, 2.0));
+    assert_eq!(cuboid.scale(), Vector3::new(2.0, 2.0, 2.0));
+    let half_extents = cuboid
+        .collider()
+        .unwrap()
+        .shape()
+        .as_cuboid()
+        .unwrap()
+        .half_extents;
+    assert!((half_extents - Vector3::new(1.0, 1.0, 1.0)).norm() < 1e-5);
+
+    // a ball can't be stretched, so it's approximated
+    ball.transform.set_nonuniform_scale(2.0, 1.0, 1.0);
+    world.fixed_update();
+    let collider = ball.get_component::<Collider3D>().unwrap();
+    let shape = collider.collider().unwrap().shape();
+    assert!(shape.as_convex_polyhedron().is_some());
+    let extents = collider.aabb().unwrap().extents();
+    assert!(
+        (extents - Vector3::new(2.0, 1.0, 1.0)).norm() < 0.05,
+        "{extents:?}"
+    );
+}
+
+#[test]
+fn forked_physics_steps_without_touching_the_original() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut ground = spawn_box(&mut world, "Ground", Vector3::new(0.0, -0.5, 0.0));
+    ground.transform.set_nonuniform_scale(20.0, 1.0, 20.0);
+    let mut ball = spawn_box(&mut world, "Ball", Vector3::new(0.0, 5.0, 0.0));
+    let body = ball.add_component::<RigidBodyComponent>().body_handle;
+    world.fixed_update();
+    world.physics.rigid_body_set[body].set_linvel(Vector3::new(3.0, 4.0, 0.0), true);
+
+    let mut fork = world.fork_physics();
+    let start = *world.physics.rigid_body_set[body].translation();
+    for _ in 0..120 {
+        fork.fixed_update();
+    }
+
+    let landed = *fork.physics.rigid_body_set[body].translation();
+    assert_eq!(*world.physics.rigid_body_set[body].translation(), start);
+    assert!(landed.x > start.x + 3.0 && landed.y < 1.0, "{landed:?}");
+
+    // queries on the fork report the objects of the original world
+    let ray = Ray::new(Point3::new(0.0, 10.0, 0.0), -Vector3::y());
+    let hit = fork
+        .physics
+        .cast_ray(&ray, 20.0, true, QueryFilter::default());
+    assert_eq!(hit.map(|(_, obj)| obj), Some(ground));
+
+    for _ in 0..120 {
+        world.fixed_update();
+    }
+    assert_eq!(*world.physics.rigid_body_set[body].translation(), landed);
+}
+
+#[test]
+fn dropped_drag_handles_remove_their_anchor_and_joint() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut crate_obj = spawn_body(&mut world, "Crate", Vector3::zeros());
+    world.fixed_update();
+    let bodies = world.physics.rigid_body_set.len();
+
+    {
+        let drag = world.begin_drag(crate_obj, Vector3::zeros()).unwrap();
+        drag.move_to(Vector3::new(0.0, 2.0, 0.0));
+        assert_eq!(world.physics.rigid_body_set.len(), bodies + 1);
+        assert_eq!(world.physics.impulse_joint_set.len(), 1);
+    }
+    assert_eq!(world.physics.rigid_body_set.len(), bodies);
+    assert_eq!(world.physics.impulse_joint_set.len(), 0);
+
+    // the target going away first mustn't keep the anchor around either
+    let drag = world.begin_drag(crate_obj, Vector3::zeros()).unwrap();
+    crate_obj.delete();
+    world.fixed_update();
+    drop(drag);
+    assert_eq!(world.physics.rigid_body_set.len(), bodies - 1);
+    assert_eq!(world.physics.impulse_joint_set.len(), 0);
+}
+
+#[test]
+fn dragged_body_follows_the_grab_point() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut crate_obj = spawn_box(&mut world, "Crate", Vector3::new(0.0, 1.0, 0.0));
+    let body = crate_obj.add_component::<RigidBodyComponent>().body_handle;
+    let mut ground = spawn_box(&mut world, "Ground", Vector3::new(0.0, -5.0, 0.0));
+    ground.transform.set_scale(0.1);
+    world.fixed_update();
+
+    let drag = world
+        .begin_drag(crate_obj, Vector3::new(0.0, 1.5, 0.0))
+        .expect("crate is dynamic");
+    assert!(world.begin_drag(ground, Vector3::zeros()).is_none());
+
+    drag.move_to(Vector3::new(3.0, 2.5, 0.0));
+    assert_eq!(drag.target(), Vector3::new(3.0, 2.5, 0.0));
+    for _ in 0..240 {
+        world.fixed_update();
+    }
+
+    // the grabbed top of the crate hangs at the target, with the crate below it
+    let rb = &world.physics.rigid_body_set[body];
+    let grabbed = rb.position() * Point3::new(0.0, 0.5, 0.0);
+    assert!(
+        (grabbed.coords - drag.target()).norm() < 0.05,
+        "{grabbed:?}"
+    );
+    assert!(rb.linvel().norm() < 0.1, "{:?}", rb.linvel());
+
+    let bodies = world.physics.rigid_body_set.len();
+    drag.end_drag();
+    assert_eq!(world.physics.rigid_body_set.len(), bodies - 1);
+    for _ in 0..30 {
+        world.fixed_update();
+    }
+    assert!(world.physics.rigid_body_set[body].translation().y < 1.5);
+}
+
+#[test]
+fn physics_profile_breaks_down_the_last_tick() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut ground = spawn_box(&mut world, "Ground", Vector3::new(0.0, -0.5, 0.0));
+    ground.transform.set_nonuniform_scale(20.0, 1.0, 20.0);
+    for i in 0..4 {
+        let mut crate_obj = spawn_box(&mut world, "Crate", Vector3::new(i as f32 * 2.0, 0.5, 0.0));
+        crate_obj.add_component::<RigidBodyComponent>();
+    }
+    world.fixed_update();
+    world.fixed_update();
+    assert_eq!(world.physics_profile(), PhysicsProfile::default());
+
+    world.set_physics_profiling(true);
+    world.set_physics_substeps(2);
+    world.fixed_update();
+
+    let profile = world.physics_profile();
+    assert_eq!(profile.substeps, 2);
+    assert_eq!(profile.contact_pairs, 4);
+    assert!(profile.contacts >= 4);
+    if cfg!(feature = "physics-profiler") {
+        assert!(profile.step >= profile.solver + profile.narrow_phase);
+        assert!(profile.step > std::time::Duration::ZERO);
+    }
+
+    world.set_physics_profiling(false);
+    world.fixed_update();
+    assert_eq!(world.physics_profile().substeps, 0);
+}
+
+#[test]
+fn single_body_state_restores_without_touching_others() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut ball = spawn_box(&mut world, "Ball", Vector3::new(0.0, 5.0, 0.0));
+    let mut rb = ball.add_component::<RigidBodyComponent>();
+    let mut other = spawn_box(&mut world, "Other", Vector3::new(5.0, 5.0, 0.0));
+    let other_body = other.add_component::<RigidBodyComponent>().body_handle;
+    world.fixed_update();
+    rb.body_mut()
+        .unwrap()
+        .set_linvel(Vector3::new(2.0, 3.0, 0.0), true);
+
+    let checkpoint = rb.capture_state().unwrap();
+    for _ in 0..10 {
+        world.fixed_update();
+    }
+    let expected = rb.capture_state().unwrap();
+    let other_y = world.physics.rigid_body_set[other_body].translation().y;
+
+    // a pending force must not survive the restore
+    rb.body_mut()
+        .unwrap()
+        .add_force(Vector3::new(0.0, 500.0, 0.0), true);
+    rb.restore_state(&checkpoint);
+    assert_eq!(rb.capture_state(), Some(checkpoint));
+    assert_eq!(ball.transform.position(), checkpoint.position);
+    assert!(!rb.body().unwrap().is_sleeping());
+    assert_eq!(
+        world.physics.rigid_body_set[other_body].translation().y,
+        other_y
+    );
+
+    for _ in 0..10 {
+        world.fixed_update();
+    }
+    let replayed = rb.capture_state().unwrap();
+    assert!(!replayed.differs_from(&expected, 1e-4), "{replayed:?}");
+}
+
+/// Drops a spinning box onto the floor, then replays the last steps from a snapshot taken
+/// mid-tumble. Returns the poses of the original run and of the replay.
+fn replayed_tumble(warmstart: bool) -> (Vec<Isometry3<f32>>, Vec<Isometry3<f32>>) {
+    let mut world = World::builder().deterministic(true).build();
+    world.set_contact_warmstart(warmstart);
+    assert_eq!(world.contact_warmstart(), warmstart);
+
+    let mut floor = spawn_box(&mut world, "Floor", Vector3::new(0.0, -5.0, 0.0));
+    floor.transform.set_nonuniform_scale(20.0, 1.0, 20.0);
+    let tumbler = spawn_body(&mut world, "Tumbler", Vector3::new(0.0, -3.0, 0.0));
+    let mut rb = tumbler.get_component::<RigidBodyComponent>().unwrap();
+    rb.body_mut()
+        .unwrap()
+        .set_angvel(Vector3::new(4.0, 0.0, 6.0), true);
+    // it lands after about 27 ticks and keeps tumbling for a while
+    for _ in 0..30 {
+        world.fixed_update();
+    }
+
+    let checkpoint = rb.capture_state().unwrap();
+    let body = rb.body_handle;
+    let run = |world: &mut World| -> Vec<Isometry3<f32>> {
+        (0..20)
+            .map(|_| {
+                world.fixed_update();
+                *world.physics.rigid_body_set[body].position()
+            })
+            .collect()
+    };
+    let original = run(&mut world);
+    rb.restore_state(&checkpoint);
+    let replay = run(&mut world);
+    (original, replay)
+}
+
+#[test]
+fn snapshot_replays_match_only_without_contact_warmstart() {
+    let (original, replay) = replayed_tumble(false);
+    assert_eq!(original, replay);
+
+    let (original, replay) = replayed_tumble(true);
+    assert_ne!(original, replay);
+}
+
+#[test]
+fn capturing_the_state_of_a_removed_body_is_none() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut ball = spawn_box(&mut world, "Ball", Vector3::new(0.0, 5.0, 0.0));
+    let rb = ball.add_component::<RigidBodyComponent>();
+    world.fixed_update();
+    assert!(rb.capture_state().is_some());
+
+    let physics = &mut world.physics;
+    physics.rigid_body_set.remove(
+        rb.body_handle,
+        &mut physics.island_manager,
+        &mut physics.collider_set,
+        &mut physics.impulse_joint_set,
+        &mut physics.multibody_joint_set,
+        true,
+    );
+    assert_eq!(rb.capture_state(), None);
+}
+
+#[test]
+fn bodies_leaving_the_world_bounds_are_disabled() {
+    let mut world = World::builder()
+        .deterministic(true)
+        .broad_phase(BroadPhaseConfig {
+            optimization: BroadPhaseOptimization::None,
+            length_unit: 1.0,
+        })
+        .world_bounds(
+            Vector3::new(-10.0, -5.0, -10.0),
+            Vector3::new(10.0, 10.0, 10.0),
+        )
+        .build();
+
+    let mut faller = world.new_object("Faller");
+    faller.add_component::<Collider3D>();
+    faller.add_component::<RigidBodyComponent>();
+    world.add_child(faller);
+
+    for _ in 0..120 {
+        world.fixed_update();
+    }
+
+    let rb = faller.get_component::<RigidBodyComponent>().unwrap();
+    let body = rb.body().unwrap();
+    assert!(!body.is_enabled());
+    assert!(body.translation().y < -5.0);
+    assert!(body.translation().y > -6.0);
+}
+
+#[test]
+fn hard_impacts_fire_once_above_the_threshold() {
+    let mut world = World::builder().deterministic(true).build();
+    world.physics.set_hard_impact_threshold(Some(100.0));
+
+    let mut ground = world.new_object("Ground");
+    ground.transform.set_scale(20.0);
+    ground.transform.set_position(0.0, -10.0, 0.0);
+    ground.add_component::<Collider3D>();
+    world.add_child(ground);
+
+    let mut crate_obj = world.new_object("Crate");
+    crate_obj.transform.set_position(0.0, 5.0, 0.0);
+    crate_obj.add_component::<Collider3D>();
+    crate_obj.add_component::<RigidBodyComponent>();
+    world.add_child(crate_obj);
+
+    let impacts = Rc::new(RefCell::new(Vec::new()));
+    let seen = impacts.clone();
+    world.on_world_event(move |_world, event| {
+        if let WorldEvent::HardImpact { a, b, force } = event {
+            seen.borrow_mut().push((a, b, force));
+        }
+    });
+
+    for _ in 0..180 {
+        world.fixed_update();
+    }
+
+    let impacts = impacts.borrow();
+    assert_eq!(impacts.len(), 1, "{impacts:?}");
+    let (a, b, force) = impacts[0];
+    assert!([a, b].contains(&crate_obj) && [a, b].contains(&ground));
+    assert!(force > 100.0);
+
+    // resting on the ground only pushes with about its weight
+    let collider = crate_obj.get_component::<Collider3D>().unwrap().phys_handle;
+    let contacts = world.physics.contacts(collider);
+    let resting = contacts[0].impulse / world.physics.integration_parameters.dt;
+    assert!(resting > 5.0 && resting < 20.0, "{resting}");
+}
+
+#[test]
+fn gravity_zones_override_world_gravity_by_priority() {
+    let mut world = World::builder().deterministic(true).build();
+
+    let mut station = spawn_box(&mut world, "Station", Vector3::zeros());
+    station.transform.set_scale(20.0);
+    let mut station_zone = station.add_component::<GravityZoneComponent>();
+    station_zone.gravity = Vector3::new(0.0, 5.0, 0.0);
+
+    let mut vacuum = spawn_box(&mut world, "Vacuum", Vector3::new(-6.0, 0.0, 0.0));
+    vacuum.transform.set_scale(6.0);
+    let mut vacuum_zone = vacuum.add_component::<GravityZoneComponent>();
+    vacuum_zone.gravity = Vector3::zeros();
+    vacuum_zone.priority = 1;
+
+    let lifted = spawn_body(&mut world, "Lifted", Vector3::new(6.0, 0.0, 0.0));
+    let floating = spawn_body(&mut world, "Floating", Vector3::new(-6.0, 0.0, 0.0));
+    let falling = spawn_body(&mut world, "Falling", Vector3::new(30.0, 0.0, 0.0));
+
+    for _ in 0..30 {
+        world.fixed_update();
+    }
+
+    assert!(lifted.transform.position().y > 0.5);
+    assert!(floating.transform.position().y.abs() < 0.1);
+    assert!(falling.transform.position().y < -1.0);
+    assert_eq!(vacuum_zone.affected_objects(&world), vec![floating]);
+    assert!(!station_zone.affected_objects(&world).contains(&floating));
+}
+
+#[test]
+fn gravity_zones_of_equal_priority_go_to_the_lower_object_id() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut first = spawn_box(&mut world, "First", Vector3::new(-1.0, 0.0, 0.0));
+    first.transform.set_scale(4.0);
+    let mut second = spawn_box(&mut world, "Second", Vector3::new(1.0, 0.0, 0.0));
+    second.transform.set_scale(4.0);
+    assert!(first < second);
+
+    // the later object gets its zone first, so storage order doesn't match id order
+    let mut down = second.add_component::<GravityZoneComponent>();
+    down.gravity = Vector3::new(0.0, -5.0, 0.0);
+    let mut up = first.add_component::<GravityZoneComponent>();
+    up.gravity = Vector3::new(0.0, 5.0, 0.0);
+
+    let body = spawn_body(&mut world, "Body", Vector3::zeros());
+    for _ in 0..30 {
+        world.fixed_update();
+    }
+
+    assert_eq!(up.affected_objects(&world), vec![body]);
+    assert!(down.affected_objects(&world).is_empty());
+    assert!(body.transform.position().y > 0.5);
+}
+
+#[test]
+fn gravity_zones_reach_bodies_through_child_colliders() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut station = spawn_box(&mut world, "Station", Vector3::zeros());
+    station.transform.set_scale(20.0);
+    let mut zone = station.add_component::<GravityZoneComponent>();
+    zone.gravity = Vector3::new(0.0, 5.0, 0.0);
+
+    let mut ship = world.new_object("Ship");
+    let body = ship.add_component::<RigidBodyComponent>().body_handle;
+    world.add_child(ship);
+    let mut hull = world.new_object("Hull");
+    let mut collider = hull.add_component::<Collider3D>();
+    ship.add_child(hull);
+    collider.link_to_rigid_body(&mut world, Some(body));
+
+    for _ in 0..30 {
+        world.fixed_update();
+    }
+
+    assert_eq!(zone.affected_objects(&world), vec![ship]);
+    assert!(ship.transform.position().y > 0.5);
+}
+
+#[test]
+fn contacts_with_lists_touching_objects_of_the_last_step() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut ground = spawn_box(&mut world, "Ground", Vector3::new(0.0, -10.0, 0.0));
+    ground.transform.set_scale(20.0);
+    let mut player = spawn_body(&mut world, "Player", Vector3::new(0.0, 0.6, 0.0));
+
+    for _ in 0..60 {
+        world.fixed_update();
+    }
+
+    let contacts: Vec<_> = world.contacts_with(player).collect();
+    assert_eq!(contacts.len(), 1, "{contacts:?}");
+    let ground_contact = contacts[0];
+    assert_eq!(ground_contact.other, ground);
+    assert!(ground_contact.normal.y < -0.99);
+    assert!(ground_contact.point.y.abs() < 0.05);
+    assert_eq!(
+        ground_contact.collider,
+        player.get_component::<Collider3D>().unwrap().phys_handle
+    );
+
+    let from_ground: Vec<_> = world.contacts_with(ground).collect();
+    assert_eq!(from_ground.len(), 1);
+    assert_eq!(from_ground[0].other, player);
+    assert!(from_ground[0].normal.y > 0.99);
+
+    player.transform.set_position(0.0, 5.0, 0.0);
+    world.fixed_update();
+    assert_eq!(world.contacts_with(player).count(), 0);
+}
+
+#[test]
+fn disabled_colliders_stop_colliding_and_leave_queries() {
+    let mut world = World::builder().deterministic(true).build();
+    let floor = spawn_box(&mut world, "Floor", Vector3::new(0.0, -2.0, 0.0));
+    let ball = spawn_body(&mut world, "Ball", Vector3::zeros());
+    world.fixed_update();
+
+    let down = Ray::new(Point3::new(0.0, 10.0, 0.0), -Vector3::y());
+    let below_ball = || QueryFilterBuilder::new().exclude(ball);
+    let hit = world.raycast(&down, 20.0, &LayerMask::all(), below_ball());
+    assert_eq!(hit.map(|(_, obj)| obj), Some(floor));
+
+    let mut collider = floor.get_component::<Collider3D>().unwrap();
+    let handle = collider.phys_handle;
+    collider.set_enabled(false);
+    assert!(!collider.is_enabled());
+    for _ in 0..30 {
+        world.fixed_update();
+    }
+    assert!(
+        world
+            .raycast(&down, 20.0, &LayerMask::all(), below_ball())
+            .is_none()
+    );
+    // resting on the floor, it would stop at -1
+    let height = ball.transform.position().y;
+    assert!(height < -1.1, "{height}");
+
+    // an inactive object keeps it off, and brings back what was set in the meantime
+    world.set_active(floor, false);
+    collider.set_enabled(true);
+    assert!(collider.is_enabled());
+    world.fixed_update();
+    assert!(
+        world
+            .raycast(&down, 20.0, &LayerMask::all(), below_ball())
+            .is_none()
+    );
+    world.set_active(floor, true);
+
+    world.fixed_update();
+    assert_eq!(collider.phys_handle, handle);
+    let hit = world.raycast(&down, 20.0, &LayerMask::all(), below_ball());
+    assert_eq!(hit.map(|(_, obj)| obj), Some(floor));
+}
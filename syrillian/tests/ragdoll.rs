@@ -0,0 +1,75 @@
+use nalgebra::{Matrix4, Vector3};
+use std::collections::HashMap;
+use syrillian::World;
+use syrillian::assets::{Mesh, StoreType};
+use syrillian::components::{
+    MeshRenderer, Ragdoll, RagdollComponent, RagdollConfig, SkeletalComponent, SphericalJoint,
+};
+use syrillian::core::Bones;
+
+fn two_bone_chain() -> Bones {
+    let root = Matrix4::identity();
+    let tip = Matrix4::new_translation(&Vector3::new(0.0, 1.0, 0.0));
+
+    Bones {
+        names: vec!["Root".to_string(), "Tip".to_string()],
+        parents: vec![None, Some(0)],
+        children: vec![vec![1], vec![]],
+        roots: vec![0],
+        inverse_bind: vec![root, tip.try_inverse().unwrap()],
+        bind_global: vec![root, tip],
+        bind_local: vec![root, tip],
+        index_of: HashMap::from([("Root".to_string(), 0), ("Tip".to_string(), 1)]),
+    }
+}
+
+#[test]
+fn ragdoll_follows_falling_bodies() {
+    let mut world = World::builder().deterministic(true).build();
+
+    let vertices = Mesh::cube(1.0).vertices().to_vec();
+    let mesh = Mesh::builder(vertices)
+        .with_bones(two_bone_chain())
+        .build()
+        .store(world.as_ref());
+
+    let mut character = world.new_object("Character");
+    character.transform.set_position(0.0, 5.0, 0.0);
+    character.add_component::<MeshRenderer>().set_mesh(mesh);
+    let mut skeleton = character.add_component::<SkeletalComponent>();
+    world.add_child(character);
+
+    let config = RagdollConfig {
+        radius: 0.1,
+        ..RagdollConfig::default()
+    };
+    let root = Ragdoll::from_skeleton(&mut skeleton, &config).expect("skeleton has bones");
+
+    let ragdoll = character.get_component::<RagdollComponent>().unwrap();
+    let bodies: Vec<_> = ragdoll.bodies().collect();
+    assert_eq!(bodies.len(), 2);
+    assert_eq!(bodies[0], root);
+    assert_eq!(root.transform.position(), Vector3::new(0.0, 5.0, 0.0));
+    assert_eq!(bodies[1].transform.position(), Vector3::new(0.0, 6.0, 0.0));
+
+    let joint = bodies[1].get_component::<SphericalJoint>().unwrap();
+    assert_eq!(joint.connected(), Some(root));
+    assert_eq!(joint.swing_limit(), Some(config.swing_limit));
+
+    for _ in 0..30 {
+        world.fixed_update();
+        world.update();
+        world.next_frame();
+    }
+
+    assert!(root.transform.position().y < 4.5);
+
+    skeleton.update_palette();
+    let pose = skeleton.global_pose();
+    let root_bone = pose[0].column(3).xyz();
+    let tip_bone = pose[1].column(3).xyz();
+
+    // bones are in model space, which stayed in place while the bodies fell
+    assert!((root_bone.y - (root.transform.position().y - 5.0)).abs() < 1e-3);
+    assert!(((tip_bone - root_bone).norm() - 1.0).abs() < 0.05);
+}
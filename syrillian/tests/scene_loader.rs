@@ -1,7 +1,10 @@
+use std::cell::RefCell;
 use std::path::PathBuf;
+use std::rc::Rc;
 
 use syrillian::World;
-use syrillian::assets::scene_loader::SceneLoader;
+use syrillian::assets::scene_loader::{LoadStatus, SceneLoader};
+use syrillian::core::{UpAxis, WorldEvent};
 
 fn asset_path(relative: &str) -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(relative)
@@ -69,3 +72,52 @@ fn load_scene_from_buffer_spawns_world_objects() {
         "expected child nodes to be spawned under the scene root"
     );
 }
+
+#[test]
+fn async_scene_loads_spawn_under_the_placeholder_root() {
+    let (mut world, _render_rx, _event_rx, _pick_tx) = World::fresh();
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let seen = events.clone();
+    world.on_world_event(move |_world, event| {
+        if matches!(
+            event,
+            WorldEvent::SceneLoaded(_) | WorldEvent::SceneLoadFailed(_)
+        ) {
+            seen.borrow_mut().push(event);
+        }
+    });
+
+    let path = asset_path("testmodels/hampter/hampter.glb");
+    let scene = world.load_gltf_async(path.to_string_lossy());
+    let missing = world.load_gltf_async("testmodels/does_not_exist.glb");
+    assert_eq!(scene.root().name, "glTF Scene");
+    assert!(scene.root().children().is_empty());
+
+    world.wait_for_scene_loads();
+
+    assert!(scene.is_ready());
+    assert!(!scene.root().children().is_empty());
+    assert_eq!(missing.status(), LoadStatus::Failed);
+    assert!(missing.take_error().is_some());
+    assert!(missing.root().children().is_empty());
+    assert_eq!(
+        *events.borrow(),
+        [
+            WorldEvent::SceneLoaded(scene.root()),
+            WorldEvent::SceneLoadFailed(missing.root())
+        ]
+    );
+}
+
+#[test]
+fn scenes_stand_upright_in_z_up_worlds() {
+    let bytes = std::fs::read(asset_path("testmodels/hampter/hampter.glb"))
+        .expect("failed to read test model");
+
+    let mut world = World::builder().up_axis(UpAxis::Z).build();
+    let root = SceneLoader::load_buffer(world.as_mut(), &bytes).expect("scene should load");
+
+    // the Y-up of the file points along the world's up
+    let up = root.transform.rotation() * nalgebra::Vector3::y();
+    assert!((up - nalgebra::Vector3::z()).norm() < 1e-5, "{up:?}");
+}
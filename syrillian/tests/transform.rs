@@ -1,5 +1,6 @@
-use nalgebra::{UnitQuaternion, Vector3};
+use nalgebra::{Point3, UnitQuaternion, Vector3};
 use slotmap::Key;
+use syrillian::World;
 use syrillian::engine::core::{GameObjectId, Transform};
 
 #[test]
@@ -21,3 +22,24 @@ fn rotation_and_scale() {
     t.set_uniform_local_scale(2.0);
     assert_eq!(*t.local_scale(), Vector3::new(2.0, 2.0, 2.0));
 }
+
+#[test]
+fn look_at_points_forward_at_the_target() {
+    let mut world = World::builder().build();
+    let mut turret = world.new_object("Turret");
+    turret.transform.set_position(1.0, 0.0, 1.0);
+    let mut target = world.new_object("Target");
+    target.transform.set_position(4.0, 4.0, 1.0);
+
+    turret.look_at(target);
+    let forward = turret.transform.forward();
+    assert!((forward - Vector3::new(0.6, 0.8, 0.0)).norm() < 1e-5);
+    assert!(turret.transform.right().y.abs() < 1e-5);
+
+    // straight down along up, the old heading turns into up
+    turret
+        .transform
+        .look_at(Point3::new(1.0, -5.0, 1.0), Vector3::y());
+    assert!((turret.transform.forward() + Vector3::y()).norm() < 1e-5);
+    assert!((turret.transform.up() - Vector3::x()).norm() < 1e-5);
+}
@@ -1,5 +1,11 @@
+use nalgebra::{Isometry3, UnitQuaternion, Vector3};
+use std::cell::RefCell;
+use std::rc::Rc;
 use syrillian::World;
-use syrillian::core::EventType;
+use syrillian::components::{Collider3D, Component, RigidBodyComponent};
+use syrillian::core::{EventType, GameObjectBuilder, Transform, UpAxis, WorldEvent};
+use syrillian::utils::Timer;
+use uuid::Uuid;
 use web_time::Duration;
 
 #[test]
@@ -92,3 +98,450 @@ fn click_registration_toggles() {
     obj.stop_notify_for(&mut world, EventType::CLICK);
     assert!(!world.is_listening_for(obj, EventType::CLICK));
 }
+
+#[test]
+fn objects_are_found_by_persistent_uuid() {
+    let (mut world, _rx1, _rx2, _pick_tx) = World::fresh();
+    let first = world.new_object("First");
+    let second = world.new_object("Second");
+    world.add_child(first);
+    world.add_child(second);
+
+    assert_ne!(first.uuid(), second.uuid());
+    assert_eq!(world.find_by_uuid(first.uuid()), Some(first));
+
+    let saved = Uuid::from_u128(0x1234);
+    let old = second.uuid();
+    assert!(world.set_object_uuid(second, saved));
+    assert_eq!(second.uuid(), saved);
+    assert_eq!(world.find_by_uuid(saved), Some(second));
+    assert_eq!(world.find_by_uuid(old), None);
+    assert!(!world.set_object_uuid(first, saved));
+    assert_eq!(world.find_by_uuid(saved), Some(second));
+
+    world.delete_object(second);
+    assert_eq!(world.find_by_uuid(saved), None);
+}
+
+#[test]
+fn batched_spawn_and_despawn_keep_bookkeeping_consistent() {
+    let mut world = World::builder().deterministic(true).build();
+    let roots_before = world.children.len();
+    let objects_before = world.objects.len();
+
+    let crates = world.spawn_batch(50, |i| {
+        GameObjectBuilder::new(format!("Crate {i}"))
+            .at(i as f32 * 3.0, 0.0, 0.0)
+            .scale(2.0)
+            .with::<Collider3D>()
+            .with_then::<RigidBodyComponent>(|rb| rb.set_kinematic(true))
+            .setup(|mut obj| {
+                let lid = obj.world().new_object("Lid");
+                obj.add_child(lid);
+            })
+    });
+
+    assert_eq!(crates.len(), 50);
+    assert_eq!(world.children.len(), roots_before + 50);
+    assert_eq!(world.objects.len(), objects_before + 100);
+    assert_eq!(world.physics.rigid_body_set.len(), 50);
+    assert_eq!(crates[7].name, "Crate 7");
+    assert_eq!(crates[7].transform.position(), Vector3::new(21.0, 0.0, 0.0));
+    assert_eq!(crates[7].children().len(), 1);
+    let rb = crates[7].get_component::<RigidBodyComponent>().unwrap();
+    assert!(rb.is_kinematic());
+
+    let (gone, kept): (Vec<_>, Vec<_>) = crates.iter().partition(|obj| obj.name.ends_with('0'));
+    world.despawn_batch(&gone);
+
+    assert_eq!(world.children.len(), roots_before + kept.len());
+    assert_eq!(world.objects.len(), objects_before + kept.len() * 2);
+    assert_eq!(world.physics.rigid_body_set.len(), kept.len());
+    assert_eq!(world.physics.collider_set.len(), kept.len());
+    assert!(gone.iter().all(|obj| !obj.exists()));
+    assert!(kept.iter().all(|obj| world.children.contains(obj)));
+    assert!(world.find_by_uuid(kept[0].uuid()).is_some());
+}
+
+#[test]
+fn query_changed_reports_only_moved_transforms() {
+    let mut world = World::builder().build();
+    let mut parent = world.new_object("Parent");
+    let mut child = world.new_object("Child");
+    let mut still = world.new_object("Still");
+    parent.add_child(child);
+    world.add_child(parent);
+    world.add_child(still);
+
+    let mut fresh = world.query_changed::<Transform>();
+    fresh.sort();
+    let mut all = vec![parent, child, still];
+    all.sort();
+    assert_eq!(fresh, all);
+
+    world.next_frame();
+    assert!(world.query_changed::<Transform>().is_empty());
+
+    // writing the current values back isn't a change
+    let pos = still.transform.position();
+    still.transform.set_position_vec(pos);
+    still.transform.set_rotation(UnitQuaternion::identity());
+    assert!(!still.transform.changed_this_frame());
+
+    parent.transform.translate(Vector3::new(1.0, 0.0, 0.0));
+    let mut moved = world.query_changed::<Transform>();
+    moved.sort();
+    let mut expected = vec![parent, child];
+    expected.sort();
+    assert_eq!(moved, expected);
+
+    child.transform.set_scale(2.0);
+    world.next_frame();
+    assert!(!child.transform.changed_this_frame());
+}
+
+#[test]
+fn set_transforms_places_objects_and_bodies_in_one_pass() {
+    let mut world = World::builder().deterministic(true).build();
+    let tiles = world.spawn_batch(50, |i| GameObjectBuilder::new(format!("Tile {i}")));
+    let mut platform = world.new_object("Platform");
+    let mut body = platform.add_component::<RigidBodyComponent>();
+    body.set_kinematic(true);
+    let mut rider = world.new_object("Rider");
+    rider.transform.set_local_position(0.0, 1.0, 0.0);
+    platform.add_child(rider);
+    world.add_child(platform);
+    world.next_frame();
+
+    let turn = UnitQuaternion::from_axis_angle(&Vector3::y_axis(), 1.0);
+    let mut poses: Vec<_> = tiles
+        .iter()
+        .enumerate()
+        .map(|(i, &tile)| {
+            let pose = Isometry3::from_parts(Vector3::new(i as f32, 0.0, 2.0).into(), turn);
+            (tile, pose)
+        })
+        .collect();
+    let platform_pose = Isometry3::translation(10.0, 5.0, 0.0);
+    poses.push((platform, platform_pose));
+    world.set_transforms(&poses);
+
+    for (tile, pose) in &poses[..tiles.len()] {
+        assert!((tile.transform.position() - pose.translation.vector).norm() < 1e-5);
+        assert!(tile.transform.rotation().angle_to(&turn) < 1e-5);
+    }
+
+    // the kinematic body is teleported, instead of being swept there next step
+    assert_eq!(body.body().unwrap().position(), &platform_pose);
+    assert!(rider.transform.changed_this_frame());
+    assert!((rider.transform.position() - Vector3::new(10.0, 6.0, 0.0)).norm() < 1e-5);
+
+    world.fixed_update();
+    assert_eq!(body.body().unwrap().linvel().norm(), 0.0);
+}
+
+#[test]
+fn world_events_announce_spawned_and_destroyed_objects() {
+    let (mut world, ..) = World::fresh();
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let log = events.clone();
+    world.on_world_event(move |_world, event| {
+        // components are still attached while the object is being destroyed
+        let has_body = event
+            .object()
+            .get_component::<RigidBodyComponent>()
+            .is_some();
+        log.borrow_mut().push((event, has_body));
+    });
+    world.update();
+    events.borrow_mut().clear();
+
+    let mut parent = world.new_object("Parent");
+    let child = world.new_object("Child");
+    parent.add_component::<RigidBodyComponent>();
+    parent.add_child(child);
+    world.add_child(parent);
+
+    // objects that never made it to a frame aren't announced at all
+    let short_lived = world.new_object("Short lived");
+    world.delete_object(short_lived);
+    assert!(events.borrow().is_empty());
+
+    world.update();
+    assert_eq!(
+        *events.borrow(),
+        [
+            (WorldEvent::Spawned(parent), true),
+            (WorldEvent::Spawned(child), false)
+        ]
+    );
+    events.borrow_mut().clear();
+
+    world.delete_object(parent);
+    assert_eq!(
+        *events.borrow(),
+        [
+            (WorldEvent::Destroyed(parent), true),
+            (WorldEvent::Destroyed(child), false)
+        ]
+    );
+}
+
+#[derive(Default)]
+struct UpdateCounter {
+    updates: usize,
+}
+
+impl Component for UpdateCounter {
+    fn update(&mut self, _world: &mut World) {
+        self.updates += 1;
+    }
+}
+
+#[test]
+fn inactive_objects_skip_updates_and_physics() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut parent = world.new_object("Crate");
+    parent.transform.set_position(0.0, 10.0, 0.0);
+    parent.add_component::<Collider3D>();
+    let rb = parent.add_component::<RigidBodyComponent>();
+    let mut child = world.new_object("Label");
+    let counter = child.add_component::<UpdateCounter>();
+    parent.add_child(child);
+    world.add_child(parent);
+
+    let tick = |world: &mut World| {
+        world.fixed_update();
+        world.update();
+    };
+
+    tick(&mut world);
+    assert_eq!(counter.updates, 1);
+
+    world.set_active(parent, false);
+    assert!(!world.is_active(child));
+    let height = parent.transform.position().y;
+    for _ in 0..10 {
+        tick(&mut world);
+    }
+    assert_eq!(counter.updates, 1);
+    assert_eq!(parent.transform.position().y, height);
+    assert!(!rb.body().unwrap().is_enabled());
+
+    // moved while inactive, the body continues from there
+    parent.transform.set_position(5.0, 20.0, 0.0);
+    world.set_active(parent, true);
+    tick(&mut world);
+    assert_eq!(counter.updates, 2);
+    let body = rb.body().unwrap();
+    assert!(body.is_enabled());
+    assert_eq!(body.translation().x, 5.0);
+    assert!(body.translation().y < 20.0 && body.translation().y > 19.0);
+}
+
+#[test]
+fn spawn_at_starts_bodies_at_the_pose() {
+    let mut world = World::builder().deterministic(true).build();
+    let pose = Isometry3::new(Vector3::new(3.0, 8.0, -2.0), Vector3::new(0.0, 1.2, 0.0));
+
+    let mut spawned = world.spawn_at("Crate", pose);
+    assert!(world.children.contains(&spawned));
+    assert!((spawned.transform.position() - pose.translation.vector).norm() < 1e-5);
+    spawned.add_component::<Collider3D>();
+    let rb = spawned.add_component::<RigidBodyComponent>();
+
+    let built = GameObjectBuilder::new("Barrel")
+        .pose(pose)
+        .with::<Collider3D>()
+        .with::<RigidBodyComponent>()
+        .build(&mut world);
+    let built_rb = built.get_component::<RigidBodyComponent>().unwrap();
+
+    for rb in [&rb, &built_rb] {
+        let body = rb.body().unwrap();
+        assert!((body.translation() - pose.translation.vector).norm() < 1e-5);
+        assert!(body.rotation().angle_to(&pose.rotation) < 1e-5);
+        // nothing is drawn at the origin while the first steps interpolate
+        let rendered = rb.render_isometry(0.5);
+        assert!((rendered.translation.vector - pose.translation.vector).norm() < 1e-5);
+        assert!(rendered.rotation.angle_to(&pose.rotation) < 1e-5);
+    }
+
+    world.fixed_update();
+    let rendered = rb.render_isometry(0.0);
+    assert!((rendered.translation.vector - pose.translation.vector).norm() < 1e-5);
+}
+
+#[test]
+fn pause_freezes_physics_and_gameplay_timers() {
+    let mut world = World::builder().deterministic(true).build();
+    let mut crate_obj = world.new_object("Crate");
+    crate_obj.transform.set_position(0.0, 10.0, 0.0);
+    crate_obj.add_component::<Collider3D>();
+    crate_obj.add_component::<RigidBodyComponent>();
+    world.add_child(crate_obj);
+
+    let mut respawn = Timer::new(Duration::from_secs(60));
+    let mut menu_blink = Timer::repeating(Duration::from_millis(1));
+    menu_blink.set_ignore_pause(true);
+    assert!(!respawn.ignores_pause() && menu_blink.ignores_pause());
+
+    world.set_paused(true);
+    assert!(world.is_paused());
+    for _ in 0..10 {
+        std::thread::sleep(Duration::from_millis(2));
+        world.next_frame();
+        world.fixed_update();
+        respawn.tick(&world);
+        menu_blink.tick(&world);
+    }
+
+    assert_eq!(world.game_delta_time(), Duration::ZERO);
+    assert!(world.delta_time() > Duration::ZERO);
+    assert_eq!(respawn.elapsed(), Duration::ZERO);
+    assert!(menu_blink.is_finished());
+    assert_eq!(crate_obj.transform.position().y, 10.0);
+
+    world.set_paused(false);
+    world.next_frame();
+    respawn.tick(&world);
+    for _ in 0..5 {
+        world.fixed_update();
+    }
+    assert!(respawn.elapsed() > Duration::ZERO);
+    assert!(crate_obj.transform.position().y < 10.0);
+}
+
+#[test]
+fn frame_and_tick_counters_follow_the_update_loop() {
+    let mut world = World::builder().deterministic(true).build();
+    assert_eq!(world.frame_count(), 0);
+    assert_eq!(world.physics_tick_count(), 0);
+    assert_eq!(world.elapsed_time(), Duration::ZERO);
+
+    for _ in 0..3 {
+        std::thread::sleep(Duration::from_millis(2));
+        world.next_frame();
+        world.fixed_update();
+    }
+    assert_eq!(world.frame_count(), 3);
+    assert_eq!(world.physics_tick_count(), 3);
+    let elapsed = world.elapsed_time();
+    assert!(elapsed >= Duration::from_millis(6));
+    assert!(elapsed <= world.time());
+
+    world.set_paused(true);
+    for _ in 0..3 {
+        std::thread::sleep(Duration::from_millis(2));
+        world.next_frame();
+        world.fixed_update();
+    }
+    assert_eq!(world.frame_count(), 6);
+    assert_eq!(world.physics_tick_count(), 3);
+    assert_eq!(world.elapsed_time(), elapsed);
+
+    world.set_paused(false);
+    std::thread::sleep(Duration::from_millis(2));
+    world.next_frame();
+    world.fixed_update();
+    assert_eq!(world.physics_tick_count(), 4);
+    assert!(world.elapsed_time() > elapsed);
+}
+
+#[test]
+fn timer_finishes_once_or_repeats() {
+    let mut once = Timer::new(Duration::from_millis(100));
+    assert!(!once.advance(Duration::from_millis(60)));
+    assert_eq!(once.remaining(), Duration::from_millis(40));
+    assert!(once.advance(Duration::from_millis(60)));
+    assert!(!once.advance(Duration::from_millis(200)));
+    assert!(once.is_finished());
+    once.reset();
+    assert!(!once.is_finished());
+
+    let mut repeating = Timer::repeating(Duration::from_millis(100));
+    assert!(repeating.advance(Duration::from_millis(250)));
+    assert_eq!(repeating.elapsed(), Duration::from_millis(50));
+    assert!(repeating.advance(Duration::from_millis(50)));
+}
+
+#[test]
+fn spatial_grid_follows_moved_and_deleted_objects() {
+    let (mut world, ..) = World::fresh();
+    world.spatial.set_cell_size(4.0);
+
+    let mut near = world.new_object("Near");
+    near.transform.set_position(2.0, 0.0, 0.0);
+    let mut far = world.new_object("Far");
+    far.transform.set_position(-30.0, 0.0, 12.0);
+    let mut enemy = world.new_object("Enemy");
+    enemy.transform.set_position(1.0, 1.0, 0.0);
+    world.spatial.insert(near, "pickup");
+    world.spatial.insert(far, "pickup");
+    world.spatial.insert(enemy, "enemy");
+    world.next_frame();
+
+    let origin = Vector3::zeros();
+    assert_eq!(world.spatial.within(origin, 5.0), vec![enemy, near]);
+    assert_eq!(
+        world.spatial.nearest_tagged(origin, 5.0, "pickup"),
+        Some(near)
+    );
+    assert_eq!(
+        world.spatial.within_tagged(origin, 100.0, "pickup"),
+        vec![near, far]
+    );
+    assert_eq!(world.spatial.tag(enemy), Some("enemy"));
+
+    // positions are taken over at the end of the frame
+    near.transform.set_position(50.0, 0.0, 0.0);
+    assert_eq!(
+        world.spatial.nearest_tagged(origin, 5.0, "pickup"),
+        Some(near)
+    );
+    world.next_frame();
+    assert_eq!(world.spatial.nearest_tagged(origin, 5.0, "pickup"), None);
+    assert_eq!(
+        world.spatial.nearest(Vector3::new(48.0, 0.0, 0.0), 5.0),
+        Some(near)
+    );
+
+    world.delete_object(enemy);
+    world.next_frame();
+    assert!(!world.spatial.contains(enemy));
+    assert_eq!(world.spatial.len(), 2);
+    assert!(world.spatial.within(origin, 5.0).is_empty());
+}
+
+#[test]
+fn z_up_worlds_pull_down_z_and_look_level() {
+    let mut world = World::builder()
+        .up_axis(UpAxis::Z)
+        .deterministic(true)
+        .build();
+    assert_eq!(world.up_axis(), UpAxis::Z);
+    assert!((world.physics.gravity - Vector3::new(0.0, 0.0, -9.81)).norm() < 1e-5);
+
+    // a camera starts out looking at the horizon, not the ground
+    let camera = world.new_camera().parent();
+    assert!((camera.transform.forward() - Vector3::y()).norm() < 1e-5);
+    assert!((camera.transform.up() - Vector3::z()).norm() < 1e-5);
+
+    let mut ball = world.new_object("Ball");
+    ball.add_component::<Collider3D>();
+    ball.add_component::<RigidBodyComponent>();
+    world.add_child(ball);
+    for _ in 0..10 {
+        world.fixed_update();
+    }
+    let position = ball.transform.position();
+    assert!(position.z < -0.1, "{position:?}");
+    assert!(position.xy().norm() < 1e-5, "{position:?}");
+
+    // explicit gravity still wins
+    let moon = World::builder()
+        .up_axis(UpAxis::Z)
+        .gravity(Vector3::new(0.0, 0.0, -1.62))
+        .build();
+    assert_eq!(moon.physics.gravity.z, -1.62);
+}